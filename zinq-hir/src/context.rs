@@ -1,14 +1,25 @@
+use zinq_syntax::macros::Expander;
+
 use crate::{Arena, expr::HirExpr, id::ExprId};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Context {
     pub exprs: Arena<ExprId, HirExpr>,
+
+    /// the macros left to expand in expression position
+    pub macros: Expander,
 }
 
 impl Context {
     pub fn new() -> Self {
         Self {
             exprs: Arena::new(),
+            macros: Expander::new(),
         }
     }
+
+    pub fn with_macros(mut self, macros: Expander) -> Self {
+        self.macros = macros;
+        self
+    }
 }
@@ -1,6 +1,6 @@
 use zinq_parse::Spanned;
 use zinq_syntax::Syntax;
-use zinq_token::{Arithmetic, Cmp, Logical};
+use zinq_token::{Arithmetic, Bitwise, Cmp, Logical};
 
 use crate::{Build, expr::HirExpr, id::ExprId};
 
@@ -39,6 +39,28 @@ impl Build for zinq_syntax::expr::ArithmeticExpr {
     }
 }
 
+impl Build for zinq_syntax::expr::BitwiseExpr {
+    type Output = ExprId;
+
+    fn build(&self, ctx: &mut crate::Context) -> zinq_error::Result<Self::Output> {
+        let left = self.left.build(ctx)?;
+        let right = self.right.build(ctx)?;
+        let op = BinaryOp::from(self.op.clone());
+        let id = zinq_hash::v1()
+            .push_str(self.name())
+            .push_field("left", left.to_string())
+            .push_field("op", op as u8)
+            .push_field("right", right.to_string())
+            .build()
+            .into();
+
+        ctx.exprs
+            .add(id, HirBinaryExpr { left, op, right }.into(), self.span());
+
+        Ok(id)
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum BinaryOp {
@@ -47,6 +69,14 @@ pub enum BinaryOp {
     Sub,
     Div,
     Mult,
+    Rem,
+
+    // Bitwise
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
 
     // Compare
     Eq,
@@ -68,6 +98,19 @@ impl From<Arithmetic> for BinaryOp {
             Arithmetic::Sub(_) => Self::Sub,
             Arithmetic::Div(_) => Self::Div,
             Arithmetic::Mult(_) => Self::Mult,
+            Arithmetic::Rem(_) => Self::Rem,
+        }
+    }
+}
+
+impl From<Bitwise> for BinaryOp {
+    fn from(value: Bitwise) -> Self {
+        match value {
+            Bitwise::And(_) => Self::BitAnd,
+            Bitwise::Or(_) => Self::BitOr,
+            Bitwise::Xor(_) => Self::BitXor,
+            Bitwise::Shl(_) => Self::Shl,
+            Bitwise::Shr(_) => Self::Shr,
         }
     }
 }
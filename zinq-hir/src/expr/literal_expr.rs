@@ -1,7 +1,13 @@
-use crate::expr::HirExpr;
+use zinq_parse::Spanned;
+use zinq_syntax::Syntax;
+use zinq_token::Literal;
+
+use crate::{Build, expr::HirExpr, id::ExprId};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum HirLiteralExpr {}
+pub struct HirLiteralExpr {
+    pub value: Literal,
+}
 
 impl From<HirLiteralExpr> for HirExpr {
     fn from(value: HirLiteralExpr) -> Self {
@@ -9,18 +15,25 @@ impl From<HirLiteralExpr> for HirExpr {
     }
 }
 
-// impl Build for zinq_syntax::expr::LiteralExpr {
-//     type Output = ExprId;
+impl Build for zinq_syntax::expr::LiteralExpr {
+    type Output = ExprId;
 
-//     fn build(&self, ctx: &mut crate::Context) -> zinq_error::Result<Self::Output> {
-//         let id = ExprId::new(self.name())
-//             .attr(self.value.to_string().into())
-//             .build()
-//             .into();
+    fn build(&self, ctx: &mut crate::Context) -> zinq_error::Result<Self::Output> {
+        let id = zinq_hash::v1()
+            .push_str(self.name())
+            .push_field("value", &self.value)
+            .build()
+            .into();
 
-//         ctx.exprs
-//             .add(id, HirLiteralExpr {  }.into(), self.span());
+        ctx.exprs.add(
+            id,
+            HirLiteralExpr {
+                value: self.value.clone(),
+            }
+            .into(),
+            self.span(),
+        );
 
-//         Ok(id)
-//     }
-// }
+        Ok(id)
+    }
+}
@@ -22,6 +22,13 @@ impl Build for zinq_syntax::expr::Expr {
     fn build(&self, ctx: &mut crate::Context) -> zinq_error::Result<Self::Output> {
         match self {
             Self::Arithmetic(v) => v.build(ctx),
+            Self::Bitwise(v) => v.build(ctx),
+            Self::Literal(v) => v.build(ctx),
+            Self::Not(v) => v.build(ctx),
+            Self::Neg(v) => v.build(ctx),
+            Self::BitNot(v) => v.build(ctx),
+            // `expand` leaves no calls behind and stops at the recursion limit
+            Self::Macro(v) => ctx.macros.expand::<Self>(&v.call)?.build(ctx),
             _ => unimplemented!(),
         }
     }
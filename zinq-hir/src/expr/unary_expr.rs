@@ -1,4 +1,7 @@
-use crate::id::ExprId;
+use zinq_parse::{Span, Spanned};
+use zinq_syntax::Syntax;
+
+use crate::{Build, expr::HirExpr, id::ExprId};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct HirUnaryExpr {
@@ -6,8 +9,114 @@ pub struct HirUnaryExpr {
     pub right: ExprId,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+impl From<HirUnaryExpr> for HirExpr {
+    fn from(value: HirUnaryExpr) -> Self {
+        Self::Unary(value)
+    }
+}
+
+impl Build for zinq_syntax::expr::NotExpr {
+    type Output = ExprId;
+
+    fn build(&self, ctx: &mut crate::Context) -> zinq_error::Result<Self::Output> {
+        let right = self.right.build(ctx)?;
+        Ok(add(ctx, self.name(), UnaryOp::Not, right, self.span()))
+    }
+}
+
+impl Build for zinq_syntax::expr::NegExpr {
+    type Output = ExprId;
+
+    fn build(&self, ctx: &mut crate::Context) -> zinq_error::Result<Self::Output> {
+        let right = self.right.build(ctx)?;
+        Ok(add(ctx, self.name(), UnaryOp::Negative, right, self.span()))
+    }
+}
+
+impl Build for zinq_syntax::expr::BitNotExpr {
+    type Output = ExprId;
+
+    fn build(&self, ctx: &mut crate::Context) -> zinq_error::Result<Self::Output> {
+        let right = self.right.build(ctx)?;
+        Ok(add(ctx, self.name(), UnaryOp::BitNot, right, self.span()))
+    }
+}
+
+// the operators only differ in their syntax node
+fn add(ctx: &mut crate::Context, name: &str, op: UnaryOp, right: ExprId, span: Span) -> ExprId {
+    let id = zinq_hash::v1()
+        .push_str(name)
+        .push_field("op", op as u8)
+        .push_field("right", right.to_string())
+        .build()
+        .into();
+
+    ctx.exprs.add(id, HirUnaryExpr { op, right }.into(), span);
+    id
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum UnaryOp {
     Negative,
     Not,
+    BitNot,
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+    use zinq_syntax::expr::ExprParser;
+
+    use crate::{
+        Build, Context,
+        expr::{HirExpr, UnaryOp},
+    };
+
+    #[test]
+    fn should_build_unary() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut ctx = Context::new();
+
+        for (src, op) in [
+            ("~1", UnaryOp::BitNot),
+            ("!true", UnaryOp::Not),
+            ("-1", UnaryOp::Negative),
+        ] {
+            let mut cursor = Span::from_str(src).cursor();
+            let id = parser.parse_expr(&mut cursor)?.build(&mut ctx)?;
+            let HirExpr::Unary(expr) = ctx.exprs.require(&id) else {
+                panic!("expected a unary expression for '{}'", src);
+            };
+
+            debug_assert_eq!(expr.op, op);
+            debug_assert!(matches!(
+                ctx.exprs.require(&expr.right),
+                HirExpr::Literal(_)
+            ));
+            debug_assert_eq!(ctx.exprs.get_span(&id).unwrap().to_string(), src);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_build_nested_bit_not() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut ctx = Context::new();
+        let mut cursor = Span::from_str("~~1").cursor();
+        let id = parser.parse_expr(&mut cursor)?.build(&mut ctx)?;
+        let HirExpr::Unary(outer) = ctx.exprs.require(&id) else {
+            panic!("expected a unary expression");
+        };
+
+        debug_assert_eq!(outer.op, UnaryOp::BitNot);
+        debug_assert!(
+            matches!(ctx.exprs.require(&outer.right), HirExpr::Unary(inner) if inner.op == UnaryOp::BitNot)
+        );
+        debug_assert_eq!(ctx.exprs.count(), 3);
+
+        Ok(())
+    }
 }
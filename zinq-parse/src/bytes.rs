@@ -21,6 +21,16 @@ impl Bytes {
         self.0.len()
     }
 
+    ///
+    /// ## ptr_eq
+    /// whether both point to the same
+    /// shared sequence of bytes
+    ///
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
     #[inline]
     pub fn first(&self) -> Location {
         self.at(0)
@@ -120,4 +130,23 @@ mod tests {
         debug_assert_eq!(location.column(), 1);
         debug_assert_eq!(bytes[location.index()], b's');
     }
+    #[test]
+    fn should_step_locations() {
+        let bytes = Bytes::from(b"hi\nmy\n\nname\n\n\nis\n\n\n\nbob");
+        let mut location = bytes.first();
+
+        for i in 1..=bytes.len() {
+            debug_assert!(location.next(&bytes));
+            debug_assert_eq!(location, bytes.at(i));
+        }
+
+        debug_assert!(!location.next(&bytes));
+
+        for i in (0..bytes.len()).rev() {
+            debug_assert!(location.back(&bytes));
+            debug_assert_eq!(location, bytes.at(i));
+        }
+
+        debug_assert!(!location.back(&bytes));
+    }
 }
@@ -3,7 +3,7 @@ use std::ops::Index;
 use zinq_error::{Error, Result, ZinqError, ZinqErrorCode};
 
 use crate::{
-    Diagnostic, EOF, ParseError, ParseResult, Span, Tx,
+    Diagnostic, EOF, ParseError, ParseResult, SourceMap, Span, Tx,
     diagnostic::{self, NOOP},
 };
 
@@ -16,6 +16,7 @@ use crate::{
 pub struct Cursor {
     changes: Tx<Span>,
     diagnostics: Vec<Diagnostic>,
+    map: Option<SourceMap>,
 }
 
 impl Cursor {
@@ -29,6 +30,7 @@ impl Cursor {
         Self {
             changes: Tx::new(self.span().clone()),
             diagnostics: vec![],
+            map: self.map.clone(),
         }
     }
 
@@ -41,6 +43,30 @@ impl Cursor {
         self.changes.last()
     }
 
+    ///
+    /// ## with_map
+    /// map the spans of this cursor back to the
+    /// source its bytes were printed from
+    ///
+    #[inline]
+    pub fn with_map(mut self, map: SourceMap) -> Self {
+        self.map = Some(map);
+        self
+    }
+
+    ///
+    /// ## source_span
+    /// the current span, resolved through the
+    /// `SourceMap` when the cursor has one
+    ///
+    #[inline]
+    pub fn source_span(&self) -> Span {
+        match &self.map {
+            None => self.span().clone(),
+            Some(map) => map.resolve(self.span()),
+        }
+    }
+
     ///
     /// ## peek
     /// peek at the next byte
@@ -225,7 +251,7 @@ impl Cursor {
 
         if !other.diagnostics.is_empty() {
             self.diagnostics.push(
-                Diagnostic::noop(self.span().clone())
+                Diagnostic::noop(self.source_span())
                     .children(&other.diagnostics)
                     .build(),
             );
@@ -242,7 +268,7 @@ impl Cursor {
     #[inline]
     pub fn error(&self, code: ZinqErrorCode, message: &str) -> ZinqError {
         ParseError::from_error(
-            self.span().clone(),
+            self.source_span(),
             Error::new().code(code).message(message).build().into(),
         )
         .into()
@@ -251,10 +277,11 @@ impl Cursor {
     ///
     /// ## report
     /// emit a diagnostic to be added to the analyzer report
+    /// at the current span, mapped like `source_span`
     ///
     #[inline]
     pub fn report(&mut self, code: diagnostic::Code) -> &mut Self {
-        self.diagnostics.push(code.at(self.span().clone()).build());
+        self.diagnostics.push(code.at(self.source_span()).build());
         self
     }
 
@@ -265,10 +292,31 @@ impl Cursor {
     #[inline]
     pub fn report_as(&mut self, code: diagnostic::Code, message: &str) -> &mut Self {
         self.diagnostics
-            .push(code.at(self.span().clone()).message(message).build());
+            .push(code.at(self.source_span()).message(message).build());
+        self
+    }
+
+    ///
+    /// ## diagnose
+    /// emit an already built diagnostic, for
+    /// when it belongs to a span other than
+    /// the current one
+    ///
+    #[inline]
+    pub fn diagnose(&mut self, diagnostic: Diagnostic) -> &mut Self {
+        self.diagnostics.push(diagnostic);
         self
     }
 
+    ///
+    /// ## diagnostics
+    /// the diagnostics emitted so far
+    ///
+    #[inline]
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
     ///
     /// ## build
     /// build the parse result from the cursor
@@ -282,7 +330,7 @@ impl Cursor {
         ParseResult {
             value,
             diagnostic: Some(
-                NOOP.at(self.span().clone())
+                NOOP.at(self.source_span())
                     .children(&self.diagnostics)
                     .build(),
             ),
@@ -296,6 +344,7 @@ impl From<Span> for Cursor {
         Self {
             changes: Tx::<Span>::new(span.clone()),
             diagnostics: vec![],
+            map: None,
         }
     }
 }
@@ -320,7 +369,7 @@ impl std::fmt::Display for Cursor {
 mod tests {
     use zinq_error::Result;
 
-    use crate::{Bytes, Span};
+    use crate::{Bytes, SourceMap, Span, diagnostic::NOOP};
 
     #[test]
     fn should_peek() -> Result<()> {
@@ -393,4 +442,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_report_at_source_span() -> Result<()> {
+        let original = Span::from_str("let\n    a = 1");
+        let printed = Span::from_str("let a = 1");
+        let map = SourceMap::new(vec![
+            (0, 3, original.slice(0, 3)),
+            (4, 5, original.slice(8, 9)),
+            (6, 7, original.slice(10, 11)),
+            (8, 9, original.slice(12, 13)),
+        ]);
+
+        let mut cursor = printed.cursor().with_map(map);
+
+        cursor.next_n(4)?.commit().next()?;
+        cursor.report_as(NOOP, "at a");
+
+        let result = cursor.build(());
+        let diagnostic = result.diagnostic.expect("expected diagnostic");
+        let child = &diagnostic.children[0];
+
+        debug_assert_eq!(child.span.to_string(), "a");
+        debug_assert_eq!(child.span.start().index(), 8);
+        debug_assert_eq!(child.span.start().line(), 1);
+        debug_assert_eq!(child.span.start().column(), 4);
+        debug_assert_eq!(diagnostic.span.start().index(), 8);
+
+        Ok(())
+    }
 }
@@ -17,6 +17,41 @@ pub const TOKEN_NOT_FOUND_ERROR: Code = Code {
     description: "token not found.",
 };
 
+pub const ITEM_NOT_ALLOWED_ERROR: Code = Code {
+    id: 2,
+    severity: Severity::Error,
+    name: "ItemNotAllowed",
+    description: "item not allowed here.",
+};
+
+pub const MODULE_NOT_FOUND_ERROR: Code = Code {
+    id: 3,
+    severity: Severity::Error,
+    name: "ModuleNotFound",
+    description: "module file not found.",
+};
+
+pub const CYCLIC_MODULE_ERROR: Code = Code {
+    id: 4,
+    severity: Severity::Error,
+    name: "CyclicModule",
+    description: "module declarations form a cycle.",
+};
+
+pub const DUPLICATE_MODULE_ERROR: Code = Code {
+    id: 5,
+    severity: Severity::Error,
+    name: "DuplicateModule",
+    description: "module declared more than once.",
+};
+
+pub const INVALID_META_ERROR: Code = Code {
+    id: 6,
+    severity: Severity::Error,
+    name: "InvalidMeta",
+    description: "attribute argument is not a path, list or key value.",
+};
+
 impl Diagnostic {
     pub fn internal_error(span: Span) -> Builder {
         Self::new(span).code(INTERNAL_ERROR)
@@ -25,4 +60,24 @@ impl Diagnostic {
     pub fn token_not_found_error(span: Span) -> Builder {
         Self::new(span).code(TOKEN_NOT_FOUND_ERROR)
     }
+
+    pub fn item_not_allowed_error(span: Span) -> Builder {
+        Self::new(span).code(ITEM_NOT_ALLOWED_ERROR)
+    }
+
+    pub fn module_not_found_error(span: Span) -> Builder {
+        Self::new(span).code(MODULE_NOT_FOUND_ERROR)
+    }
+
+    pub fn cyclic_module_error(span: Span) -> Builder {
+        Self::new(span).code(CYCLIC_MODULE_ERROR)
+    }
+
+    pub fn duplicate_module_error(span: Span) -> Builder {
+        Self::new(span).code(DUPLICATE_MODULE_ERROR)
+    }
+
+    pub fn invalid_meta_error(span: Span) -> Builder {
+        Self::new(span).code(INVALID_META_ERROR)
+    }
 }
@@ -7,6 +7,7 @@ mod error;
 mod file_meta_data;
 mod location;
 mod result;
+mod source_map;
 mod span;
 mod tx;
 
@@ -18,6 +19,7 @@ pub use error::*;
 pub use file_meta_data::*;
 pub use location::*;
 pub use result::*;
+pub use source_map::*;
 pub use span::*;
 pub use tx::*;
 
@@ -91,19 +91,15 @@ impl Location {
     ///
     #[inline]
     pub fn next(&mut self, bytes: &[u8]) -> bool {
-        if self.index + 1 > bytes.len() {
-            return false;
-        }
+        let byte = match bytes.get(self.index) {
+            None => return false,
+            Some(v) => v,
+        };
 
         self.index += 1;
-
-        if self.index == bytes.len() {
-            return true;
-        }
-
         self.column += 1;
 
-        if bytes.index(self.index) == &b'\n' {
+        if byte == &b'\n' {
             self.line += 1;
             self.column = 0;
         }
@@ -121,13 +117,7 @@ impl Location {
             return false;
         }
 
-        let mut column = self.column;
-
-        if column > 0 {
-            column -= 1;
-        }
-
-        let mut line = self.line;
+        self.index -= 1;
 
         if bytes.index(self.index) == &b'\n' {
             let mut count = 0;
@@ -140,13 +130,12 @@ impl Location {
                 count += 1;
             }
 
-            line -= 1;
-            column = count;
+            self.line -= 1;
+            self.column = count;
+            return true;
         }
 
-        self.index -= 1;
-        self.column = column;
-        self.line = line;
+        self.column -= 1;
         true
     }
 }
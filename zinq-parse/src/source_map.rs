@@ -0,0 +1,103 @@
+use std::rc::Rc;
+
+use crate::Span;
+
+///
+/// ## SourceMap
+/// maps byte ranges of a synthesized source back to
+/// the spans they were printed from, so values parsed
+/// from it keep pointing at the original source
+///
+#[derive(Debug, Default, Clone)]
+pub struct SourceMap {
+    entries: Rc<[(usize, usize, Span)]>,
+}
+
+impl SourceMap {
+    ///
+    /// ## new
+    /// create a map from `(start, end, original)`
+    /// entries ordered by their range
+    ///
+    pub fn new(entries: Vec<(usize, usize, Span)>) -> Self {
+        Self {
+            entries: entries.into(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    ///
+    /// ## count
+    /// the number of entries ending at or before `index`
+    ///
+    pub fn count(&self, index: usize) -> usize {
+        self.entries.partition_point(|(_, end, _)| end <= &index)
+    }
+
+    ///
+    /// ## resolve
+    /// the original span covering the mapped entries
+    /// that overlap `span`, or `span` itself when none do,
+    /// empty spans resolve to the start of the next entry
+    ///
+    pub fn resolve(&self, span: &Span) -> Span {
+        let start = span.start().index();
+        let end = span.end().index();
+
+        if start == end {
+            return match self.entries.get(self.count(start)) {
+                Some((_, _, next)) => next.between(*next.start(), *next.start()),
+                None => match self.entries.last() {
+                    None => span.clone(),
+                    Some((_, _, last)) => last.between(*last.end(), *last.end()),
+                },
+            };
+        }
+
+        let first = self.entries.partition_point(|(_, e, _)| e <= &start);
+        let last = self.entries.partition_point(|(s, _, _)| s < &end);
+
+        if first >= last {
+            return span.clone();
+        }
+
+        let from = &self.entries[first].2;
+        let to = &self.entries[last - 1].2;
+
+        if first + 1 == last || !from.is_same_source(to) {
+            return from.clone();
+        }
+
+        Span::join(from.clone(), to.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SourceMap, Span};
+
+    #[test]
+    fn should_resolve() {
+        let original = Span::from_str("let   a = 1");
+        let map = SourceMap::new(vec![
+            (0, 3, original.slice(0, 3)),
+            (4, 5, original.slice(6, 7)),
+            (6, 7, original.slice(8, 9)),
+        ]);
+
+        let printed = Span::from_str("let a = 1");
+
+        debug_assert_eq!(map.resolve(&printed.slice(4, 5)).to_string(), "a");
+        debug_assert_eq!(map.resolve(&printed.slice(0, 5)).to_string(), "let   a");
+        debug_assert_eq!(map.resolve(&printed.slice(8, 9)).to_string(), "1");
+        debug_assert_eq!(map.count(5), 2);
+        debug_assert_eq!(map.resolve(&printed.slice(4, 4)).start().index(), 6);
+    }
+}
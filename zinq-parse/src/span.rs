@@ -27,6 +27,7 @@ pub struct Span {
     end: Location,
     file: Option<FileMetaData>,
     bytes: Bytes,
+    generated: bool,
 }
 
 impl Span {
@@ -39,6 +40,7 @@ impl Span {
             end: bytes.last(),
             file: None,
             bytes,
+            generated: false,
         }
     }
 
@@ -51,9 +53,22 @@ impl Span {
             end: bytes.last(),
             file: None,
             bytes,
+            generated: false,
         }
     }
 
+    ///
+    /// ## generated
+    /// a detached span for synthesized tokens,
+    /// its source is only the given text
+    ///
+    #[inline]
+    pub fn generated(src: &str) -> Self {
+        let mut span = Self::from_str(src);
+        span.generated = true;
+        span
+    }
+
     #[inline]
     pub fn from_file(path: &str) -> Result<Self> {
         let file = FileMetaData::try_from(path)?;
@@ -64,6 +79,7 @@ impl Span {
             end: bytes.last(),
             file: Some(file),
             bytes,
+            generated: false,
         })
     }
 
@@ -115,6 +131,21 @@ impl Span {
         self.file.as_ref()
     }
 
+    #[inline]
+    pub fn is_generated(&self) -> bool {
+        self.generated
+    }
+
+    ///
+    /// ## is_same_source
+    /// whether both spans slice the same source,
+    /// meaning they can be joined
+    ///
+    #[inline]
+    pub fn is_same_source(&self, other: &Self) -> bool {
+        self.bytes.ptr_eq(&other.bytes)
+    }
+
     #[inline]
     pub fn sof(&self) -> bool {
         self.end.index() == 0
@@ -150,6 +181,23 @@ impl Span {
             end,
             file: self.file.clone(),
             bytes: self.bytes.clone(),
+            generated: self.generated,
+        }
+    }
+
+    ///
+    /// ## between
+    /// create a span over the same source bounded
+    /// by two already resolved locations
+    ///
+    #[inline]
+    pub fn between(&self, start: Location, end: Location) -> Self {
+        Self {
+            start,
+            end,
+            file: self.file.clone(),
+            bytes: self.bytes.clone(),
+            generated: self.generated,
         }
     }
 
@@ -38,10 +38,21 @@ impl Syntax for ArithmeticExpr {
         "Expr::Binary::Arithmetic"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_arithmetic_expr(self);
-        self.left.accept(visitor);
-        self.right.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_arithmetic_expr(self)
+            .then(|| crate::expr::walk_arithmetic_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_arithmetic_expr_mut(self)
+            .then(|| crate::expr::walk_arithmetic_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_arithmetic_expr_fold(folder, self);
+        folder.fold_arithmetic_expr(node)
     }
 }
 
@@ -74,4 +85,19 @@ mod tests {
         debug_assert!(value.is_arithmetic());
         Ok(())
     }
+
+    #[test]
+    fn should_bind_factor_over_term() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a - b * c % d - e").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let sub = value.as_arithmetic();
+        let rem = sub.left.as_arithmetic().right.as_arithmetic();
+
+        debug_assert!(sub.op.is_sub());
+        debug_assert!(sub.right.is_path());
+        debug_assert!(rem.op.is_rem());
+        debug_assert!(rem.left.as_arithmetic().op.is_mult());
+        Ok(())
+    }
 }
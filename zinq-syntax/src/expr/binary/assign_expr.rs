@@ -1,30 +1,40 @@
 use zinq_parse::{Span, Spanned};
-use zinq_token::Eq;
+use zinq_token::Assign;
 
 use crate::{Syntax, expr::Expr};
 
 ///
 /// ## Assign Expression
-/// `message = (...)`
+/// `message = (...)` or a compound
+/// assignment like `count += 1`
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AssignExpr {
     pub left: Box<Expr>,
-    pub eq: Eq,
+    pub op: Assign,
     pub right: Box<Expr>,
 }
 
 impl AssignExpr {
-    /// `<left> = <right>`
-    pub fn new(left: Expr, eq: Eq, right: Expr) -> Self {
+    /// `<left> <op> <right>`
+    pub fn new(left: Expr, op: Assign, right: Expr) -> Self {
         Self {
             left: Box::new(left),
-            eq,
+            op,
             right: Box::new(right),
         }
     }
 }
 
+impl Expr {
+    pub fn is_compound_assign(&self) -> bool {
+        match self {
+            Self::Assign(v) => v.op.is_compound(),
+            _ => false,
+        }
+    }
+}
+
 impl From<AssignExpr> for Expr {
     fn from(value: AssignExpr) -> Self {
         Self::Assign(value)
@@ -36,10 +46,21 @@ impl Syntax for AssignExpr {
         "Expr::Binary::Assign"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_assign_expr(self);
-        self.left.accept(visitor);
-        self.right.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_assign_expr(self)
+            .then(|| crate::expr::walk_assign_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_assign_expr_mut(self)
+            .then(|| crate::expr::walk_assign_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_assign_expr_fold(folder, self);
+        folder.fold_assign_expr(node)
     }
 }
 
@@ -72,4 +93,28 @@ mod tests {
         debug_assert!(value.is_assign());
         Ok(())
     }
+
+    #[test]
+    fn should_parse_compound() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a <<= b %= 2").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let assign = value.as_assign();
+
+        debug_assert!(value.is_compound_assign());
+        debug_assert!(assign.op.is_shl());
+        debug_assert!(assign.right.as_assign().op.is_rem());
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_read_eq_eq_as_assign() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a |= b == c").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(value.as_assign().op.is_or());
+        debug_assert!(value.as_assign().right.is_cmp());
+        Ok(())
+    }
 }
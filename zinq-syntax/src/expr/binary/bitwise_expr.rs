@@ -0,0 +1,127 @@
+use zinq_parse::{Span, Spanned};
+use zinq_token::Bitwise;
+
+use crate::{Syntax, expr::Expr};
+
+///
+/// ## Bitwise Expression
+/// `<left> <op> <right>`
+/// ### Example
+/// `flags & MASK` or `1 << shift`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitwiseExpr {
+    pub left: Box<Expr>,
+    pub op: Bitwise,
+    pub right: Box<Expr>,
+}
+
+impl BitwiseExpr {
+    /// `<left> <op> <right>`
+    pub fn new(left: Expr, op: Bitwise, right: Expr) -> Self {
+        Self {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+        }
+    }
+}
+
+impl From<BitwiseExpr> for Expr {
+    fn from(value: BitwiseExpr) -> Self {
+        Self::Bitwise(value)
+    }
+}
+
+impl Syntax for BitwiseExpr {
+    fn name(&self) -> &str {
+        "Expr::Binary::Bitwise"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_bitwise_expr(self)
+            .then(|| crate::expr::walk_bitwise_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_bitwise_expr_mut(self)
+            .then(|| crate::expr::walk_bitwise_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_bitwise_expr_fold(folder, self);
+        folder.fold_bitwise_expr(node)
+    }
+}
+
+impl std::fmt::Display for BitwiseExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for BitwiseExpr {
+    fn span(&self) -> Span {
+        Span::join(self.left.span(), self.right.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::ExprParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a & 2").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "a & 2");
+        debug_assert!(value.is_bitwise());
+        Ok(())
+    }
+
+    #[test]
+    fn should_bind_and_over_xor_over_or() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a | b ^ c & d").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let or = value.as_bitwise();
+        let xor = or.right.as_bitwise();
+
+        debug_assert!(or.op.is_or());
+        debug_assert!(xor.op.is_xor());
+        debug_assert!(xor.right.as_bitwise().op.is_and());
+        Ok(())
+    }
+
+    #[test]
+    fn should_bind_shift_below_term() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"1 << n + 1 >> 2").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let shr = value.as_bitwise();
+        let shl = shr.left.as_bitwise();
+
+        debug_assert!(shr.op.is_shr());
+        debug_assert!(shl.op.is_shl());
+        debug_assert!(shl.right.is_arithmetic());
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_read_logical_and_as_bitwise() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a && b & c").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(value.is_and());
+        debug_assert!(value.as_logical().right.is_bitwise());
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use zinq_parse::{Span, Spanned};
+use zinq_token::As;
+
+use crate::{Syntax, expr::Expr, ty::Type};
+
+///
+/// ## Cast Expression
+/// `<left> as <type>`, binds tighter than
+/// any other binary operator but looser
+/// than unary ones, so `-a as u8 * b` is
+/// `((-a) as u8) * b`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CastExpr {
+    pub left: Box<Expr>,
+    pub keyword: As,
+    pub ty: Type,
+}
+
+impl CastExpr {
+    /// `<left> as <ty>`
+    pub fn new(left: Expr, keyword: As, ty: Type) -> Self {
+        Self {
+            left: Box::new(left),
+            keyword,
+            ty,
+        }
+    }
+}
+
+impl From<CastExpr> for Expr {
+    fn from(value: CastExpr) -> Self {
+        Self::Cast(value)
+    }
+}
+
+impl Syntax for CastExpr {
+    fn name(&self) -> &str {
+        "Expr::Binary::Cast"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_cast_expr(self)
+            .then(|| crate::expr::walk_cast_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_cast_expr_mut(self)
+            .then(|| crate::expr::walk_cast_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_cast_expr_fold(folder, self);
+        folder.fold_cast_expr(node)
+    }
+}
+
+impl std::fmt::Display for CastExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for CastExpr {
+    fn span(&self) -> Span {
+        Span::join(self.left.span(), self.ty.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::ExprParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a as u8").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "a as u8");
+        debug_assert!(value.is_cast());
+        Ok(())
+    }
+
+    #[test]
+    fn should_bind_tighter_than_factor() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"-a as u8 * b as u8 as u32").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let mult = value.as_arithmetic();
+
+        debug_assert!(mult.op.is_mult());
+        debug_assert!(mult.left.as_cast().left.is_neg());
+        debug_assert_eq!(mult.right.as_cast().ty.to_string(), "u32");
+        debug_assert!(mult.right.as_cast().left.is_cast());
+        Ok(())
+    }
+}
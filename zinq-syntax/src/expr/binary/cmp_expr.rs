@@ -38,10 +38,21 @@ impl Syntax for CmpExpr {
         "Expr::Binary::Cmp"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_cmp_expr(self);
-        self.left.accept(visitor);
-        self.right.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_cmp_expr(self)
+            .then(|| crate::expr::walk_cmp_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_cmp_expr_mut(self)
+            .then(|| crate::expr::walk_cmp_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_cmp_expr_fold(folder, self);
+        folder.fold_cmp_expr(node)
     }
 }
 
@@ -74,4 +85,21 @@ mod tests {
         debug_assert!(value.is_cmp());
         Ok(())
     }
+
+    #[test]
+    fn should_not_chain() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+
+        for src in ["a < b < c", "a == b == c", "a != b >= c"] {
+            let mut cursor = Span::from_str(src).cursor();
+            debug_assert!(parser.parse_expr(&mut cursor).is_err(), "{}", src);
+        }
+
+        let mut cursor = Span::from_bytes(b"(a < b) == c && c > d").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(value.is_and());
+        debug_assert!(value.as_logical().left.as_cmp().left.is_group());
+        Ok(())
+    }
 }
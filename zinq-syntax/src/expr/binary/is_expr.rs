@@ -1,7 +1,12 @@
-use zinq_parse::{Span, Spanned};
+use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::Is;
 
-use crate::{Syntax, expr::Expr, ty::Type};
+use crate::{
+    Syntax,
+    expr::Expr,
+    pat::{Pattern, TupleStructPattern},
+    ty::Type,
+};
 
 ///
 /// ## Is Expression
@@ -9,22 +14,26 @@ use crate::{Syntax, expr::Expr, ty::Type};
 /// if <left> is <type> {
 ///     ...
 /// }
+///
+/// if <left> is Some(v) {
+///     ...
+/// }
 /// ```
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IsExpr {
     pub left: Box<Expr>,
     pub keyword: Is,
-    pub ty: Type,
+    pub target: IsTarget,
 }
 
 impl IsExpr {
-    /// `<left> is <ty>`
-    pub fn new(left: Expr, keyword: Is, ty: Type) -> Self {
+    /// `<left> is <target>`
+    pub fn new(left: Expr, keyword: Is, target: IsTarget) -> Self {
         Self {
             left: Box::new(left),
             keyword,
-            ty,
+            target,
         }
     }
 }
@@ -40,10 +49,21 @@ impl Syntax for IsExpr {
         "Expr::Binary::Is"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_is_expr(self);
-        self.left.accept(visitor);
-        self.ty.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_is_expr(self)
+            .then(|| crate::expr::walk_is_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_is_expr_mut(self)
+            .then(|| crate::expr::walk_is_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_is_expr_fold(folder, self);
+        folder.fold_is_expr(node)
     }
 }
 
@@ -55,7 +75,105 @@ impl std::fmt::Display for IsExpr {
 
 impl Spanned for IsExpr {
     fn span(&self) -> Span {
-        Span::join(self.left.span(), self.ty.span())
+        Span::join(self.left.span(), self.target.span())
+    }
+}
+
+///
+/// ## Is Target
+/// the right side of `is`, a tuple struct pattern
+/// like `Some(v)` binds its fields, anything else
+/// is a type test
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IsTarget {
+    Type(Type),
+    Pattern(Pattern),
+}
+
+impl IsTarget {
+    pub fn is_type(&self) -> bool {
+        matches!(self, Self::Type(_))
+    }
+
+    pub fn is_pattern(&self) -> bool {
+        matches!(self, Self::Pattern(_))
+    }
+
+    pub fn as_type(&self) -> &Type {
+        match self {
+            Self::Type(v) => v,
+            v => panic!("expected Type, received {}", v.name()),
+        }
+    }
+
+    pub fn as_pattern(&self) -> &Pattern {
+        match self {
+            Self::Pattern(v) => v,
+            v => panic!("expected Pattern, received {}", v.name()),
+        }
+    }
+}
+
+impl Syntax for IsTarget {
+    fn name(&self) -> &str {
+        match self {
+            Self::Type(v) => v.name(),
+            Self::Pattern(v) => v.name(),
+        }
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        crate::expr::walk_is_target(visitor, self)
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        crate::expr::walk_is_target_mut(visitor, self)
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        crate::expr::walk_is_target_fold(folder, self)
+    }
+}
+
+impl std::fmt::Display for IsTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for IsTarget {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<TupleStructPattern>(cursor).unwrap_or(false)
+            || parser.peek::<Type>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for IsTarget {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        // a `{` after the path is the body of an `if`, so
+        // struct patterns are never read here
+        if parser.peek::<TupleStructPattern>(cursor).unwrap_or(false) {
+            let pattern = parser.parse::<TupleStructPattern>(cursor)?;
+            return Ok(Self::Pattern(pattern.into()));
+        }
+
+        Ok(Self::Type(parser.parse::<Type>(cursor)?))
+    }
+}
+
+impl Spanned for IsTarget {
+    fn span(&self) -> Span {
+        match self {
+            Self::Type(v) => v.span(),
+            Self::Pattern(v) => v.span(),
+        }
     }
 }
 
@@ -52,10 +52,21 @@ impl Syntax for LogicalExpr {
         "Expr::Binary::Logical"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_logical_expr(self);
-        self.left.accept(visitor);
-        self.right.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_logical_expr(self)
+            .then(|| crate::expr::walk_logical_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_logical_expr_mut(self)
+            .then(|| crate::expr::walk_logical_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_logical_expr_fold(folder, self);
+        folder.fold_logical_expr(node)
     }
 }
 
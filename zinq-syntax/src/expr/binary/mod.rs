@@ -1,11 +1,15 @@
 mod arithmetic_expr;
 mod assign_expr;
+mod bitwise_expr;
+mod cast_expr;
 mod cmp_expr;
 mod is_expr;
 mod logical_expr;
 
 pub use arithmetic_expr::*;
 pub use assign_expr::*;
+pub use bitwise_expr::*;
+pub use cast_expr::*;
 pub use cmp_expr::*;
 pub use is_expr::*;
 pub use logical_expr::*;
@@ -0,0 +1,86 @@
+use zinq_parse::{Parse, Peek, Spanned};
+
+use crate::{
+    Syntax,
+    expr::{Expr, ExprParser},
+};
+
+///
+/// ## Const Expression
+/// an expression that must be known at compile time,
+/// like the `N` in `[T; N]` or `[0; N]`, it parses
+/// as any other expression and is evaluated later
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstExpr {
+    pub value: Box<Expr>,
+}
+
+impl std::ops::Deref for ConstExpr {
+    type Target = Expr;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl From<Expr> for ConstExpr {
+    fn from(value: Expr) -> Self {
+        Self {
+            value: Box::new(value),
+        }
+    }
+}
+
+impl Syntax for ConstExpr {
+    fn name(&self) -> &str {
+        "Expr::Const"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_const_expr(self)
+            .then(|| crate::expr::walk_const_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_const_expr_mut(self)
+            .then(|| crate::expr::walk_const_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_const_expr_fold(folder, self);
+        folder.fold_const_expr(node)
+    }
+}
+
+impl std::fmt::Display for ConstExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for ConstExpr {
+    fn span(&self) -> zinq_parse::Span {
+        self.value.span()
+    }
+}
+
+impl Peek for ConstExpr {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Expr>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for ConstExpr {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        Ok(parser.parse_expr(cursor)?.into())
+    }
+}
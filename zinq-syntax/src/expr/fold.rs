@@ -0,0 +1,412 @@
+use zinq_token::Suffixed;
+
+use crate::Syntax;
+
+use super::*;
+
+pub trait ExprFold: MatchFold {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        node
+    }
+
+    fn fold_const_expr(&mut self, node: ConstExpr) -> ConstExpr {
+        node
+    }
+
+    fn fold_arithmetic_expr(&mut self, node: ArithmeticExpr) -> ArithmeticExpr {
+        node
+    }
+
+    fn fold_assign_expr(&mut self, node: AssignExpr) -> AssignExpr {
+        node
+    }
+
+    fn fold_bitwise_expr(&mut self, node: BitwiseExpr) -> BitwiseExpr {
+        node
+    }
+
+    fn fold_cast_expr(&mut self, node: CastExpr) -> CastExpr {
+        node
+    }
+
+    fn fold_cmp_expr(&mut self, node: CmpExpr) -> CmpExpr {
+        node
+    }
+
+    fn fold_is_expr(&mut self, node: IsExpr) -> IsExpr {
+        node
+    }
+
+    fn fold_logical_expr(&mut self, node: LogicalExpr) -> LogicalExpr {
+        node
+    }
+
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        node
+    }
+
+    fn fold_if_expr(&mut self, node: IfExpr) -> IfExpr {
+        node
+    }
+
+    fn fold_if_else_expr(&mut self, node: IfElseExpr) -> IfElseExpr {
+        node
+    }
+
+    fn fold_range_expr(&mut self, node: RangeExpr) -> RangeExpr {
+        node
+    }
+
+    fn fold_call_expr(&mut self, node: CallExpr) -> CallExpr {
+        node
+    }
+
+    fn fold_index_expr(&mut self, node: IndexExpr) -> IndexExpr {
+        node
+    }
+
+    fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
+        node
+    }
+
+    fn fold_try_expr(&mut self, node: TryExpr) -> TryExpr {
+        node
+    }
+
+    fn fold_ref_expr(&mut self, node: RefExpr) -> RefExpr {
+        node
+    }
+
+    fn fold_closure_expr(&mut self, node: ClosureExpr) -> ClosureExpr {
+        node
+    }
+
+    fn fold_neg_expr(&mut self, node: NegExpr) -> NegExpr {
+        node
+    }
+
+    fn fold_not_expr(&mut self, node: NotExpr) -> NotExpr {
+        node
+    }
+
+    fn fold_bit_not_expr(&mut self, node: BitNotExpr) -> BitNotExpr {
+        node
+    }
+
+    fn fold_array_expr(&mut self, node: ArrayExpr) -> ArrayExpr {
+        node
+    }
+
+    fn fold_block_expr(&mut self, node: BlockExpr) -> BlockExpr {
+        node
+    }
+
+    fn fold_group_expr(&mut self, node: GroupExpr) -> GroupExpr {
+        node
+    }
+
+    fn fold_literal_expr(&mut self, node: LiteralExpr) -> LiteralExpr {
+        node
+    }
+
+    fn fold_macro_expr(&mut self, node: MacroExpr) -> MacroExpr {
+        node
+    }
+
+    fn fold_path_expr(&mut self, node: PathExpr) -> PathExpr {
+        node
+    }
+
+    fn fold_struct_expr(&mut self, node: StructExpr) -> StructExpr {
+        node
+    }
+
+    fn fold_tuple_expr(&mut self, node: TupleExpr) -> TupleExpr {
+        node
+    }
+}
+
+pub fn walk_expr_fold<F: crate::Fold>(folder: &mut F, node: Expr) -> Expr {
+    match node {
+        Expr::Literal(v) => Expr::Literal(v.fold(folder)),
+        Expr::Path(v) => Expr::Path(v.fold(folder)),
+        Expr::Group(v) => Expr::Group(v.fold(folder)),
+        Expr::Struct(v) => Expr::Struct(v.fold(folder)),
+        Expr::Tuple(v) => Expr::Tuple(v.fold(folder)),
+        Expr::Array(v) => Expr::Array(v.fold(folder)),
+        Expr::Block(v) => Expr::Block(v.fold(folder)),
+        Expr::Range(v) => Expr::Range(v.fold(folder)),
+        Expr::Macro(v) => Expr::Macro(v.fold(folder)),
+        Expr::Arithmetic(v) => Expr::Arithmetic(v.fold(folder)),
+        Expr::Assign(v) => Expr::Assign(v.fold(folder)),
+        Expr::Bitwise(v) => Expr::Bitwise(v.fold(folder)),
+        Expr::Cast(v) => Expr::Cast(v.fold(folder)),
+        Expr::Cmp(v) => Expr::Cmp(v.fold(folder)),
+        Expr::Is(v) => Expr::Is(v.fold(folder)),
+        Expr::Logical(v) => Expr::Logical(v.fold(folder)),
+        Expr::Call(v) => Expr::Call(v.fold(folder)),
+        Expr::Index(v) => Expr::Index(v.fold(folder)),
+        Expr::Member(v) => Expr::Member(v.fold(folder)),
+        Expr::Try(v) => Expr::Try(v.fold(folder)),
+        Expr::Not(v) => Expr::Not(v.fold(folder)),
+        Expr::Neg(v) => Expr::Neg(v.fold(folder)),
+        Expr::BitNot(v) => Expr::BitNot(v.fold(folder)),
+        Expr::Ref(v) => Expr::Ref(v.fold(folder)),
+        Expr::Closure(v) => Expr::Closure(Box::new((*v).fold(folder))),
+        Expr::If(v) => Expr::If(v.fold(folder)),
+        Expr::IfElse(v) => Expr::IfElse(Box::new((*v).fold(folder))),
+        Expr::Match(v) => Expr::Match(v.fold(folder)),
+    }
+}
+
+pub fn walk_const_expr_fold<F: crate::Fold>(folder: &mut F, node: ConstExpr) -> ConstExpr {
+    ConstExpr {
+        value: Box::new((*node.value).fold(folder)),
+    }
+}
+
+pub fn walk_arithmetic_expr_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: ArithmeticExpr,
+) -> ArithmeticExpr {
+    ArithmeticExpr {
+        left: Box::new((*node.left).fold(folder)),
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_assign_expr_fold<F: crate::Fold>(folder: &mut F, node: AssignExpr) -> AssignExpr {
+    AssignExpr {
+        left: Box::new((*node.left).fold(folder)),
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_bitwise_expr_fold<F: crate::Fold>(folder: &mut F, node: BitwiseExpr) -> BitwiseExpr {
+    BitwiseExpr {
+        left: Box::new((*node.left).fold(folder)),
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_cast_expr_fold<F: crate::Fold>(folder: &mut F, node: CastExpr) -> CastExpr {
+    CastExpr {
+        left: Box::new((*node.left).fold(folder)),
+        ty: node.ty.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_cmp_expr_fold<F: crate::Fold>(folder: &mut F, node: CmpExpr) -> CmpExpr {
+    CmpExpr {
+        left: Box::new((*node.left).fold(folder)),
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_is_expr_fold<F: crate::Fold>(folder: &mut F, node: IsExpr) -> IsExpr {
+    IsExpr {
+        left: Box::new((*node.left).fold(folder)),
+        target: node.target.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_logical_expr_fold<F: crate::Fold>(folder: &mut F, node: LogicalExpr) -> LogicalExpr {
+    LogicalExpr {
+        left: Box::new((*node.left).fold(folder)),
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_match_expr_fold<F: crate::Fold>(folder: &mut F, node: MatchExpr) -> MatchExpr {
+    MatchExpr {
+        expr: Box::new((*node.expr).fold(folder)),
+        arms: node.arms.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_if_expr_fold<F: crate::Fold>(folder: &mut F, node: IfExpr) -> IfExpr {
+    IfExpr {
+        cond: Box::new((*node.cond).fold(folder)),
+        then_expr: Box::new((*node.then_expr).fold(folder)),
+        else_expr: Box::new((*node.else_expr).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_if_else_expr_fold<F: crate::Fold>(folder: &mut F, node: IfElseExpr) -> IfElseExpr {
+    IfElseExpr {
+        binding: node
+            .binding
+            .map(|(keyword, v, eq)| (keyword, v.fold(folder), eq)),
+        cond: Box::new((*node.cond).fold(folder)),
+        then_block: node.then_block.fold(folder),
+        else_branch: node
+            .else_branch
+            .map(|(keyword, v)| (keyword, Box::new((*v).fold(folder)))),
+        ..node
+    }
+}
+
+pub fn walk_range_expr_fold<F: crate::Fold>(folder: &mut F, node: RangeExpr) -> RangeExpr {
+    RangeExpr {
+        start: node.start.map(|v| Box::new((*v).fold(folder))),
+        end: node.end.map(|v| Box::new((*v).fold(folder))),
+        ..node
+    }
+}
+
+pub fn walk_call_expr_fold<F: crate::Fold>(folder: &mut F, node: CallExpr) -> CallExpr {
+    CallExpr {
+        target: Box::new((*node.target).fold(folder)),
+        args: node.args.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_index_expr_fold<F: crate::Fold>(folder: &mut F, node: IndexExpr) -> IndexExpr {
+    IndexExpr {
+        target: Box::new((*node.target).fold(folder)),
+        index: Box::new((*node.index).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_member_expr_fold<F: crate::Fold>(folder: &mut F, node: MemberExpr) -> MemberExpr {
+    MemberExpr {
+        target: Box::new((*node.target).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_try_expr_fold<F: crate::Fold>(folder: &mut F, node: TryExpr) -> TryExpr {
+    TryExpr {
+        target: Box::new((*node.target).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_ref_expr_fold<F: crate::Fold>(folder: &mut F, node: RefExpr) -> RefExpr {
+    RefExpr {
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_closure_expr_fold<F: crate::Fold>(folder: &mut F, node: ClosureExpr) -> ClosureExpr {
+    ClosureExpr {
+        params: node.params.map(|v| v.fold(folder)),
+        return_ty: node.return_ty.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        body: match node.body {
+            ClosureBody::Expr(v) => ClosureBody::Expr(Box::new((*v).fold(folder))),
+            ClosureBody::Block(v) => ClosureBody::Block(Box::new((*v).fold(folder))),
+        },
+        ..node
+    }
+}
+
+pub fn walk_neg_expr_fold<F: crate::Fold>(folder: &mut F, node: NegExpr) -> NegExpr {
+    NegExpr {
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_not_expr_fold<F: crate::Fold>(folder: &mut F, node: NotExpr) -> NotExpr {
+    NotExpr {
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_bit_not_expr_fold<F: crate::Fold>(folder: &mut F, node: BitNotExpr) -> BitNotExpr {
+    BitNotExpr {
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_array_expr_fold<F: crate::Fold>(folder: &mut F, node: ArrayExpr) -> ArrayExpr {
+    ArrayExpr {
+        items: node.items.map(|v| v.fold(folder)),
+        len: node.len.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_block_expr_fold<F: crate::Fold>(folder: &mut F, node: BlockExpr) -> BlockExpr {
+    BlockExpr {
+        block: node.block.fold(folder),
+    }
+}
+
+pub fn walk_group_expr_fold<F: crate::Fold>(folder: &mut F, node: GroupExpr) -> GroupExpr {
+    GroupExpr {
+        inner: Box::new((*node.inner).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_literal_expr_fold<F: crate::Fold>(_folder: &mut F, node: LiteralExpr) -> LiteralExpr {
+    node
+}
+
+pub fn walk_macro_expr_fold<F: crate::Fold>(_folder: &mut F, node: MacroExpr) -> MacroExpr {
+    node
+}
+
+pub fn walk_path_expr_fold<F: crate::Fold>(folder: &mut F, node: PathExpr) -> PathExpr {
+    PathExpr {
+        path: crate::walk_path_fold(folder, node.path),
+    }
+}
+
+pub fn walk_struct_expr_fold<F: crate::Fold>(folder: &mut F, node: StructExpr) -> StructExpr {
+    StructExpr {
+        path: crate::walk_path_fold(folder, node.path),
+        members: node
+            .members
+            .map(|v| crate::walk_member_value_fold(folder, v)),
+        spread: node.spread.map(|v| crate::walk_expr_spread_fold(folder, v)),
+        ..node
+    }
+}
+
+pub fn walk_tuple_expr_fold<F: crate::Fold>(folder: &mut F, node: TupleExpr) -> TupleExpr {
+    TupleExpr {
+        items: node.items.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_is_target_fold<F: crate::Fold>(folder: &mut F, node: IsTarget) -> IsTarget {
+    match node {
+        IsTarget::Type(v) => IsTarget::Type(v.fold(folder)),
+        IsTarget::Pattern(v) => IsTarget::Pattern(v.fold(folder)),
+    }
+}
+
+pub fn walk_match_arm_fold<F: crate::Fold>(folder: &mut F, node: Arm) -> Arm {
+    Arm {
+        pattern: node.pattern.fold(folder),
+        guard: node.guard.map(|v| Suffixed {
+            suffix: Box::new((*v.suffix).fold(folder)),
+            ..v
+        }),
+        body: Box::new((*node.body).fold(folder)),
+        ..node
+    }
+}
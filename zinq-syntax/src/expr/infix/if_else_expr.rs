@@ -0,0 +1,166 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Else, Eq, If, Let};
+
+use crate::{
+    Syntax,
+    expr::{BlockExpr, Expr},
+    pat::Pattern,
+    stmt::{BlockStmt, IfStmt, Stmt},
+};
+
+///
+/// ## If Else Expression
+/// a block bodied `if` whose value is the
+/// tail expression of the branch taken
+///
+/// ```
+/// let sign = if n < 0 { -1 } else if n == 0 { 0 } else { 1 };
+/// ```
+///
+/// the `else` branch is either a `BlockExpr`
+/// or another `IfElseExpr`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IfElseExpr {
+    pub keyword: If,
+    pub binding: Option<(Let, Pattern, Eq)>,
+    pub cond: Box<Expr>,
+    pub then_block: BlockStmt,
+    pub else_branch: Option<(Else, Box<Expr>)>,
+}
+
+impl IfElseExpr {
+    pub fn is_let(&self) -> bool {
+        self.binding.is_some()
+    }
+
+    pub fn has_else(&self) -> bool {
+        self.else_branch.is_some()
+    }
+}
+
+impl From<IfElseExpr> for Expr {
+    fn from(value: IfElseExpr) -> Self {
+        Self::IfElse(Box::new(value))
+    }
+}
+
+impl From<IfStmt> for IfElseExpr {
+    fn from(value: IfStmt) -> Self {
+        let else_branch = value.else_stmt.map(|(keyword, stmt)| {
+            let expr: Expr = match *stmt {
+                Stmt::If(v) => Self::from(v).into(),
+                Stmt::Block(v) => BlockExpr::from(v).into(),
+                v => panic!("expected BlockStmt or IfStmt, received {}", v.name()),
+            };
+
+            (keyword, Box::new(expr))
+        });
+
+        Self {
+            keyword: value.keyword,
+            binding: value.binding,
+            cond: Box::new(value.cond),
+            then_block: value.then_stmt,
+            else_branch,
+        }
+    }
+}
+
+impl Syntax for IfElseExpr {
+    fn name(&self) -> &str {
+        "Expr::IfElse"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_if_else_expr(self)
+            .then(|| crate::expr::walk_if_else_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_if_else_expr_mut(self)
+            .then(|| crate::expr::walk_if_else_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_if_else_expr_fold(folder, self);
+        folder.fold_if_else_expr(node)
+    }
+}
+
+impl std::fmt::Display for IfElseExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for IfElseExpr {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        parser.peek::<If>(cursor)
+    }
+}
+
+impl Parse for IfElseExpr {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        Ok(parser.parse::<IfStmt>(cursor)?.into())
+    }
+}
+
+impl Spanned for IfElseExpr {
+    fn span(&self) -> Span {
+        if let Some((_, expr)) = &self.else_branch {
+            return Span::join(self.keyword.span(), expr.span());
+        }
+
+        Span::join(self.keyword.span(), self.then_block.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::StmtParser;
+
+    #[test]
+    fn should_parse_let_init() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor =
+            Span::from_bytes(b"let x = if a > 0 { a } else if a == 0 { 0 } else { b };").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+        let init = &stmt.as_let().init.as_ref().unwrap().suffix;
+        let value = init.as_if_else();
+        let (_, else_if) = value.else_branch.as_ref().unwrap();
+        let (_, last) = else_if.as_if_else().else_branch.as_ref().unwrap();
+
+        debug_assert_eq!(value.then_block.tail.as_ref().unwrap().to_string(), "a");
+        debug_assert!(last.is_block());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_as_fn_tail() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"fn max(a: i32, b: i32) -> i32 {
+                if a > 0 { a } else { b }
+            }",
+        )
+        .cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+        let body = &stmt.as_fn().block;
+
+        debug_assert!(body.stmts.is_empty());
+        debug_assert!(body.tail.as_ref().unwrap().is_if_else());
+        Ok(())
+    }
+}
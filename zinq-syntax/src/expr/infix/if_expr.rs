@@ -27,11 +27,21 @@ impl Syntax for IfExpr {
         "Expr::If"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_if_expr(self);
-        self.cond.accept(visitor);
-        self.then_expr.accept(visitor);
-        self.else_expr.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_if_expr(self)
+            .then(|| crate::expr::walk_if_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_if_expr_mut(self)
+            .then(|| crate::expr::walk_if_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_if_expr_fold(folder, self);
+        folder.fold_if_expr(node)
     }
 }
 
@@ -76,4 +86,49 @@ mod tests {
         debug_assert_eq!(expr.to_string(), "a < 5 ? a < 3 ? 2 : 1 : -1");
         Ok(())
     }
+
+    #[test]
+    fn should_parse_in_closure_args() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"c ? xs.map(|x| x ? a : b) : ys.map(|y| y?)").cursor();
+        let expr = parser.parse_expr(&mut cursor)?;
+        let then_expr = &expr.as_if().then_expr.as_call().args;
+        let else_expr = &expr.as_if().else_expr.as_call().args;
+
+        debug_assert!(cursor.eof());
+        debug_assert_eq!(
+            then_expr.first().unwrap().value().to_string(),
+            "|x| x ? a : b"
+        );
+        debug_assert!(
+            then_expr
+                .first()
+                .unwrap()
+                .value()
+                .as_closure()
+                .body
+                .is_expr()
+        );
+        debug_assert_eq!(else_expr.first().unwrap().value().to_string(), "|y| y?");
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_deeply_nested() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let src = format!("{}0{}", "a ? ".repeat(20), " : 1".repeat(20));
+        let mut cursor = Span::from_str(&src).cursor();
+        let mut expr = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(cursor.eof());
+
+        for _ in 0..20 {
+            debug_assert!(expr.is_if());
+            debug_assert!(expr.as_if().else_expr.is_literal());
+            expr = *expr.as_if().then_expr.clone();
+        }
+
+        debug_assert!(expr.is_literal());
+        Ok(())
+    }
 }
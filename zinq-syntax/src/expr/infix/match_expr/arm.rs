@@ -1,5 +1,5 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{EqArrow, Suffixed};
+use zinq_token::{EqArrow, If, Suffixed};
 
 use crate::{
     Syntax,
@@ -7,21 +7,45 @@ use crate::{
     pat::Pattern,
 };
 
+///
+/// ## Arm
+/// `<pattern> if <guard> => <expr>`,
+/// the guard is optional
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Arm {
     pub pattern: Pattern,
+    pub guard: Option<Suffixed<If, Box<Expr>>>,
     pub arrow: EqArrow,
     pub body: Box<Expr>,
 }
 
+impl Arm {
+    pub fn has_guard(&self) -> bool {
+        self.guard.is_some()
+    }
+}
+
 impl Syntax for Arm {
     fn name(&self) -> &str {
         "Expr::Match::Arm"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_match_arm(self);
-        self.body.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_match_arm(self)
+            .then(|| crate::expr::walk_match_arm(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_match_arm_mut(self)
+            .then(|| crate::expr::walk_match_arm_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_match_arm_fold(folder, self);
+        folder.fold_match_arm(node)
     }
 }
 
@@ -44,7 +68,10 @@ impl Peek for Arm {
     ) -> zinq_error::Result<bool> {
         Ok(parser
             .peek::<Suffixed<Pattern, EqArrow>>(cursor)
-            .unwrap_or(false))
+            .unwrap_or(false)
+            || parser
+                .peek::<Suffixed<Pattern, If>>(cursor)
+                .unwrap_or(false))
     }
 }
 
@@ -54,11 +81,13 @@ impl Parse for Arm {
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
         let pattern = parser.parse::<Pattern>(cursor)?;
+        let guard = parser.parse::<Option<Suffixed<If, Box<Expr>>>>(cursor)?;
         let arrow = parser.parse::<EqArrow>(cursor)?;
         let body = parser.parse_expr(cursor)?;
 
         Ok(Self {
             pattern,
+            guard,
             arrow,
             body: Box::new(body),
         })
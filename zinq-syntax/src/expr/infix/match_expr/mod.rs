@@ -11,7 +11,8 @@ use crate::{Syntax, expr::Expr};
 /// ## Match Expression
 /// ```
 /// match <expr> {
-///     <path> => <expr>,
+///     <pattern> => <expr>,
+///     <pattern> if <expr> => <expr>,
 /// }
 /// ```
 ///
@@ -35,13 +36,21 @@ impl Syntax for MatchExpr {
         "Expr::Match"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_match_expr(self);
-        self.expr.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_match_expr(self)
+            .then(|| crate::expr::walk_match_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_match_expr_mut(self)
+            .then(|| crate::expr::walk_match_expr_mut(visitor, self))
+    }
 
-        for arm in self.arms.iter() {
-            arm.value().accept(visitor);
-        }
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_match_expr_fold(folder, self);
+        folder.fold_match_expr(node)
     }
 }
 
@@ -207,4 +216,166 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_block_arm() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"match self {
+            true => {
+                let v = 1;
+                v + 1
+            },
+            false => 0,
+        }",
+        )
+        .cursor();
+
+        let expr = parser.parse_expr(&mut cursor)?;
+        let arm = expr.as_match().arms.first().unwrap().value();
+
+        debug_assert_eq!(expr.as_match().arms.len(), 2);
+        debug_assert!(arm.body.as_block().block.has_tail());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_guard() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"match value {
+            Some(x) if x > 3 => x,
+            _ => 0,
+        }",
+        )
+        .cursor();
+
+        let expr = parser.parse_expr(&mut cursor)?;
+        let arm = expr.as_match().arms.first().unwrap().value();
+
+        debug_assert_eq!(expr.as_match().arms.len(), 2);
+        debug_assert!(arm.has_guard());
+        debug_assert!(arm.pattern.is_tuple_struct());
+        debug_assert_eq!(arm.guard.as_ref().unwrap().suffix.to_string(), "x > 3");
+        debug_assert!(!expr.as_match().arms.last().unwrap().value().has_guard());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_range_pattern() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"match value {
+            1..=5 => \"LOW\",
+            'a'..='z' => \"LETTER\",
+            b'a'..b'z' => \"BYTE\",
+            10.. => \"HIGH\",
+        }",
+        )
+        .cursor();
+
+        let expr = parser.parse_expr(&mut cursor)?;
+        let arms = expr
+            .as_match()
+            .arms
+            .iter()
+            .map(|arm| arm.value().pattern.clone())
+            .collect::<Vec<_>>();
+
+        debug_assert_eq!(arms.len(), 4);
+        debug_assert!(arms[0].as_range().is_inclusive());
+        debug_assert_eq!(arms[0].to_string(), "1..=5");
+        debug_assert!(arms[1].as_range().start.as_ref().unwrap().is_char());
+        debug_assert_eq!(arms[1].to_string(), "'a'..='z'");
+        debug_assert!(!arms[2].as_range().is_inclusive());
+        debug_assert_eq!(arms[2].to_string(), "b'a'..b'z'");
+        debug_assert!(arms[3].as_range().end.is_none());
+        debug_assert_eq!(arms[3].to_string(), "10..");
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_open_start_range_pattern() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"match value {
+            ..=5 => \"LOW\",
+            ..10 => \"MID\",
+            (a, ..) => a,
+        }",
+        )
+        .cursor();
+
+        let expr = parser.parse_expr(&mut cursor)?;
+        let arms = expr
+            .as_match()
+            .arms
+            .iter()
+            .map(|arm| arm.value().pattern.clone())
+            .collect::<Vec<_>>();
+
+        debug_assert_eq!(arms.len(), 3);
+        debug_assert!(arms[0].as_range().start.is_none());
+        debug_assert!(arms[0].as_range().is_inclusive());
+        debug_assert_eq!(arms[0].to_string(), "..=5");
+        debug_assert!(arms[1].as_range().start.is_none());
+        debug_assert_eq!(arms[1].to_string(), "..10");
+        debug_assert!(arms[2].is_tuple());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_negative_range_pattern() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"match value {
+            -5..=-1 => \"NEGATIVE\",
+            -1 => \"MINUS ONE\",
+            0..=5 => \"POSITIVE\",
+        }",
+        )
+        .cursor();
+
+        let expr = parser.parse_expr(&mut cursor)?;
+        let arms = expr
+            .as_match()
+            .arms
+            .iter()
+            .map(|arm| arm.value().pattern.clone())
+            .collect::<Vec<_>>();
+        let range = arms[0].as_range();
+
+        debug_assert_eq!(arms.len(), 3);
+        debug_assert!(range.start.as_ref().unwrap().is_negative());
+        debug_assert!(range.end.as_ref().unwrap().is_negative());
+        debug_assert_eq!(arms[0].to_string(), "-5..=-1");
+        debug_assert!(arms[1].as_literal().is_negative());
+        debug_assert_eq!(arms[1].to_string(), "-1");
+        debug_assert!(!arms[2].as_range().start.as_ref().unwrap().is_negative());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_bind_pattern() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"match value {
+            n @ 1..=5 | n @ 10 => n,
+            mut v @ Some(_) => v,
+        }",
+        )
+        .cursor();
+
+        let expr = parser.parse_expr(&mut cursor)?;
+        let first = &expr.as_match().arms.first().unwrap().value().pattern;
+        let last = &expr.as_match().arms.last().unwrap().value().pattern;
+
+        debug_assert!(first.is_or());
+        debug_assert!(first.as_or().left.as_bind().inner.is_range());
+        debug_assert_eq!(first.as_or().right.to_string(), "n @ 10");
+        debug_assert!(last.as_bind().mutable.is_some());
+        debug_assert!(last.as_bind().inner.is_tuple_struct());
+        debug_assert_eq!(last.to_string(), "mut v @ Some(_)");
+        Ok(())
+    }
 }
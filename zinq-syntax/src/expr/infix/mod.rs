@@ -1,13 +1,35 @@
+mod if_else_expr;
 mod if_expr;
 mod match_expr;
 mod range_expr;
 
+pub use if_else_expr::*;
 pub use if_expr::*;
 pub use match_expr::*;
 pub use range_expr::*;
 
+use crate::Visit;
+
 pub trait MatchVisitor {
     #![allow(unused)]
 
-    fn visit_match_arm(&mut self, node: &Arm) {}
+    fn visit_match_arm(&mut self, node: &Arm) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub trait MatchVisitorMut {
+    #![allow(unused)]
+
+    fn visit_match_arm_mut(&mut self, node: &mut Arm) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub trait MatchFold {
+    #![allow(unused)]
+
+    fn fold_match_arm(&mut self, node: Arm) -> Arm {
+        node
+    }
 }
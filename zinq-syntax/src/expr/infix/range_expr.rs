@@ -1,21 +1,27 @@
 use zinq_parse::{Span, Spanned};
-use zinq_token::DotDot;
 
-use crate::{Syntax, expr::Expr};
+use crate::{Syntax, expr::Expr, pat::RangeLimit};
 
 ///
 /// ## Range Expression
 /// - `..<end>`
 /// - `<start>..`
 /// - `<start>..<end>`
+/// - `<start>..=<end>`
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RangeExpr {
     pub start: Option<Box<Expr>>,
-    pub dots: DotDot,
+    pub limit: RangeLimit,
     pub end: Option<Box<Expr>>,
 }
 
+impl RangeExpr {
+    pub fn is_inclusive(&self) -> bool {
+        self.limit.is_inclusive()
+    }
+}
+
 impl From<RangeExpr> for Expr {
     fn from(value: RangeExpr) -> Self {
         Self::Range(value)
@@ -27,16 +33,21 @@ impl Syntax for RangeExpr {
         "Expr::Infix::Range"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_range_expr(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_range_expr(self)
+            .then(|| crate::expr::walk_range_expr(visitor, self))
+    }
 
-        if let Some(start) = &self.start {
-            start.accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_range_expr_mut(self)
+            .then(|| crate::expr::walk_range_expr_mut(visitor, self))
+    }
 
-        if let Some(end) = &self.end {
-            end.accept(visitor);
-        }
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_range_expr_fold(folder, self);
+        folder.fold_range_expr(node)
     }
 }
 
@@ -48,8 +59,8 @@ impl std::fmt::Display for RangeExpr {
 
 impl Spanned for RangeExpr {
     fn span(&self) -> Span {
-        let mut start = self.dots.span();
-        let mut end = self.dots.span();
+        let mut start = self.limit.span();
+        let mut end = self.limit.span();
 
         if let Some(v) = &self.start {
             start = v.span();
@@ -105,4 +116,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_inclusive() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+
+        for src in ["0..=3", "..=3"] {
+            let mut cursor = Span::from_str(src).cursor();
+            let expr = parser.parse_expr(&mut cursor)?;
+
+            debug_assert_eq!(expr.to_string(), src);
+            debug_assert!(expr.as_range().is_inclusive());
+            debug_assert!(expr.as_range().end.is_some());
+        }
+
+        let mut cursor = Span::from_bytes(b"0..=").cursor();
+        debug_assert!(parser.parse_expr(&mut cursor).is_err());
+
+        Ok(())
+    }
 }
@@ -1,20 +1,28 @@
 pub mod binary;
+mod const_expr;
+mod fold;
 pub mod infix;
 pub mod parser;
 pub mod postfix;
+mod precedence;
 pub mod prefix;
 pub mod primary;
 pub mod unary;
 mod visitor;
+mod visitor_mut;
 
 pub use binary::*;
+pub use const_expr::*;
+pub use fold::*;
 pub use infix::*;
 pub use parser::*;
 pub use postfix::*;
+pub use precedence::*;
 pub use prefix::*;
 pub use primary::*;
 pub use unary::*;
 pub use visitor::*;
+pub use visitor_mut::*;
 
 use zinq_error::Result;
 use zinq_parse::{Parse, Peek, Spanned};
@@ -35,11 +43,15 @@ pub enum Expr {
     Struct(StructExpr),
     Tuple(TupleExpr),
     Array(ArrayExpr),
+    Block(BlockExpr),
     Range(RangeExpr),
+    Macro(MacroExpr),
 
     /// ## Binary
     Arithmetic(ArithmeticExpr),
     Assign(AssignExpr),
+    Bitwise(BitwiseExpr),
+    Cast(CastExpr),
     Cmp(CmpExpr),
     Is(IsExpr),
     Logical(LogicalExpr),
@@ -48,16 +60,20 @@ pub enum Expr {
     Call(CallExpr),
     Index(IndexExpr),
     Member(MemberExpr),
+    Try(TryExpr),
 
     /// ## Unary
     Not(NotExpr),
     Neg(NegExpr),
+    BitNot(BitNotExpr),
 
     /// ## Prefix
     Ref(RefExpr),
+    Closure(Box<ClosureExpr>),
 
     /// ## Infix
     If(IfExpr),
+    IfElse(Box<IfElseExpr>),
     Match(MatchExpr),
 }
 
@@ -104,6 +120,10 @@ impl Expr {
         }
     }
 
+    pub fn is_block(&self) -> bool {
+        matches!(self, Self::Block(_))
+    }
+
     pub fn is_range(&self) -> bool {
         match self {
             Self::Range(_) => true,
@@ -111,6 +131,10 @@ impl Expr {
         }
     }
 
+    pub fn is_macro(&self) -> bool {
+        matches!(self, Self::Macro(_))
+    }
+
     pub fn is_arithmetic(&self) -> bool {
         match self {
             Self::Arithmetic(_) => true,
@@ -125,6 +149,14 @@ impl Expr {
         }
     }
 
+    pub fn is_bitwise(&self) -> bool {
+        matches!(self, Self::Bitwise(_))
+    }
+
+    pub fn is_cast(&self) -> bool {
+        matches!(self, Self::Cast(_))
+    }
+
     pub fn is_cmp(&self) -> bool {
         match self {
             Self::Cmp(_) => true,
@@ -174,6 +206,14 @@ impl Expr {
         }
     }
 
+    pub fn is_closure(&self) -> bool {
+        matches!(self, Self::Closure(_))
+    }
+
+    pub fn is_try(&self) -> bool {
+        matches!(self, Self::Try(_))
+    }
+
     pub fn is_not(&self) -> bool {
         match self {
             Self::Not(_) => true,
@@ -188,6 +228,10 @@ impl Expr {
         }
     }
 
+    pub fn is_bit_not(&self) -> bool {
+        matches!(self, Self::BitNot(_))
+    }
+
     pub fn is_if(&self) -> bool {
         match self {
             Self::If(_) => true,
@@ -195,6 +239,10 @@ impl Expr {
         }
     }
 
+    pub fn is_if_else(&self) -> bool {
+        matches!(self, Self::IfElse(_))
+    }
+
     pub fn is_match(&self) -> bool {
         match self {
             Self::Match(_) => true,
@@ -244,6 +292,13 @@ impl Expr {
         }
     }
 
+    pub fn as_block(&self) -> &BlockExpr {
+        match self {
+            Self::Block(v) => v,
+            v => panic!("expected BlockExpr, received {}", v.name()),
+        }
+    }
+
     pub fn as_range(&self) -> &RangeExpr {
         match self {
             Self::Range(v) => v,
@@ -251,6 +306,34 @@ impl Expr {
         }
     }
 
+    pub fn as_macro(&self) -> &MacroExpr {
+        match self {
+            Self::Macro(v) => v,
+            v => panic!("expected MacroExpr, received {}", v.name()),
+        }
+    }
+
+    pub fn as_bitwise(&self) -> &BitwiseExpr {
+        match self {
+            Self::Bitwise(v) => v,
+            v => panic!("expected BitwiseExpr, received {}", v.name()),
+        }
+    }
+
+    pub fn as_cast(&self) -> &CastExpr {
+        match self {
+            Self::Cast(v) => v,
+            v => panic!("expected CastExpr, received {}", v.name()),
+        }
+    }
+
+    pub fn as_try(&self) -> &TryExpr {
+        match self {
+            Self::Try(v) => v,
+            v => panic!("expected TryExpr, received {}", v.name()),
+        }
+    }
+
     pub fn as_arithmetic(&self) -> &ArithmeticExpr {
         match self {
             Self::Arithmetic(v) => v,
@@ -314,6 +397,13 @@ impl Expr {
         }
     }
 
+    pub fn as_closure(&self) -> &ClosureExpr {
+        match self {
+            Self::Closure(v) => v,
+            v => panic!("expected ClosureExpr, received {}", v.name()),
+        }
+    }
+
     pub fn as_not(&self) -> &NotExpr {
         match self {
             Self::Not(v) => v,
@@ -328,6 +418,13 @@ impl Expr {
         }
     }
 
+    pub fn as_bit_not(&self) -> &BitNotExpr {
+        match self {
+            Self::BitNot(v) => v,
+            v => panic!("expected BitNotExpr, received {}", v.name()),
+        }
+    }
+
     pub fn as_if(&self) -> &IfExpr {
         match self {
             Self::If(v) => v,
@@ -335,6 +432,13 @@ impl Expr {
         }
     }
 
+    pub fn as_if_else(&self) -> &IfElseExpr {
+        match self {
+            Self::IfElse(v) => v,
+            v => panic!("expected IfElseExpr, received {}", v.name()),
+        }
+    }
+
     pub fn as_match(&self) -> &MatchExpr {
         match self {
             Self::Match(v) => v,
@@ -352,48 +456,46 @@ impl Syntax for Expr {
             Self::Struct(v) => v.name(),
             Self::Tuple(v) => v.name(),
             Self::Array(v) => v.name(),
+            Self::Block(v) => v.name(),
             Self::Range(v) => v.name(),
+            Self::Macro(v) => v.name(),
             Self::Arithmetic(v) => v.name(),
             Self::Assign(v) => v.name(),
+            Self::Bitwise(v) => v.name(),
+            Self::Cast(v) => v.name(),
             Self::Cmp(v) => v.name(),
             Self::Is(v) => v.name(),
             Self::Logical(v) => v.name(),
             Self::Call(v) => v.name(),
             Self::Index(v) => v.name(),
             Self::Member(v) => v.name(),
+            Self::Try(v) => v.name(),
             Self::Ref(v) => v.name(),
+            Self::Closure(v) => v.name(),
             Self::Not(v) => v.name(),
             Self::Neg(v) => v.name(),
+            Self::BitNot(v) => v.name(),
             Self::If(v) => v.name(),
+            Self::IfElse(v) => v.name(),
             Self::Match(v) => v.name(),
         }
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_expr(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_expr(self)
+            .then(|| crate::expr::walk_expr(visitor, self))
+    }
 
-        match self {
-            Self::Arithmetic(v) => v.accept(visitor),
-            Self::Array(v) => v.accept(visitor),
-            Self::Assign(v) => v.accept(visitor),
-            Self::Call(v) => v.accept(visitor),
-            Self::Cmp(v) => v.accept(visitor),
-            Self::Group(v) => v.accept(visitor),
-            Self::If(v) => v.accept(visitor),
-            Self::Index(v) => v.accept(visitor),
-            Self::Is(v) => v.accept(visitor),
-            Self::Literal(v) => v.accept(visitor),
-            Self::Logical(v) => v.accept(visitor),
-            Self::Match(v) => v.accept(visitor),
-            Self::Member(v) => v.accept(visitor),
-            Self::Neg(v) => v.accept(visitor),
-            Self::Not(v) => v.accept(visitor),
-            Self::Path(v) => v.accept(visitor),
-            Self::Range(v) => v.accept(visitor),
-            Self::Ref(v) => v.accept(visitor),
-            Self::Struct(v) => v.accept(visitor),
-            Self::Tuple(v) => v.accept(visitor),
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_expr_mut(self)
+            .then(|| crate::expr::walk_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_expr_fold(folder, self);
+        folder.fold_expr(node)
     }
 }
 
@@ -406,19 +508,27 @@ impl std::fmt::Display for Expr {
             Self::Struct(v) => write!(f, "{}", v),
             Self::Tuple(v) => write!(f, "{}", v),
             Self::Array(v) => write!(f, "{}", v),
+            Self::Block(v) => write!(f, "{}", v),
             Self::Range(v) => write!(f, "{}", v),
+            Self::Macro(v) => write!(f, "{}", v),
             Self::Arithmetic(v) => write!(f, "{}", v),
             Self::Assign(v) => write!(f, "{}", v),
+            Self::Bitwise(v) => write!(f, "{}", v),
+            Self::Cast(v) => write!(f, "{}", v),
             Self::Cmp(v) => write!(f, "{}", v),
             Self::Is(v) => write!(f, "{}", v),
             Self::Logical(v) => write!(f, "{}", v),
             Self::Call(v) => write!(f, "{}", v),
             Self::Index(v) => write!(f, "{}", v),
             Self::Member(v) => write!(f, "{}", v),
+            Self::Try(v) => write!(f, "{}", v),
             Self::Ref(v) => write!(f, "{}", v),
+            Self::Closure(v) => write!(f, "{}", v),
             Self::Not(v) => write!(f, "{}", v),
             Self::Neg(v) => write!(f, "{}", v),
+            Self::BitNot(v) => write!(f, "{}", v),
             Self::If(v) => write!(f, "{}", v),
+            Self::IfElse(v) => write!(f, "{}", v),
             Self::Match(v) => write!(f, "{}", v),
         }
     }
@@ -451,19 +561,27 @@ impl Spanned for Expr {
             Self::Struct(v) => v.span(),
             Self::Tuple(v) => v.span(),
             Self::Array(v) => v.span(),
+            Self::Block(v) => v.span(),
             Self::Range(v) => v.span(),
+            Self::Macro(v) => v.span(),
             Self::Arithmetic(v) => v.span(),
             Self::Assign(v) => v.span(),
+            Self::Bitwise(v) => v.span(),
+            Self::Cast(v) => v.span(),
             Self::Cmp(v) => v.span(),
             Self::Is(v) => v.span(),
             Self::Logical(v) => v.span(),
             Self::Call(v) => v.span(),
             Self::Index(v) => v.span(),
             Self::Member(v) => v.span(),
+            Self::Try(v) => v.span(),
             Self::Ref(v) => v.span(),
+            Self::Closure(v) => v.span(),
             Self::Not(v) => v.span(),
             Self::Neg(v) => v.span(),
+            Self::BitNot(v) => v.span(),
             Self::If(v) => v.span(),
+            Self::IfElse(v) => v.span(),
             Self::Match(v) => v.span(),
         }
     }
@@ -1,32 +1,31 @@
 use zinq_error::Result;
 use zinq_parse::Cursor;
 use zinq_token::{
-    And, AndAnd, Arithmetic, Cmp, Colon, Comma, Dot, DotDot, Eq, Ident, Is, LBrace, LBracket,
-    LParen, Match, Minus, Mut, Not, OrOr, Plus, Punctuated, Question, RBrace, RBracket, RParen,
-    Slash, Star,
+    And, Arithmetic, As, Assign, Bitwise, Cmp, Colon, Comma, Dot, DotDot, Ident, Is, LBrace,
+    LBracket, LParen, Logical, Match, Minus, Mut, Not, Or, Punctuated, Question, RArrow, RBrace,
+    RBracket, RParen, Suffixed, Tilde,
 };
 
 use crate::{
     expr::{
-        ArithmeticExpr, Arm, ArrayExpr, AssignExpr, CallExpr, CmpExpr, Expr, GroupExpr, IfExpr,
-        IndexExpr, IsExpr, LiteralExpr, LogicalExpr, MatchExpr, MemberExpr, NegExpr, NotExpr,
-        PathExpr, RangeExpr, RefExpr, StructExpr, TupleExpr,
+        ArithmeticExpr, Arm, ArrayExpr, AssignExpr, Assoc, BitNotExpr, BitwiseExpr, BlockExpr,
+        CallExpr, CastExpr, ClosureBody, ClosureExpr, CmpExpr, Expr, GroupExpr, IfElseExpr, IfExpr,
+        IndexExpr, Infix, IsExpr, IsTarget, LiteralExpr, LogicalExpr, MacroExpr, MatchExpr,
+        MemberExpr, NegExpr, NotExpr, PathExpr, Precedence, RangeExpr, RefExpr, StructExpr,
+        TryExpr, TupleExpr,
     },
+    param::FnParam,
+    pat::RangeLimit,
+    stmt::BlockStmt,
     ty::Type,
 };
 
 pub trait ExprParser {
     fn parse_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_assign_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
+    fn parse_cond_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
+    fn parse_binary_expr(&mut self, cursor: &mut Cursor, min: Precedence) -> Result<Expr>;
+    fn parse_closure_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
     fn parse_match_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_if_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_range_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_or_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_and_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_is_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_cmp_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_term_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
-    fn parse_factor_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
     fn parse_unary_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
     fn parse_postfix_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
     fn parse_primary_expr(&mut self, cursor: &mut Cursor) -> Result<Expr>;
@@ -34,180 +33,288 @@ pub trait ExprParser {
 
 impl ExprParser for zinq_parse::ZinqParser {
     fn parse_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        self.parse_assign_expr(cursor)
+        self.parse_binary_expr(cursor, Precedence::Lowest)
     }
 
-    fn parse_assign_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let expr = self.parse_match_expr(cursor)?;
-
-        if self.peek::<Eq>(cursor).unwrap_or(false) {
-            let eq = self.parse::<Eq>(cursor)?;
-            let right = self.parse_assign_expr(cursor)?;
+    ///
+    /// ## parse_cond_expr
+    /// the head of an `if`, `while`, `for` or `match`,
+    /// a `name {` there opens the body so a struct
+    /// expression has to be wrapped in `(...)`
+    ///
+    fn parse_cond_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
+        Ok(self.binary(cursor, Precedence::Lowest, false)?.0)
+    }
 
-            return Ok(AssignExpr::new(expr, eq, right).into());
-        }
+    ///
+    /// ## parse_binary_expr
+    /// precedence climbing over `INFIX_TABLE`, only
+    /// operators binding at least as tight as `min`
+    /// are folded into the result
+    ///
+    fn parse_binary_expr(&mut self, cursor: &mut Cursor, min: Precedence) -> Result<Expr> {
+        Ok(self.binary(cursor, min, true)?.0)
+    }
 
-        Ok(expr)
+    fn parse_closure_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
+        Ok(self.closure(cursor, true)?.0)
     }
 
     fn parse_match_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        if self.peek::<Match>(cursor).unwrap_or(false) {
-            let keyword = self.parse::<Match>(cursor)?;
-            let expr = self.parse_assign_expr(cursor)?;
-            let left_brace = self.parse::<LBrace>(cursor)?;
-            let arms = self.parse::<Punctuated<Arm, Comma>>(cursor)?;
-            let right_brace = self.parse::<RBrace>(cursor)?;
-
-            return Ok(MatchExpr {
-                keyword,
-                expr: Box::new(expr),
-                left_brace,
-                arms,
-                right_brace,
-            }
-            .into());
-        }
-
-        Ok(self.parse_if_expr(cursor)?)
+        Ok(self.match_expr(cursor, true)?.0)
     }
 
-    fn parse_if_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let expr = self.parse_range_expr(cursor)?;
-
-        if self.peek::<Question>(cursor).unwrap_or(false) {
-            let question = self.parse::<Question>(cursor)?;
-            let then_expr = self.parse_assign_expr(cursor)?;
-            let colon = self.parse::<Colon>(cursor)?;
-            let else_expr = self.parse_assign_expr(cursor)?;
-
-            return Ok(IfExpr {
-                cond: Box::new(expr),
-                question,
-                then_expr: Box::new(then_expr),
-                colon,
-                else_expr: Box::new(else_expr),
-            }
-            .into());
-        }
-
-        Ok(expr)
+    fn parse_unary_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
+        Ok(self.unary(cursor, true)?.0)
     }
 
-    fn parse_range_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let expr = self.parse_or_expr(cursor)?;
-
-        if self.peek::<DotDot>(cursor).unwrap_or(false) {
-            let dots = self.parse::<DotDot>(cursor)?;
-            let mut end = None;
-
-            if self.peek::<Expr>(cursor).unwrap_or(false) {
-                end = Some(Box::new(self.parse_or_expr(cursor)?));
-            }
-
-            return Ok(RangeExpr {
-                start: Some(Box::new(expr)),
-                dots,
-                end,
-            }
-            .into());
-        }
-
-        Ok(expr)
+    ///
+    /// ## parse_postfix_expr
+    /// calls, members, indexing and `?`, a `?` that is
+    /// followed by an expression and a `:` starts a
+    /// ternary and is left for `parse_binary_expr`
+    ///
+    fn parse_postfix_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
+        Ok(self.postfix(cursor, true)?.0)
     }
 
-    fn parse_or_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let mut expr = self.parse_and_expr(cursor)?;
-
-        while self.peek::<OrOr>(cursor).unwrap_or(false) {
-            let op = self.parse::<OrOr>(cursor)?;
-            let right = self.parse_and_expr(cursor)?;
-
-            expr = LogicalExpr::new(expr, op.into(), right).into();
-        }
-
-        Ok(expr)
+    fn parse_primary_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
+        self.primary(cursor, true)
     }
+}
 
-    fn parse_and_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let mut expr = self.parse_is_expr(cursor)?;
+///
+/// ## Operand
+/// an expression along with the ternary `postfix`
+/// read ahead after it, which every level hands
+/// back up until `binary` reaches its `?`
+///
+type Operand = (Expr, Option<Box<Ternary>>);
+
+///
+/// ## ExprChain
+/// the levels behind `ExprParser`, `structs` is off in
+/// the head of an `if`, `while`, `for` or `match` and
+/// stays off down the chain, anything between delimiters
+/// is parsed through `Parse` and starts over with it on
+///
+trait ExprChain {
+    fn binary(&mut self, cursor: &mut Cursor, min: Precedence, structs: bool) -> Result<Operand>;
+    fn infix(
+        &mut self,
+        cursor: &mut Cursor,
+        infix: Infix,
+        expr: Expr,
+        ternary: Option<Box<Ternary>>,
+        next: Precedence,
+        structs: bool,
+    ) -> Result<Operand>;
+    fn closure(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand>;
+    fn match_expr(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand>;
+    fn unary(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand>;
+    fn postfix(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand>;
+    fn primary(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Expr>;
+}
 
-        while self.peek::<AndAnd>(cursor).unwrap_or(false) {
-            let op = self.parse::<AndAnd>(cursor)?;
-            let right = self.parse_is_expr(cursor)?;
+impl ExprChain for zinq_parse::ZinqParser {
+    fn binary(&mut self, cursor: &mut Cursor, min: Precedence, structs: bool) -> Result<Operand> {
+        let (mut expr, mut ternary) = self.closure(cursor, structs)?;
+        let mut last = None;
 
-            expr = LogicalExpr::new(expr, op.into(), right).into();
-        }
-
-        Ok(expr)
-    }
+        while let Some((infix, prec, assoc)) = Infix::peek(cursor, self) {
+            if prec < min {
+                break;
+            }
 
-    fn parse_is_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let mut expr = self.parse_cmp_expr(cursor)?;
+            if assoc == Assoc::None && last == Some(prec) {
+                let kind = match infix {
+                    Infix::Cmp => "comparison",
+                    _ => "range",
+                };
+
+                return Err(cursor.error(
+                    zinq_error::BAD_ARGUMENTS,
+                    &format!(
+                        "{} operators cannot be chained, wrap one side in '(...)'",
+                        kind
+                    ),
+                ));
+            }
 
-        while self.peek::<Is>(cursor).unwrap_or(false) {
-            let keyword = self.parse::<Is>(cursor)?;
-            let ty = self.parse::<Type>(cursor)?;
+            let next = match assoc {
+                Assoc::Right => prec,
+                Assoc::Left | Assoc::None => prec.next(),
+            };
 
-            expr = IsExpr::new(expr, keyword, ty).into();
+            (expr, ternary) = self.infix(cursor, infix, expr, ternary, next, structs)?;
+            last = Some(prec);
         }
 
-        Ok(expr)
+        Ok((expr, ternary))
     }
 
-    fn parse_cmp_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let mut expr = self.parse_term_expr(cursor)?;
+    // fold the operator at the cursor into `expr`, kept out of
+    // `binary` so the frames of nested operands stay small
+    fn infix(
+        &mut self,
+        cursor: &mut Cursor,
+        infix: Infix,
+        expr: Expr,
+        ternary: Option<Box<Ternary>>,
+        next: Precedence,
+        structs: bool,
+    ) -> Result<Operand> {
+        let operand = match infix {
+            Infix::Assign => {
+                let op = self.parse::<Assign>(cursor)?;
+                let (right, ternary) = self.binary(cursor, next, structs)?;
+                (AssignExpr::new(expr, op, right).into(), ternary)
+            }
+            Infix::Ternary => {
+                let (question, then_expr) = match ternary {
+                    Some(mut v) => {
+                        cursor.merge(v.cursor.commit());
+                        (v.question, v.then_expr)
+                    }
+                    None => (
+                        self.parse::<Question>(cursor)?,
+                        self.binary(cursor, Precedence::Assign, structs)?.0,
+                    ),
+                };
+
+                let colon = self.parse::<Colon>(cursor)?;
+                let (else_expr, ternary) = self.binary(cursor, Precedence::Assign, structs)?;
+
+                let expr = IfExpr {
+                    cond: Box::new(expr),
+                    question,
+                    then_expr: Box::new(then_expr),
+                    colon,
+                    else_expr: Box::new(else_expr),
+                };
+
+                (expr.into(), ternary)
+            }
+            Infix::Range => {
+                let limit = self.parse::<RangeLimit>(cursor)?;
+                let mut end = None;
+                let mut ternary = None;
+
+                if limit.is_inclusive() || self.peek::<Expr>(cursor).unwrap_or(false) {
+                    let (right, next_ternary) = self.binary(cursor, next, structs)?;
+                    end = Some(Box::new(right));
+                    ternary = next_ternary;
+                }
 
-        while self.peek::<Cmp>(cursor).unwrap_or(false) {
-            let op = self.parse::<Cmp>(cursor)?;
-            let right = self.parse_term_expr(cursor)?;
+                let expr = RangeExpr {
+                    start: Some(Box::new(expr)),
+                    limit,
+                    end,
+                };
 
-            expr = CmpExpr::new(expr, op, right).into();
-        }
+                (expr.into(), ternary)
+            }
+            Infix::Logical => {
+                let op = self.parse::<Logical>(cursor)?;
+                let (right, ternary) = self.binary(cursor, next, structs)?;
+                (LogicalExpr::new(expr, op, right).into(), ternary)
+            }
+            Infix::Is => {
+                let keyword = self.parse::<Is>(cursor)?;
+                let target = self.parse::<IsTarget>(cursor)?;
+                (IsExpr::new(expr, keyword, target).into(), None)
+            }
+            Infix::Cast => {
+                let keyword = self.parse::<As>(cursor)?;
+                let ty = self.parse::<Type>(cursor)?;
+                (CastExpr::new(expr, keyword, ty).into(), None)
+            }
+            Infix::Cmp => {
+                let op = self.parse::<Cmp>(cursor)?;
+                let (right, ternary) = self.binary(cursor, next, structs)?;
+                (CmpExpr::new(expr, op, right).into(), ternary)
+            }
+            Infix::Bitwise => {
+                let op = self.parse::<Bitwise>(cursor)?;
+                let (right, ternary) = self.binary(cursor, next, structs)?;
+                (BitwiseExpr::new(expr, op, right).into(), ternary)
+            }
+            Infix::Arithmetic => {
+                let op = self.parse::<Arithmetic>(cursor)?;
+                let (right, ternary) = self.binary(cursor, next, structs)?;
+                (ArithmeticExpr::new(expr, op, right).into(), ternary)
+            }
+        };
 
-        Ok(expr)
+        Ok(operand)
     }
 
-    fn parse_term_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let mut expr = self.parse_factor_expr(cursor)?;
+    fn closure(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand> {
+        if self.peek::<Or>(cursor).unwrap_or(false) {
+            // `||` is read as two `|` with nothing between them
+            let left_or = self.parse::<Or>(cursor)?;
+            let params = self.parse::<Punctuated<FnParam, Comma>>(cursor)?;
+            let right_or = self.parse::<Or>(cursor)?;
+            let return_ty = self.parse::<Option<Suffixed<RArrow, Type>>>(cursor)?;
+            let body = match return_ty.is_some() || self.peek::<LBrace>(cursor).unwrap_or(false) {
+                true => ClosureBody::Block(self.parse::<Box<BlockStmt>>(cursor)?),
+                false => ClosureBody::Expr(Box::new(
+                    self.binary(cursor, Precedence::Lowest, structs)?.0,
+                )),
+            };
 
-        while self.peek::<Plus>(cursor).unwrap_or(false)
-            || self.peek::<Minus>(cursor).unwrap_or(false)
-        {
-            let op = self.parse::<Arithmetic>(cursor)?;
-            let right = self.parse_factor_expr(cursor)?;
+            let expr = ClosureExpr {
+                left_or,
+                params,
+                right_or,
+                return_ty,
+                body,
+            };
 
-            expr = ArithmeticExpr::new(expr, op, right).into();
+            return Ok((expr.into(), None));
         }
 
-        Ok(expr)
+        self.match_expr(cursor, structs)
     }
 
-    fn parse_factor_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let mut expr = self.parse_unary_expr(cursor)?;
+    fn match_expr(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand> {
+        if self.peek::<Match>(cursor).unwrap_or(false) {
+            let keyword = self.parse::<Match>(cursor)?;
+            let expr = self.parse_cond_expr(cursor)?;
+            let left_brace = self.parse::<LBrace>(cursor)?;
+            let arms = self.parse::<Punctuated<Arm, Comma>>(cursor)?;
+            let right_brace = self.parse::<RBrace>(cursor)?;
 
-        while self.peek::<Star>(cursor).unwrap_or(false)
-            || self.peek::<Slash>(cursor).unwrap_or(false)
-        {
-            let op = self.parse::<Arithmetic>(cursor)?;
-            let right = self.parse_unary_expr(cursor)?;
+            let expr = MatchExpr {
+                keyword,
+                expr: Box::new(expr),
+                left_brace,
+                arms,
+                right_brace,
+            };
 
-            expr = ArithmeticExpr::new(expr, op, right).into();
+            return Ok((expr.into(), None));
         }
 
-        Ok(expr)
+        self.unary(cursor, structs)
     }
 
-    fn parse_unary_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
+    fn unary(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand> {
         if self.peek::<Not>(cursor).unwrap_or(false) {
             let not = self.parse::<Not>(cursor)?;
-            let right = self.parse_unary_expr(cursor)?;
+            let (right, ternary) = self.unary(cursor, structs)?;
 
-            return Ok(NotExpr::new(not, right).into());
+            return Ok((NotExpr::new(not, right).into(), ternary));
         } else if self.peek::<Minus>(cursor).unwrap_or(false) {
             let minus = self.parse::<Minus>(cursor)?;
-            let right = self.parse_unary_expr(cursor)?;
+            let (right, ternary) = self.unary(cursor, structs)?;
+
+            return Ok((NegExpr::new(minus, right).into(), ternary));
+        } else if self.peek::<Tilde>(cursor).unwrap_or(false) {
+            let tilde = self.parse::<Tilde>(cursor)?;
+            let (right, ternary) = self.unary(cursor, structs)?;
 
-            return Ok(NegExpr::new(minus, right).into());
+            return Ok((BitNotExpr::new(tilde, right).into(), ternary));
         } else if self.peek::<And>(cursor).unwrap_or(false) {
             let and = self.parse::<And>(cursor)?;
             let mut mutable = None;
@@ -216,28 +323,30 @@ impl ExprParser for zinq_parse::ZinqParser {
                 mutable = Some(self.parse::<Mut>(cursor)?);
             }
 
-            let right = self.parse_unary_expr(cursor)?;
-            return Ok(RefExpr::new(and, mutable, right).into());
-        } else if self.peek::<DotDot>(cursor).unwrap_or(false) {
-            let dots = self.parse::<DotDot>(cursor)?;
-            let end = match self.parse_unary_expr(cursor) {
-                Err(_) => None,
-                Ok(v) => Some(Box::new(v)),
+            let (right, ternary) = self.unary(cursor, structs)?;
+            return Ok((RefExpr::new(and, mutable, right).into(), ternary));
+        } else if self.peek::<RangeLimit>(cursor).unwrap_or(false) {
+            let limit = self.parse::<RangeLimit>(cursor)?;
+            let (end, ternary) = match self.unary(cursor, structs) {
+                Err(_) if !limit.is_inclusive() => (None, None),
+                Err(err) => return Err(err),
+                Ok((v, ternary)) => (Some(Box::new(v)), ternary),
             };
 
-            return Ok(RangeExpr {
+            let expr = RangeExpr {
                 start: None,
-                dots,
+                limit,
                 end,
-            }
-            .into());
+            };
+
+            return Ok((expr.into(), ternary));
         }
 
-        self.parse_postfix_expr(cursor)
+        self.postfix(cursor, structs)
     }
 
-    fn parse_postfix_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
-        let mut expr = self.parse_primary_expr(cursor)?;
+    fn postfix(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Operand> {
+        let mut expr = self.primary(cursor, structs)?;
 
         while !cursor.eof() {
             if self.peek::<LParen>(cursor).unwrap_or(false) {
@@ -265,15 +374,22 @@ impl ExprParser for zinq_parse::ZinqParser {
                     right_bracket,
                 }
                 .into();
+            } else if self.peek::<Question>(cursor).unwrap_or(false) {
+                if let Some(ternary) = read_ternary(cursor, self, structs) {
+                    return Ok((expr, Some(ternary)));
+                }
+
+                let question = self.parse::<Question>(cursor)?;
+                expr = TryExpr::new(expr, question).into();
             } else {
                 break;
             }
         }
 
-        Ok(expr)
+        Ok((expr, None))
     }
 
-    fn parse_primary_expr(&mut self, cursor: &mut Cursor) -> Result<Expr> {
+    fn primary(&mut self, cursor: &mut Cursor, structs: bool) -> Result<Expr> {
         if self.peek::<LiteralExpr>(cursor).unwrap_or(false) {
             return Ok(self.parse::<LiteralExpr>(cursor)?.into());
         }
@@ -286,7 +402,11 @@ impl ExprParser for zinq_parse::ZinqParser {
             return Ok(self.parse::<TupleExpr>(cursor)?.into());
         }
 
-        if self.peek::<StructExpr>(cursor).unwrap_or(false) {
+        if self.peek::<MacroExpr>(cursor).unwrap_or(false) {
+            return Ok(self.parse::<MacroExpr>(cursor)?.into());
+        }
+
+        if structs && self.peek::<StructExpr>(cursor).unwrap_or(false) {
             return Ok(self.parse::<StructExpr>(cursor)?.into());
         }
 
@@ -298,9 +418,52 @@ impl ExprParser for zinq_parse::ZinqParser {
             return Ok(self.parse::<GroupExpr>(cursor)?.into());
         }
 
+        if self.peek::<BlockExpr>(cursor).unwrap_or(false) {
+            return Ok(self.parse::<BlockExpr>(cursor)?.into());
+        }
+
+        if self.peek::<IfElseExpr>(cursor).unwrap_or(false) {
+            return Ok(self.parse::<IfElseExpr>(cursor)?.into());
+        }
+
         Err(cursor.error(
             zinq_error::NOT_FOUND,
             &format!("unexpected token '{}'", *cursor.peek()? as char),
         ))
     }
 }
+
+// `cond ? a : b` and `a?` share a `?`, only a
+// `:` after the next expression makes it a ternary
+fn read_ternary(
+    cursor: &Cursor,
+    parser: &zinq_parse::ZinqParser,
+    structs: bool,
+) -> Option<Box<Ternary>> {
+    let mut fork = cursor.fork();
+    let mut fork_parser = parser.clone();
+    let question = fork_parser.parse::<Question>(&mut fork).ok()?;
+    let (then_expr, _) = fork_parser
+        .binary(&mut fork, Precedence::Assign, structs)
+        .ok()?;
+
+    fork_parser.peek::<Colon>(&fork).unwrap_or(false).then(|| {
+        Box::new(Ternary {
+            question,
+            then_expr,
+            cursor: fork,
+        })
+    })
+}
+
+///
+/// ## Ternary
+/// the `? then` of a ternary read by `postfix`, kept
+/// so `binary` doesn't parse it again, which would be
+/// exponential in how deep ternaries nest
+///
+struct Ternary {
+    question: Question,
+    then_expr: Expr,
+    cursor: Cursor,
+}
@@ -43,13 +43,21 @@ impl Syntax for CallExpr {
         "Expr::Postfix::Call"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_call_expr(self);
-        self.target.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_call_expr(self)
+            .then(|| crate::expr::walk_call_expr(visitor, self))
+    }
 
-        for arg in self.args.iter() {
-            arg.value().accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_call_expr_mut(self)
+            .then(|| crate::expr::walk_call_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_call_expr_fold(folder, self);
+        folder.fold_call_expr(node)
     }
 }
 
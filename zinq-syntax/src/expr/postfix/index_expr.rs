@@ -5,7 +5,8 @@ use crate::{Syntax, expr::Expr};
 
 ///
 /// ## Index Expression
-/// `arr[0]`
+/// `arr[0]` reads a single item, a range index
+/// like `arr[1..3]`, `arr[..=2]` or `arr[..]` slices
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IndexExpr {
@@ -15,6 +16,13 @@ pub struct IndexExpr {
     pub right_bracket: RBracket,
 }
 
+impl IndexExpr {
+    /// the index is a `RangeExpr`
+    pub fn is_slice(&self) -> bool {
+        self.index.is_range()
+    }
+}
+
 impl From<IndexExpr> for Expr {
     fn from(value: IndexExpr) -> Self {
         Self::Index(value)
@@ -26,10 +34,21 @@ impl Syntax for IndexExpr {
         "Expr::Postfix::Index"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_index_expr(self);
-        self.target.accept(visitor);
-        self.index.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_index_expr(self)
+            .then(|| crate::expr::walk_index_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_index_expr_mut(self)
+            .then(|| crate::expr::walk_index_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_index_expr_fold(folder, self);
+        folder.fold_index_expr(node)
     }
 }
 
@@ -64,4 +83,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_slice() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+
+        for src in ["a[1..3]", "a[1..]", "a[..=2]", "a[..]"] {
+            let mut cursor = Span::from_str(src).cursor();
+            let value = parser.parse_expr(&mut cursor)?;
+
+            debug_assert_eq!(value.to_string(), src);
+            debug_assert!(value.as_index().is_slice());
+        }
+
+        let mut cursor = Span::from_bytes(b"a[i + 1]").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(!value.as_index().is_slice());
+        Ok(())
+    }
 }
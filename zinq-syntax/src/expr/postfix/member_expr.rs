@@ -36,9 +36,21 @@ impl Syntax for MemberExpr {
         "Expr::Postfix::Member"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_member_expr(self);
-        self.target.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_member_expr(self)
+            .then(|| crate::expr::walk_member_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_member_expr_mut(self)
+            .then(|| crate::expr::walk_member_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_member_expr_fold(folder, self);
+        folder.fold_member_expr(node)
     }
 }
 
@@ -1,7 +1,9 @@
 mod call_expr;
 mod index_expr;
 mod member_expr;
+mod try_expr;
 
 pub use call_expr::*;
 pub use index_expr::*;
 pub use member_expr::*;
+pub use try_expr::*;
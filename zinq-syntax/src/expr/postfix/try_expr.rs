@@ -0,0 +1,114 @@
+use zinq_parse::{Span, Spanned};
+use zinq_token::Question;
+
+use crate::{Syntax, expr::Expr};
+
+///
+/// ## Try Expression
+/// `<target>?`, returns early with the error
+/// or empty variant of a `Result` or `Option`
+///
+/// a `?` followed by an expression and a `:`
+/// is read as a ternary `cond ? a : b` instead
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TryExpr {
+    pub target: Box<Expr>,
+    pub question: Question,
+}
+
+impl TryExpr {
+    /// `<target>?`
+    pub fn new(target: Expr, question: Question) -> Self {
+        Self {
+            target: Box::new(target),
+            question,
+        }
+    }
+}
+
+impl From<TryExpr> for Expr {
+    fn from(value: TryExpr) -> Self {
+        Self::Try(value)
+    }
+}
+
+impl Syntax for TryExpr {
+    fn name(&self) -> &str {
+        "Expr::Postfix::Try"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_try_expr(self)
+            .then(|| crate::expr::walk_try_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_try_expr_mut(self)
+            .then(|| crate::expr::walk_try_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_try_expr_fold(folder, self);
+        folder.fold_try_expr(node)
+    }
+}
+
+impl std::fmt::Display for TryExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for TryExpr {
+    fn span(&self) -> Span {
+        Span::join(self.target.span(), self.question.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::ExprParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"read(path)?.len()").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let call = value.as_call();
+
+        debug_assert_eq!(value.to_string(), "read(path)?.len()");
+        debug_assert!(call.target.as_member().target.is_try());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_ternary_with_try() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a?? b? : c").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let ternary = value.as_if();
+
+        debug_assert!(ternary.cond.as_try().target.is_path());
+        debug_assert!(ternary.then_expr.is_try());
+        debug_assert!(ternary.else_expr.is_path());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_try_before_operator() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a? + b?").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let add = value.as_arithmetic();
+
+        debug_assert!(add.left.is_try());
+        debug_assert!(add.right.is_try());
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use zinq_parse::{Cursor, ZinqParser};
+use zinq_token::{As, Is, Punct};
+
+///
+/// ## Precedence
+/// how tightly an infix operator binds,
+/// from loosest to tightest
+///
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precedence {
+    Lowest,
+    Assign,
+    Ternary,
+    Range,
+    Or,
+    And,
+    Is,
+    Cmp,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
+    Term,
+    Factor,
+    Cast,
+    Prefix,
+}
+
+impl Precedence {
+    ///
+    /// ## next
+    /// the level one step tighter, the right side of a
+    /// left associative operator is parsed at this level
+    ///
+    pub fn next(self) -> Self {
+        match self {
+            Self::Lowest => Self::Assign,
+            Self::Assign => Self::Ternary,
+            Self::Ternary => Self::Range,
+            Self::Range => Self::Or,
+            Self::Or => Self::And,
+            Self::And => Self::Is,
+            Self::Is => Self::Cmp,
+            Self::Cmp => Self::BitOr,
+            Self::BitOr => Self::BitXor,
+            Self::BitXor => Self::BitAnd,
+            Self::BitAnd => Self::Shift,
+            Self::Shift => Self::Term,
+            Self::Term => Self::Factor,
+            Self::Factor => Self::Cast,
+            Self::Cast | Self::Prefix => Self::Prefix,
+        }
+    }
+}
+
+///
+/// ## Assoc
+/// how a chain of operators at the same
+/// precedence groups, `None` forbids chaining
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Assoc {
+    Left,
+    Right,
+    None,
+}
+
+///
+/// ## Infix
+/// the kind of expression an infix operator builds
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Infix {
+    Assign,
+    Ternary,
+    Range,
+    Logical,
+    Is,
+    Cmp,
+    Bitwise,
+    Arithmetic,
+    Cast,
+}
+
+///
+/// ## INFIX_TABLE
+/// every infix operator with the expression it builds,
+/// its precedence and associativity
+///
+pub const INFIX_TABLE: &[(&str, Infix, Precedence, Assoc)] = &[
+    ("=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("+=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("-=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("*=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("/=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("%=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("&=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("|=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("^=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("<<=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    (">>=", Infix::Assign, Precedence::Assign, Assoc::Right),
+    ("?", Infix::Ternary, Precedence::Ternary, Assoc::Right),
+    ("..", Infix::Range, Precedence::Range, Assoc::None),
+    ("..=", Infix::Range, Precedence::Range, Assoc::None),
+    ("||", Infix::Logical, Precedence::Or, Assoc::Left),
+    ("&&", Infix::Logical, Precedence::And, Assoc::Left),
+    ("is", Infix::Is, Precedence::Is, Assoc::Left),
+    ("==", Infix::Cmp, Precedence::Cmp, Assoc::None),
+    ("!=", Infix::Cmp, Precedence::Cmp, Assoc::None),
+    ("<", Infix::Cmp, Precedence::Cmp, Assoc::None),
+    ("<=", Infix::Cmp, Precedence::Cmp, Assoc::None),
+    (">", Infix::Cmp, Precedence::Cmp, Assoc::None),
+    (">=", Infix::Cmp, Precedence::Cmp, Assoc::None),
+    ("|", Infix::Bitwise, Precedence::BitOr, Assoc::Left),
+    ("^", Infix::Bitwise, Precedence::BitXor, Assoc::Left),
+    ("&", Infix::Bitwise, Precedence::BitAnd, Assoc::Left),
+    ("<<", Infix::Bitwise, Precedence::Shift, Assoc::Left),
+    (">>", Infix::Bitwise, Precedence::Shift, Assoc::Left),
+    ("+", Infix::Arithmetic, Precedence::Term, Assoc::Left),
+    ("-", Infix::Arithmetic, Precedence::Term, Assoc::Left),
+    ("*", Infix::Arithmetic, Precedence::Factor, Assoc::Left),
+    ("/", Infix::Arithmetic, Precedence::Factor, Assoc::Left),
+    ("%", Infix::Arithmetic, Precedence::Factor, Assoc::Left),
+    ("as", Infix::Cast, Precedence::Cast, Assoc::Left),
+];
+
+impl Infix {
+    ///
+    /// ## peek
+    /// the infix operator at the cursor, `Punct` always
+    /// reads the longest token so `&&` is never seen as `&`
+    ///
+    pub fn peek(cursor: &Cursor, parser: &ZinqParser) -> Option<(Self, Precedence, Assoc)> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+        let text = match fork_parser.parse::<Punct>(&mut fork) {
+            Ok(punct) => punct.to_string(),
+            Err(_) if parser.peek::<Is>(cursor).unwrap_or(false) => "is".to_string(),
+            Err(_) if parser.peek::<As>(cursor).unwrap_or(false) => "as".to_string(),
+            Err(_) => return None,
+        };
+
+        INFIX_TABLE
+            .iter()
+            .find(|(op, ..)| *op == text)
+            .map(|(_, infix, prec, assoc)| (*infix, *prec, *assoc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::{Infix, Precedence};
+
+    #[test]
+    fn should_peek_longest_operator() -> Result<()> {
+        let parser = zinq_parse::ZinqParser;
+
+        for (src, infix, prec) in [
+            ("  &&", Infix::Logical, Precedence::And),
+            ("&= 1", Infix::Assign, Precedence::Assign),
+            ("& 1", Infix::Bitwise, Precedence::BitAnd),
+            ("<<= 1", Infix::Assign, Precedence::Assign),
+            ("<< 1", Infix::Bitwise, Precedence::Shift),
+            ("<= 1", Infix::Cmp, Precedence::Cmp),
+            ("..= 1", Infix::Range, Precedence::Range),
+            ("is u8", Infix::Is, Precedence::Is),
+            ("as u8", Infix::Cast, Precedence::Cast),
+        ] {
+            let cursor = Span::from_str(src).cursor();
+            let (found, found_prec, _) = Infix::peek(&cursor, &parser).expect(src);
+
+            debug_assert_eq!(found, infix);
+            debug_assert_eq!(found_prec, prec);
+        }
+
+        let cursor = Span::from_str("=> 1").cursor();
+        debug_assert!(Infix::peek(&cursor, &parser).is_none());
+
+        Ok(())
+    }
+}
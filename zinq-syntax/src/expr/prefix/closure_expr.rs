@@ -0,0 +1,169 @@
+use zinq_parse::{Span, Spanned};
+use zinq_token::{Comma, Or, Punctuated, RArrow, Suffixed};
+
+use crate::{Syntax, expr::Expr, param::FnParam, stmt::BlockStmt, ty::Type};
+
+///
+/// ## Closure Expression
+/// `|a: i32, b| a + b` or `|a| -> i32 { a }`,
+/// parameter types are inferred when left out
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClosureExpr {
+    pub left_or: Or,
+    pub params: Punctuated<FnParam, Comma>,
+    pub right_or: Or,
+    pub return_ty: Option<Suffixed<RArrow, Type>>,
+    pub body: ClosureBody,
+}
+
+impl ClosureExpr {
+    pub fn is_block(&self) -> bool {
+        self.body.is_block()
+    }
+}
+
+impl From<ClosureExpr> for Expr {
+    fn from(value: ClosureExpr) -> Self {
+        Self::Closure(Box::new(value))
+    }
+}
+
+impl Syntax for ClosureExpr {
+    fn name(&self) -> &str {
+        "Expr::Prefix::Closure"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_closure_expr(self)
+            .then(|| crate::expr::walk_closure_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_closure_expr_mut(self)
+            .then(|| crate::expr::walk_closure_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_closure_expr_fold(folder, self);
+        folder.fold_closure_expr(node)
+    }
+}
+
+impl std::fmt::Display for ClosureExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for ClosureExpr {
+    fn span(&self) -> Span {
+        Span::join(self.left_or.span(), self.body.span())
+    }
+}
+
+///
+/// ## Closure Body
+/// a single expression, or a block
+/// when a return type is given
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClosureBody {
+    Expr(Box<Expr>),
+    Block(Box<BlockStmt>),
+}
+
+impl ClosureBody {
+    pub fn is_expr(&self) -> bool {
+        matches!(self, Self::Expr(_))
+    }
+
+    pub fn is_block(&self) -> bool {
+        matches!(self, Self::Block(_))
+    }
+}
+
+impl Spanned for ClosureBody {
+    fn span(&self) -> Span {
+        match self {
+            Self::Expr(v) => v.span(),
+            Self::Block(v) => v.span(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::ExprParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"|a: i32, b| a + b").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let closure = value.as_closure();
+
+        debug_assert_eq!(value.to_string(), "|a: i32, b| a + b");
+        debug_assert_eq!(closure.params.len(), 2);
+        debug_assert!(closure.params[0].value().is_typed());
+        debug_assert!(!closure.params[1].value().is_typed());
+        debug_assert!(closure.body.is_expr());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_empty() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"|| 1").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(value.as_closure().params.is_empty());
+        debug_assert_eq!(value.to_string(), "|| 1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_block() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"|a| -> i32 {\n    return a;\n}").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let closure = value.as_closure();
+
+        debug_assert!(closure.is_block());
+        debug_assert_eq!(
+            closure.return_ty.as_ref().unwrap().suffix.to_string(),
+            "i32"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_as_arg() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"items.map(|item| item.id)").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(value.is_call());
+        debug_assert!(value.as_call().args[0].value().is_closure());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_require_block_with_return_type() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"|a| -> i32 a").cursor();
+
+        debug_assert!(parser.parse_expr(&mut cursor).is_err());
+
+        Ok(())
+    }
+}
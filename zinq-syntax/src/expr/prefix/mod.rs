@@ -1,3 +1,5 @@
+mod closure_expr;
 mod ref_expr;
 
+pub use closure_expr::*;
 pub use ref_expr::*;
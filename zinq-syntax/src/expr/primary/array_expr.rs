@@ -1,21 +1,33 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Comma, LBracket, Punctuated, RBracket};
+use zinq_token::{Comma, LBracket, Punctuated, RBracket, SemiColon, Suffixed};
 
-use crate::{Syntax, expr::Expr};
+use crate::{
+    Syntax,
+    expr::{ConstExpr, Expr},
+};
 
 ///
 /// ## Array Expression
 /// ```
 /// [a, b, c]
+/// [0; N]
 /// ```
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ArrayExpr {
     pub left_bracket: LBracket,
     pub items: Punctuated<Expr, Comma>,
+    pub len: Option<Suffixed<SemiColon, ConstExpr>>,
     pub right_bracket: RBracket,
 }
 
+impl ArrayExpr {
+    /// `[<item>; <len>]`, a single item repeated `len` times
+    pub fn is_repeat(&self) -> bool {
+        self.len.is_some()
+    }
+}
+
 impl From<ArrayExpr> for Expr {
     fn from(value: ArrayExpr) -> Self {
         Self::Array(value)
@@ -27,12 +39,21 @@ impl Syntax for ArrayExpr {
         "Expr::Primary::Array"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_array_expr(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_array_expr(self)
+            .then(|| crate::expr::walk_array_expr(visitor, self))
+    }
 
-        for item in self.items.iter() {
-            item.value().accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_array_expr_mut(self)
+            .then(|| crate::expr::walk_array_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_array_expr_fold(folder, self);
+        folder.fold_array_expr(node)
     }
 }
 
@@ -64,11 +85,18 @@ impl Parse for ArrayExpr {
     ) -> zinq_error::Result<Self> {
         let left_bracket = parser.parse::<LBracket>(cursor)?;
         let items = parser.parse::<Punctuated<Expr, Comma>>(cursor)?;
+        let len = parser.parse::<Option<Suffixed<SemiColon, ConstExpr>>>(cursor)?;
+
+        if len.is_some() && items.len() != 1 {
+            return Err(cursor.error(zinq_error::NOT_FOUND, "expected a single item before ';'"));
+        }
+
         let right_bracket = parser.parse::<RBracket>(cursor)?;
 
         Ok(Self {
             left_bracket,
             items,
+            len,
             right_bracket,
         }
         .into())
@@ -93,4 +121,29 @@ mod tests {
         debug_assert_eq!(value.as_array().items.len(), 3);
         Ok(())
     }
+
+    #[test]
+    fn should_parse_repeat() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"[0u8; N + 1]").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "[0u8; N + 1]");
+        debug_assert!(value.as_array().is_repeat());
+        debug_assert_eq!(value.as_array().items.len(), 1);
+        debug_assert_eq!(
+            value.as_array().len.as_ref().unwrap().suffix.to_string(),
+            "N + 1"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_repeat_many() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"[1, 2; 3]").cursor();
+
+        debug_assert!(parser.parse_expr(&mut cursor).is_err());
+        Ok(())
+    }
 }
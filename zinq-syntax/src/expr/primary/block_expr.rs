@@ -0,0 +1,116 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+
+use crate::{Syntax, expr::Expr, stmt::BlockStmt};
+
+///
+/// ## Block Expression
+/// a block whose value is its tail expression
+///
+/// ```
+/// let total = {
+///     let base = 10;
+///     base * 2
+/// };
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockExpr {
+    pub block: BlockStmt,
+}
+
+impl From<BlockExpr> for Expr {
+    fn from(value: BlockExpr) -> Self {
+        Self::Block(value)
+    }
+}
+
+impl From<BlockStmt> for BlockExpr {
+    fn from(block: BlockStmt) -> Self {
+        Self { block }
+    }
+}
+
+impl Syntax for BlockExpr {
+    fn name(&self) -> &str {
+        "Expr::Block"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_block_expr(self)
+            .then(|| crate::expr::walk_block_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_block_expr_mut(self)
+            .then(|| crate::expr::walk_block_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_block_expr_fold(folder, self);
+        folder.fold_block_expr(node)
+    }
+}
+
+impl std::fmt::Display for BlockExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for BlockExpr {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        parser.peek::<BlockStmt>(cursor)
+    }
+}
+
+impl Parse for BlockExpr {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        Ok(Self {
+            block: parser.parse::<BlockStmt>(cursor)?,
+        })
+    }
+}
+
+impl Spanned for BlockExpr {
+    fn span(&self) -> Span {
+        self.block.span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::ExprParser;
+
+    #[test]
+    fn should_parse_tail() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"{ let a = 1; a + 1 }").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+        let block = &value.as_block().block;
+
+        debug_assert_eq!(block.stmts.len(), 1);
+        debug_assert_eq!(block.tail.as_ref().unwrap().to_string(), "a + 1");
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_without_tail() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"{ a += 1; }").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(!value.as_block().block.has_tail());
+        Ok(())
+    }
+}
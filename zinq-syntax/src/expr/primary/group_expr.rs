@@ -25,9 +25,21 @@ impl Syntax for GroupExpr {
         "Expr::Primary::Group"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_group_expr(self);
-        self.inner.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_group_expr(self)
+            .then(|| crate::expr::walk_group_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_group_expr_mut(self)
+            .then(|| crate::expr::walk_group_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_group_expr_fold(folder, self);
+        folder.fold_group_expr(node)
     }
 }
 
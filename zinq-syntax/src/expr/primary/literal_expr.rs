@@ -23,8 +23,21 @@ impl Syntax for LiteralExpr {
         "Expr::Primary::Literal"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_literal_expr(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_literal_expr(self)
+            .then(|| crate::expr::walk_literal_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_literal_expr_mut(self)
+            .then(|| crate::expr::walk_literal_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_literal_expr_fold(folder, self);
+        folder.fold_literal_expr(node)
     }
 }
 
@@ -0,0 +1,94 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+
+use crate::{Syntax, expr::Expr, macros::MacroCall};
+
+///
+/// ## Macro Expression
+/// `max!(a, b)`, expanded before lowering
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroExpr {
+    pub call: MacroCall,
+}
+
+impl From<MacroExpr> for Expr {
+    fn from(value: MacroExpr) -> Self {
+        Self::Macro(value)
+    }
+}
+
+impl Syntax for MacroExpr {
+    fn name(&self) -> &str {
+        "Expr::Primary::Macro"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_expr(self)
+            .then(|| crate::expr::walk_macro_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_expr_mut(self)
+            .then(|| crate::expr::walk_macro_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_macro_expr_fold(folder, self);
+        folder.fold_macro_expr(node)
+    }
+}
+
+impl std::fmt::Display for MacroExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for MacroExpr {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<MacroCall>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for MacroExpr {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let call = parser.parse::<MacroCall>(cursor)?;
+        Ok(Self { call })
+    }
+}
+
+impl Spanned for MacroExpr {
+    fn span(&self) -> Span {
+        self.call.span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::Expr;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"max!(a, b + 1) * 2").cursor();
+        let expr = parser.parse::<Expr>(&mut cursor)?;
+
+        debug_assert!(expr.is_arithmetic());
+        debug_assert!(expr.as_arithmetic().left.is_macro());
+        debug_assert_eq!(expr.as_arithmetic().left.to_string(), "max!(a, b + 1)");
+        debug_assert_eq!(expr.as_arithmetic().left.as_macro().call.name(), "max");
+
+        Ok(())
+    }
+}
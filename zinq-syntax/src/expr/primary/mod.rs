@@ -1,13 +1,17 @@
 mod array_expr;
+mod block_expr;
 mod group_expr;
 mod literal_expr;
+mod macro_expr;
 mod path_expr;
 mod struct_expr;
 mod tuple_expr;
 
 pub use array_expr::*;
+pub use block_expr::*;
 pub use group_expr::*;
 pub use literal_expr::*;
+pub use macro_expr::*;
 pub use path_expr::*;
 pub use struct_expr::*;
 pub use tuple_expr::*;
@@ -22,8 +22,21 @@ impl Syntax for PathExpr {
         "Expr::Primary::Path"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_path_expr(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_path_expr(self)
+            .then(|| crate::expr::walk_path_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_path_expr_mut(self)
+            .then(|| crate::expr::walk_path_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_path_expr_fold(folder, self);
+        folder.fold_path_expr(node)
     }
 }
 
@@ -1,12 +1,14 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Comma, LBrace, Punctuated, RBrace};
 
-use crate::{Path, Syntax, expr::Expr, members::MemberValue};
+use crate::{Path, Syntax, expr::Expr, members::MemberValue, spread::ExprSpread};
 
 ///
 /// ## Struct Expression
 /// ```
 /// Struct { a: 1, b: 2 }
+/// Struct { a, b }
+/// Struct { a: 1, ..base }
 /// ```
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -14,9 +16,17 @@ pub struct StructExpr {
     pub path: Path,
     pub left_brace: LBrace,
     pub members: Punctuated<MemberValue, Comma>,
+    pub spread: Option<ExprSpread>,
     pub right_brace: RBrace,
 }
 
+impl StructExpr {
+    /// fills the remaining fields from `..base`
+    pub fn is_update(&self) -> bool {
+        self.spread.is_some()
+    }
+}
+
 impl From<StructExpr> for Expr {
     fn from(value: StructExpr) -> Self {
         Self::Struct(value)
@@ -28,8 +38,21 @@ impl Syntax for StructExpr {
         "Expr::Primary::Struct"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_struct_expr(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_struct_expr(self)
+            .then(|| crate::expr::walk_struct_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_struct_expr_mut(self)
+            .then(|| crate::expr::walk_struct_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_struct_expr_fold(folder, self);
+        folder.fold_struct_expr(node)
     }
 }
 
@@ -68,12 +91,14 @@ impl Parse for StructExpr {
         let path = parser.parse::<Path>(cursor)?;
         let left_brace = parser.parse::<LBrace>(cursor)?;
         let members = parser.parse::<Punctuated<MemberValue, Comma>>(cursor)?;
+        let spread = parser.parse::<Option<ExprSpread>>(cursor)?;
         let right_brace = parser.parse::<RBrace>(cursor)?;
 
         Ok(Self {
             path,
             left_brace,
             members,
+            spread,
             right_brace,
         }
         .into())
@@ -95,6 +120,61 @@ mod tests {
 
         debug_assert_eq!(value.to_string(), "User { name, age: 5 }");
         debug_assert_eq!(value.as_struct().members.len(), 2);
+        debug_assert!(
+            value
+                .as_struct()
+                .members
+                .first()
+                .unwrap()
+                .value()
+                .is_shorthand()
+        );
+        debug_assert!(
+            !value
+                .as_struct()
+                .members
+                .last()
+                .unwrap()
+                .value()
+                .is_shorthand()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_update() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"User { name: \"b\", ..a.clone() }").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "User { name: \"b\", ..a.clone() }");
+        debug_assert!(value.as_struct().is_update());
+        debug_assert_eq!(value.as_struct().members.len(), 1);
+        debug_assert_eq!(
+            value.as_struct().spread.as_ref().unwrap().expr.to_string(),
+            "a.clone()"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_update_only() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"User { ..Default::default() }").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(value.as_struct().is_update());
+        debug_assert_eq!(value.as_struct().members.len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_parse_members_after_update() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"User { ..a, name }").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert!(!value.is_struct());
         Ok(())
     }
 }
@@ -27,12 +27,21 @@ impl Syntax for TupleExpr {
         "Expr::Primary::Tuple"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_tuple_expr(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_expr(self)
+            .then(|| crate::expr::walk_tuple_expr(visitor, self))
+    }
 
-        for item in self.items.iter() {
-            item.value().accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_expr_mut(self)
+            .then(|| crate::expr::walk_tuple_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_tuple_expr_fold(folder, self);
+        folder.fold_tuple_expr(node)
     }
 }
 
@@ -0,0 +1,84 @@
+use zinq_parse::{Span, Spanned};
+use zinq_token::Tilde;
+
+use crate::{Syntax, expr::Expr};
+
+///
+/// ## Bitwise Not Expression
+/// `~mask`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitNotExpr {
+    pub tilde: Tilde,
+    pub right: Box<Expr>,
+}
+
+impl BitNotExpr {
+    /// `~<right>`
+    pub fn new(tilde: Tilde, right: Expr) -> Self {
+        Self {
+            tilde,
+            right: Box::new(right),
+        }
+    }
+}
+
+impl From<BitNotExpr> for Expr {
+    fn from(value: BitNotExpr) -> Self {
+        Self::BitNot(value)
+    }
+}
+
+impl Syntax for BitNotExpr {
+    fn name(&self) -> &str {
+        "Expr::Unary::BitNot"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_bit_not_expr(self)
+            .then(|| crate::expr::walk_bit_not_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_bit_not_expr_mut(self)
+            .then(|| crate::expr::walk_bit_not_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_bit_not_expr_fold(folder, self);
+        folder.fold_bit_not_expr(node)
+    }
+}
+
+impl std::fmt::Display for BitNotExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for BitNotExpr {
+    fn span(&self) -> Span {
+        Span::join(self.tilde.span(), self.right.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::expr::ExprParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"~a & b").cursor();
+        let value = parser.parse_expr(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "~a & b");
+        debug_assert!(value.as_bitwise().left.is_bit_not());
+        Ok(())
+    }
+}
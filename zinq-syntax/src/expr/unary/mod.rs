@@ -1,5 +1,7 @@
+mod bit_not_expr;
 mod neg_expr;
 mod not_expr;
 
+pub use bit_not_expr::*;
 pub use neg_expr::*;
 pub use not_expr::*;
@@ -34,9 +34,21 @@ impl Syntax for NegExpr {
         "Expr::Unary::Neg"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_neg_expr(self);
-        self.right.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_neg_expr(self)
+            .then(|| crate::expr::walk_neg_expr(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_neg_expr_mut(self)
+            .then(|| crate::expr::walk_neg_expr_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::expr::walk_neg_expr_fold(folder, self);
+        folder.fold_neg_expr(node)
     }
 }
 
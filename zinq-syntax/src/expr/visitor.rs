@@ -1,32 +1,409 @@
 #![allow(unused)]
 
+use crate::{Syntax, Visit, visitor::try_visit};
+
 use super::*;
 
 pub trait ExprVisitor: MatchVisitor {
-    fn visit_expr(&mut self, node: &Expr) {}
-
-    fn visit_arithmetic_expr(&mut self, node: &ArithmeticExpr) {}
-    fn visit_assign_expr(&mut self, node: &AssignExpr) {}
-    fn visit_cmp_expr(&mut self, node: &CmpExpr) {}
-    fn visit_is_expr(&mut self, node: &IsExpr) {}
-    fn visit_logical_expr(&mut self, node: &LogicalExpr) {}
-
-    fn visit_match_expr(&mut self, node: &MatchExpr) {}
-    fn visit_if_expr(&mut self, node: &IfExpr) {}
-    fn visit_range_expr(&mut self, node: &RangeExpr) {}
-
-    fn visit_call_expr(&mut self, node: &CallExpr) {}
-    fn visit_index_expr(&mut self, node: &IndexExpr) {}
-    fn visit_member_expr(&mut self, node: &MemberExpr) {}
-    fn visit_ref_expr(&mut self, node: &RefExpr) {}
-
-    fn visit_neg_expr(&mut self, node: &NegExpr) {}
-    fn visit_not_expr(&mut self, node: &NotExpr) {}
-
-    fn visit_array_expr(&mut self, node: &ArrayExpr) {}
-    fn visit_group_expr(&mut self, node: &GroupExpr) {}
-    fn visit_literal_expr(&mut self, node: &LiteralExpr) {}
-    fn visit_path_expr(&mut self, node: &PathExpr) {}
-    fn visit_struct_expr(&mut self, node: &StructExpr) {}
-    fn visit_tuple_expr(&mut self, node: &TupleExpr) {}
+    fn visit_expr(&mut self, node: &Expr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_const_expr(&mut self, node: &ConstExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_arithmetic_expr(&mut self, node: &ArithmeticExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_assign_expr(&mut self, node: &AssignExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_bitwise_expr(&mut self, node: &BitwiseExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_cast_expr(&mut self, node: &CastExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_cmp_expr(&mut self, node: &CmpExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_is_expr(&mut self, node: &IsExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_logical_expr(&mut self, node: &LogicalExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_match_expr(&mut self, node: &MatchExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_if_expr(&mut self, node: &IfExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_if_else_expr(&mut self, node: &IfElseExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_range_expr(&mut self, node: &RangeExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_call_expr(&mut self, node: &CallExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_index_expr(&mut self, node: &IndexExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_member_expr(&mut self, node: &MemberExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_try_expr(&mut self, node: &TryExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ref_expr(&mut self, node: &RefExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_closure_expr(&mut self, node: &ClosureExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_neg_expr(&mut self, node: &NegExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_not_expr(&mut self, node: &NotExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_bit_not_expr(&mut self, node: &BitNotExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_array_expr(&mut self, node: &ArrayExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_block_expr(&mut self, node: &BlockExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_group_expr(&mut self, node: &GroupExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_literal_expr(&mut self, node: &LiteralExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_expr(&mut self, node: &MacroExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_path_expr(&mut self, node: &PathExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_struct_expr(&mut self, node: &StructExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_expr(&mut self, node: &TupleExpr) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_expr<V: crate::Visitor>(visitor: &mut V, node: &Expr) -> Visit {
+    match node {
+        Expr::Literal(v) => v.accept(visitor),
+        Expr::Path(v) => v.accept(visitor),
+        Expr::Group(v) => v.accept(visitor),
+        Expr::Struct(v) => v.accept(visitor),
+        Expr::Tuple(v) => v.accept(visitor),
+        Expr::Array(v) => v.accept(visitor),
+        Expr::Block(v) => v.accept(visitor),
+        Expr::Range(v) => v.accept(visitor),
+        Expr::Macro(v) => v.accept(visitor),
+        Expr::Arithmetic(v) => v.accept(visitor),
+        Expr::Assign(v) => v.accept(visitor),
+        Expr::Bitwise(v) => v.accept(visitor),
+        Expr::Cast(v) => v.accept(visitor),
+        Expr::Cmp(v) => v.accept(visitor),
+        Expr::Is(v) => v.accept(visitor),
+        Expr::Logical(v) => v.accept(visitor),
+        Expr::Call(v) => v.accept(visitor),
+        Expr::Index(v) => v.accept(visitor),
+        Expr::Member(v) => v.accept(visitor),
+        Expr::Try(v) => v.accept(visitor),
+        Expr::Not(v) => v.accept(visitor),
+        Expr::Neg(v) => v.accept(visitor),
+        Expr::BitNot(v) => v.accept(visitor),
+        Expr::Ref(v) => v.accept(visitor),
+        Expr::Closure(v) => v.accept(visitor),
+        Expr::If(v) => v.accept(visitor),
+        Expr::IfElse(v) => v.accept(visitor),
+        Expr::Match(v) => v.accept(visitor),
+    }
+}
+
+pub fn walk_const_expr<V: crate::Visitor>(visitor: &mut V, node: &ConstExpr) -> Visit {
+    try_visit!(node.value.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_arithmetic_expr<V: crate::Visitor>(visitor: &mut V, node: &ArithmeticExpr) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_assign_expr<V: crate::Visitor>(visitor: &mut V, node: &AssignExpr) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_bitwise_expr<V: crate::Visitor>(visitor: &mut V, node: &BitwiseExpr) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_cast_expr<V: crate::Visitor>(visitor: &mut V, node: &CastExpr) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.ty.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_cmp_expr<V: crate::Visitor>(visitor: &mut V, node: &CmpExpr) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_is_expr<V: crate::Visitor>(visitor: &mut V, node: &IsExpr) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.target.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_logical_expr<V: crate::Visitor>(visitor: &mut V, node: &LogicalExpr) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_match_expr<V: crate::Visitor>(visitor: &mut V, node: &MatchExpr) -> Visit {
+    try_visit!(node.expr.accept(visitor));
+
+    for item in node.arms.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_if_expr<V: crate::Visitor>(visitor: &mut V, node: &IfExpr) -> Visit {
+    try_visit!(node.cond.accept(visitor));
+    try_visit!(node.then_expr.accept(visitor));
+    try_visit!(node.else_expr.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_if_else_expr<V: crate::Visitor>(visitor: &mut V, node: &IfElseExpr) -> Visit {
+    if let Some((_, v, _)) = &node.binding {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.cond.accept(visitor));
+    try_visit!(node.then_block.accept(visitor));
+
+    if let Some((_, v)) = &node.else_branch {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_range_expr<V: crate::Visitor>(visitor: &mut V, node: &RangeExpr) -> Visit {
+    if let Some(v) = &node.start {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.end {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_call_expr<V: crate::Visitor>(visitor: &mut V, node: &CallExpr) -> Visit {
+    try_visit!(node.target.accept(visitor));
+
+    for item in node.args.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_index_expr<V: crate::Visitor>(visitor: &mut V, node: &IndexExpr) -> Visit {
+    try_visit!(node.target.accept(visitor));
+    try_visit!(node.index.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_member_expr<V: crate::Visitor>(visitor: &mut V, node: &MemberExpr) -> Visit {
+    try_visit!(node.target.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_try_expr<V: crate::Visitor>(visitor: &mut V, node: &TryExpr) -> Visit {
+    try_visit!(node.target.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_ref_expr<V: crate::Visitor>(visitor: &mut V, node: &RefExpr) -> Visit {
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_closure_expr<V: crate::Visitor>(visitor: &mut V, node: &ClosureExpr) -> Visit {
+    for item in node.params.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    if let Some(v) = &node.return_ty {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    match &node.body {
+        ClosureBody::Expr(v) => try_visit!(v.accept(visitor)),
+        ClosureBody::Block(v) => try_visit!(v.accept(visitor)),
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_neg_expr<V: crate::Visitor>(visitor: &mut V, node: &NegExpr) -> Visit {
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_not_expr<V: crate::Visitor>(visitor: &mut V, node: &NotExpr) -> Visit {
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_bit_not_expr<V: crate::Visitor>(visitor: &mut V, node: &BitNotExpr) -> Visit {
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_array_expr<V: crate::Visitor>(visitor: &mut V, node: &ArrayExpr) -> Visit {
+    for item in node.items.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    if let Some(v) = &node.len {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_block_expr<V: crate::Visitor>(visitor: &mut V, node: &BlockExpr) -> Visit {
+    try_visit!(node.block.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_group_expr<V: crate::Visitor>(visitor: &mut V, node: &GroupExpr) -> Visit {
+    try_visit!(node.inner.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_literal_expr<V: crate::Visitor>(_visitor: &mut V, _node: &LiteralExpr) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_macro_expr<V: crate::Visitor>(_visitor: &mut V, _node: &MacroExpr) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_path_expr<V: crate::Visitor>(visitor: &mut V, node: &PathExpr) -> Visit {
+    try_visit!(crate::walk_path(visitor, &node.path));
+
+    Visit::Continue
+}
+
+pub fn walk_struct_expr<V: crate::Visitor>(visitor: &mut V, node: &StructExpr) -> Visit {
+    try_visit!(crate::walk_path(visitor, &node.path));
+
+    for item in node.members.iter() {
+        try_visit!(crate::walk_member_value(visitor, item.value()));
+    }
+
+    if let Some(v) = &node.spread {
+        try_visit!(crate::walk_expr_spread(visitor, v));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_expr<V: crate::Visitor>(visitor: &mut V, node: &TupleExpr) -> Visit {
+    for item in node.items.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_is_target<V: crate::Visitor>(visitor: &mut V, node: &IsTarget) -> Visit {
+    match node {
+        IsTarget::Type(v) => v.accept(visitor),
+        IsTarget::Pattern(v) => v.accept(visitor),
+    }
+}
+
+pub fn walk_match_arm<V: crate::Visitor>(visitor: &mut V, node: &Arm) -> Visit {
+    try_visit!(node.pattern.accept(visitor));
+
+    if let Some(v) = &node.guard {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    try_visit!(node.body.accept(visitor));
+
+    Visit::Continue
 }
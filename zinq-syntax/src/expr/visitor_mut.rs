@@ -0,0 +1,430 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait ExprVisitorMut: MatchVisitorMut {
+    fn visit_expr_mut(&mut self, node: &mut Expr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_const_expr_mut(&mut self, node: &mut ConstExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_arithmetic_expr_mut(&mut self, node: &mut ArithmeticExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_assign_expr_mut(&mut self, node: &mut AssignExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_bitwise_expr_mut(&mut self, node: &mut BitwiseExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_cast_expr_mut(&mut self, node: &mut CastExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_cmp_expr_mut(&mut self, node: &mut CmpExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_is_expr_mut(&mut self, node: &mut IsExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_logical_expr_mut(&mut self, node: &mut LogicalExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_match_expr_mut(&mut self, node: &mut MatchExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_if_expr_mut(&mut self, node: &mut IfExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_if_else_expr_mut(&mut self, node: &mut IfElseExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_range_expr_mut(&mut self, node: &mut RangeExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_call_expr_mut(&mut self, node: &mut CallExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_index_expr_mut(&mut self, node: &mut IndexExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_member_expr_mut(&mut self, node: &mut MemberExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_try_expr_mut(&mut self, node: &mut TryExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ref_expr_mut(&mut self, node: &mut RefExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_closure_expr_mut(&mut self, node: &mut ClosureExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_neg_expr_mut(&mut self, node: &mut NegExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_not_expr_mut(&mut self, node: &mut NotExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_bit_not_expr_mut(&mut self, node: &mut BitNotExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_array_expr_mut(&mut self, node: &mut ArrayExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_block_expr_mut(&mut self, node: &mut BlockExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_group_expr_mut(&mut self, node: &mut GroupExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_literal_expr_mut(&mut self, node: &mut LiteralExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_expr_mut(&mut self, node: &mut MacroExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_path_expr_mut(&mut self, node: &mut PathExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_struct_expr_mut(&mut self, node: &mut StructExpr) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_expr_mut(&mut self, node: &mut TupleExpr) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Expr) -> Visit {
+    match node {
+        Expr::Literal(v) => v.accept_mut(visitor),
+        Expr::Path(v) => v.accept_mut(visitor),
+        Expr::Group(v) => v.accept_mut(visitor),
+        Expr::Struct(v) => v.accept_mut(visitor),
+        Expr::Tuple(v) => v.accept_mut(visitor),
+        Expr::Array(v) => v.accept_mut(visitor),
+        Expr::Block(v) => v.accept_mut(visitor),
+        Expr::Range(v) => v.accept_mut(visitor),
+        Expr::Macro(v) => v.accept_mut(visitor),
+        Expr::Arithmetic(v) => v.accept_mut(visitor),
+        Expr::Assign(v) => v.accept_mut(visitor),
+        Expr::Bitwise(v) => v.accept_mut(visitor),
+        Expr::Cast(v) => v.accept_mut(visitor),
+        Expr::Cmp(v) => v.accept_mut(visitor),
+        Expr::Is(v) => v.accept_mut(visitor),
+        Expr::Logical(v) => v.accept_mut(visitor),
+        Expr::Call(v) => v.accept_mut(visitor),
+        Expr::Index(v) => v.accept_mut(visitor),
+        Expr::Member(v) => v.accept_mut(visitor),
+        Expr::Try(v) => v.accept_mut(visitor),
+        Expr::Not(v) => v.accept_mut(visitor),
+        Expr::Neg(v) => v.accept_mut(visitor),
+        Expr::BitNot(v) => v.accept_mut(visitor),
+        Expr::Ref(v) => v.accept_mut(visitor),
+        Expr::Closure(v) => v.accept_mut(visitor),
+        Expr::If(v) => v.accept_mut(visitor),
+        Expr::IfElse(v) => v.accept_mut(visitor),
+        Expr::Match(v) => v.accept_mut(visitor),
+    }
+}
+
+pub fn walk_const_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ConstExpr) -> Visit {
+    try_visit!(node.value.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_arithmetic_expr_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ArithmeticExpr,
+) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_assign_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut AssignExpr) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_bitwise_expr_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut BitwiseExpr,
+) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_cast_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut CastExpr) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.ty.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_cmp_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut CmpExpr) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_is_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut IsExpr) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.target.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_logical_expr_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut LogicalExpr,
+) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_match_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut MatchExpr) -> Visit {
+    try_visit!(node.expr.accept_mut(visitor));
+
+    for item in node.arms.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_if_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut IfExpr) -> Visit {
+    try_visit!(node.cond.accept_mut(visitor));
+    try_visit!(node.then_expr.accept_mut(visitor));
+    try_visit!(node.else_expr.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_if_else_expr_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut IfElseExpr,
+) -> Visit {
+    if let Some((_, v, _)) = &mut node.binding {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.cond.accept_mut(visitor));
+    try_visit!(node.then_block.accept_mut(visitor));
+
+    if let Some((_, v)) = &mut node.else_branch {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_range_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut RangeExpr) -> Visit {
+    if let Some(v) = &mut node.start {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.end {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_call_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut CallExpr) -> Visit {
+    try_visit!(node.target.accept_mut(visitor));
+
+    for item in node.args.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_index_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut IndexExpr) -> Visit {
+    try_visit!(node.target.accept_mut(visitor));
+    try_visit!(node.index.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_member_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut MemberExpr) -> Visit {
+    try_visit!(node.target.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_try_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut TryExpr) -> Visit {
+    try_visit!(node.target.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_ref_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut RefExpr) -> Visit {
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_closure_expr_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ClosureExpr,
+) -> Visit {
+    for item in node.params.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.return_ty {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    match &mut node.body {
+        ClosureBody::Expr(v) => try_visit!(v.accept_mut(visitor)),
+        ClosureBody::Block(v) => try_visit!(v.accept_mut(visitor)),
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_neg_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut NegExpr) -> Visit {
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_not_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut NotExpr) -> Visit {
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_bit_not_expr_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut BitNotExpr,
+) -> Visit {
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_array_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ArrayExpr) -> Visit {
+    for item in node.items.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.len {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_block_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut BlockExpr) -> Visit {
+    try_visit!(node.block.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_group_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut GroupExpr) -> Visit {
+    try_visit!(node.inner.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_literal_expr_mut<V: crate::VisitorMut>(
+    _visitor: &mut V,
+    _node: &mut LiteralExpr,
+) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_macro_expr_mut<V: crate::VisitorMut>(_visitor: &mut V, _node: &mut MacroExpr) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_path_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut PathExpr) -> Visit {
+    try_visit!(crate::walk_path_mut(visitor, &mut node.path));
+
+    Visit::Continue
+}
+
+pub fn walk_struct_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut StructExpr) -> Visit {
+    try_visit!(crate::walk_path_mut(visitor, &mut node.path));
+
+    for item in node.members.iter_mut() {
+        try_visit!(crate::walk_member_value_mut(visitor, item.value_mut()));
+    }
+
+    if let Some(v) = &mut node.spread {
+        try_visit!(crate::walk_expr_spread_mut(visitor, v));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_expr_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut TupleExpr) -> Visit {
+    for item in node.items.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_is_target_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut IsTarget) -> Visit {
+    match node {
+        IsTarget::Type(v) => v.accept_mut(visitor),
+        IsTarget::Pattern(v) => v.accept_mut(visitor),
+    }
+}
+
+pub fn walk_match_arm_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Arm) -> Visit {
+    try_visit!(node.pattern.accept_mut(visitor));
+
+    if let Some(v) = &mut node.guard {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    try_visit!(node.body.accept_mut(visitor));
+
+    Visit::Continue
+}
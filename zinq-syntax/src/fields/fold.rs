@@ -0,0 +1,65 @@
+use crate::Syntax;
+
+use super::*;
+
+pub trait FieldsFold {
+    fn fold_name_fields(&mut self, node: NameFields) -> NameFields {
+        node
+    }
+
+    fn fold_name_field(&mut self, node: NameField) -> NameField {
+        node
+    }
+
+    fn fold_index_fields(&mut self, node: IndexFields) -> IndexFields {
+        node
+    }
+
+    fn fold_index_field(&mut self, node: IndexField) -> IndexField {
+        node
+    }
+}
+
+pub fn walk_name_fields_fold<F: crate::Fold>(folder: &mut F, node: NameFields) -> NameFields {
+    NameFields {
+        spreads: node
+            .spreads
+            .map(|v| crate::walk_type_spread_fold(folder, v)),
+        fields: node.fields.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_name_field_fold<F: crate::Fold>(folder: &mut F, node: NameField) -> NameField {
+    NameField {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ty: node.ty.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_index_fields_fold<F: crate::Fold>(folder: &mut F, node: IndexFields) -> IndexFields {
+    IndexFields {
+        spreads: node
+            .spreads
+            .map(|v| crate::walk_type_spread_fold(folder, v)),
+        fields: node.fields.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_index_field_fold<F: crate::Fold>(folder: &mut F, node: IndexField) -> IndexField {
+    IndexField {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ty: node.ty.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_fields_fold<F: crate::Fold>(folder: &mut F, node: Fields) -> Fields {
+    match node {
+        Fields::None(v) => Fields::None(v),
+        Fields::Indexed(v) => Fields::Indexed(v.fold(folder)),
+        Fields::Named(v) => Fields::Named(v.fold(folder)),
+    }
+}
@@ -1,14 +1,38 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Comma, LParen, Punctuated, RParen};
 
-use crate::{Syntax, Visibility, spread::TypeSpread, ty::Type};
+use crate::{Syntax, Visibility, meta::Meta, spread::TypeSpread, ty::Type};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IndexField {
+    pub meta: Option<Meta>,
     pub vis: Visibility,
     pub ty: Type,
 }
 
+impl Syntax for IndexField {
+    fn name(&self) -> &str {
+        "Fields::Indexed::Field"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_index_field(self)
+            .then(|| crate::fields::walk_index_field(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_index_field_mut(self)
+            .then(|| crate::fields::walk_index_field_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::fields::walk_index_field_fold(folder, self);
+        folder.fold_index_field(node)
+    }
+}
+
 impl std::fmt::Display for IndexField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.span())
@@ -35,16 +59,20 @@ impl Parse for IndexField {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let vis = parser.parse::<Visibility>(cursor)?;
         let ty = parser.parse::<Type>(cursor)?;
 
-        Ok(Self { vis, ty })
+        Ok(Self { meta, vis, ty })
     }
 }
 
 impl Spanned for IndexField {
     fn span(&self) -> Span {
-        Span::join(self.vis.span(), self.ty.span())
+        match &self.meta {
+            None => Span::join(self.vis.span(), self.ty.span()),
+            Some(meta) => Span::join(meta.span(), self.ty.span()),
+        }
     }
 }
 
@@ -61,14 +89,21 @@ impl Syntax for IndexFields {
         "Fields::Indexed"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        for item in self.spreads.iter() {
-            item.value().ty.accept(visitor);
-        }
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_index_fields(self)
+            .then(|| crate::fields::walk_index_fields(visitor, self))
+    }
 
-        for item in self.fields.iter() {
-            item.value().ty.accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_index_fields_mut(self)
+            .then(|| crate::fields::walk_index_fields_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::fields::walk_index_fields_fold(folder, self);
+        folder.fold_index_fields(node)
     }
 }
 
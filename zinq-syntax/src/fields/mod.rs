@@ -1,8 +1,14 @@
+mod fold;
 mod index_fields;
 mod name_fields;
+mod visitor;
+mod visitor_mut;
 
+pub use fold::*;
 pub use index_fields::*;
 pub use name_fields::*;
+pub use visitor::*;
+pub use visitor_mut::*;
 use zinq_parse::{Parse, Peek, Span, Spanned};
 
 use crate::Syntax;
@@ -69,12 +75,16 @@ impl Syntax for Fields {
         }
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        match self {
-            Self::None(_) => {}
-            Self::Indexed(v) => v.accept(visitor),
-            Self::Named(v) => v.accept(visitor),
-        }
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        crate::fields::walk_fields(visitor, self)
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        crate::fields::walk_fields_mut(visitor, self)
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        crate::fields::walk_fields_fold(folder, self)
     }
 }
 
@@ -107,7 +117,7 @@ impl Parse for Fields {
             return Ok(parser.parse::<NameFields>(cursor)?.into());
         }
 
-        Ok(Self::None(cursor.span().clone()))
+        Ok(Self::None(cursor.source_span()))
     }
 }
 
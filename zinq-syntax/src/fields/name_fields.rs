@@ -1,16 +1,40 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Colon, Comma, Ident, LBrace, Punctuated, RBrace};
 
-use crate::{Syntax, Visibility, spread::TypeSpread, ty::Type};
+use crate::{Syntax, Visibility, meta::Meta, spread::TypeSpread, ty::Type};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NameField {
+    pub meta: Option<Meta>,
     pub vis: Visibility,
     pub name: Ident,
     pub colon: Colon,
     pub ty: Type,
 }
 
+impl Syntax for NameField {
+    fn name(&self) -> &str {
+        "Fields::Named::Field"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_name_field(self)
+            .then(|| crate::fields::walk_name_field(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_name_field_mut(self)
+            .then(|| crate::fields::walk_name_field_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::fields::walk_name_field_fold(folder, self);
+        folder.fold_name_field(node)
+    }
+}
+
 impl std::fmt::Display for NameField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.span())
@@ -37,12 +61,14 @@ impl Parse for NameField {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let vis = parser.parse::<Visibility>(cursor)?;
         let name = parser.parse::<Ident>(cursor)?;
         let colon = parser.parse::<Colon>(cursor)?;
         let ty = parser.parse::<Type>(cursor)?;
 
         Ok(Self {
+            meta,
             vis,
             name,
             colon,
@@ -53,7 +79,10 @@ impl Parse for NameField {
 
 impl Spanned for NameField {
     fn span(&self) -> Span {
-        Span::join(self.vis.span(), self.ty.span())
+        match &self.meta {
+            None => Span::join(self.vis.span(), self.ty.span()),
+            Some(meta) => Span::join(meta.span(), self.ty.span()),
+        }
     }
 }
 
@@ -70,14 +99,21 @@ impl Syntax for NameFields {
         "Fields::Named"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        for item in self.spreads.iter() {
-            item.value().ty.accept(visitor);
-        }
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_name_fields(self)
+            .then(|| crate::fields::walk_name_fields(visitor, self))
+    }
 
-        for item in self.fields.iter() {
-            item.value().ty.accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_name_fields_mut(self)
+            .then(|| crate::fields::walk_name_fields_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::fields::walk_name_fields_fold(folder, self);
+        folder.fold_name_fields(node)
     }
 }
 
@@ -215,6 +251,19 @@ mod tests {
         debug_assert_eq!(fields.first().unwrap().value().to_string(), "hello: string");
         debug_assert_eq!(fields.last().unwrap().value().to_string(), "pub world: u32");
 
+        Ok(())
+    }
+    #[test]
+    fn should_parse_keyword_names() -> Result<()> {
+        let mut cursor =
+            Span::from_bytes(b"{ r#match: string, protocol: u32, let_x: bool }").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let fields = parser.parse::<NameFields>(&mut cursor)?;
+
+        debug_assert_eq!(fields.len(), 3);
+        debug_assert_eq!(fields.first().unwrap().value().name.unraw(), "match");
+        debug_assert_eq!(fields.last().unwrap().value().name.to_string(), "let_x");
+
         Ok(())
     }
 }
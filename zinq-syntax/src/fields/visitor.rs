@@ -0,0 +1,75 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait FieldsVisitor {
+    fn visit_name_fields(&mut self, node: &NameFields) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_name_field(&mut self, node: &NameField) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_index_fields(&mut self, node: &IndexFields) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_index_field(&mut self, node: &IndexField) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_name_fields<V: crate::Visitor>(visitor: &mut V, node: &NameFields) -> Visit {
+    for item in node.spreads.iter() {
+        try_visit!(crate::walk_type_spread(visitor, item.value()));
+    }
+
+    for item in node.fields.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_name_field<V: crate::Visitor>(visitor: &mut V, node: &NameField) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.ty.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_index_fields<V: crate::Visitor>(visitor: &mut V, node: &IndexFields) -> Visit {
+    for item in node.spreads.iter() {
+        try_visit!(crate::walk_type_spread(visitor, item.value()));
+    }
+
+    for item in node.fields.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_index_field<V: crate::Visitor>(visitor: &mut V, node: &IndexField) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.ty.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_fields<V: crate::Visitor>(visitor: &mut V, node: &Fields) -> Visit {
+    match node {
+        Fields::None(_) => Visit::Continue,
+        Fields::Indexed(v) => v.accept(visitor),
+        Fields::Named(v) => v.accept(visitor),
+    }
+}
@@ -0,0 +1,78 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait FieldsVisitorMut {
+    fn visit_name_fields_mut(&mut self, node: &mut NameFields) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_name_field_mut(&mut self, node: &mut NameField) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_index_fields_mut(&mut self, node: &mut IndexFields) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_index_field_mut(&mut self, node: &mut IndexField) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_name_fields_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut NameFields) -> Visit {
+    for item in node.spreads.iter_mut() {
+        try_visit!(crate::walk_type_spread_mut(visitor, item.value_mut()));
+    }
+
+    for item in node.fields.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_name_field_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut NameField) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.ty.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_index_fields_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut IndexFields,
+) -> Visit {
+    for item in node.spreads.iter_mut() {
+        try_visit!(crate::walk_type_spread_mut(visitor, item.value_mut()));
+    }
+
+    for item in node.fields.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_index_field_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut IndexField) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.ty.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_fields_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Fields) -> Visit {
+    match node {
+        Fields::None(_) => Visit::Continue,
+        Fields::Indexed(v) => v.accept_mut(visitor),
+        Fields::Named(v) => v.accept_mut(visitor),
+    }
+}
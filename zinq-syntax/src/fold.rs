@@ -0,0 +1,160 @@
+use zinq_token::Suffixed;
+
+use crate::{
+    Bounds, GenericArg, GenericArgs, Generics, Path, SourceFile, Syntax, Variant, WhereClause,
+    WherePredicate,
+    members::MemberValue,
+    spread::{ExprSpread, TypeSpread},
+};
+
+pub fn walk_source_file_fold<F: crate::Fold>(folder: &mut F, mut node: SourceFile) -> SourceFile {
+    node.attrs = std::mem::take(&mut node.attrs)
+        .into_iter()
+        .map(|v| v.fold(folder))
+        .collect();
+    node.items = std::mem::take(&mut node.items)
+        .into_iter()
+        .map(|v| v.fold(folder))
+        .collect();
+    node
+}
+
+pub fn walk_generics_fold<F: crate::Fold>(folder: &mut F, node: Generics) -> Generics {
+    Generics {
+        params: node.params.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_where_clause_fold<F: crate::Fold>(folder: &mut F, node: WhereClause) -> WhereClause {
+    WhereClause {
+        predicates: node
+            .predicates
+            .map(|v| walk_where_predicate_fold(folder, v)),
+        ..node
+    }
+}
+
+pub fn walk_variant_fold<F: crate::Fold>(folder: &mut F, node: Variant) -> Variant {
+    Variant {
+        meta: node.meta.map(|v| v.fold(folder)),
+        fields: node.fields.fold(folder),
+        discriminant: node.discriminant.map(|(eq, v)| (eq, v.fold(folder))),
+        ..node
+    }
+}
+
+pub fn walk_path_fold<F: crate::Fold>(folder: &mut F, mut node: Path) -> Path {
+    for item in node.iter_mut() {
+        let section = item.value_mut();
+
+        section.generics = section.generics.take().map(|v| Suffixed {
+            suffix: walk_generic_args_fold(folder, v.suffix),
+            ..v
+        });
+    }
+
+    node
+}
+
+pub fn walk_generic_args_fold<F: crate::Fold>(folder: &mut F, node: GenericArgs) -> GenericArgs {
+    GenericArgs {
+        args: node.args.map(|v| match v {
+            GenericArg::Type(v) => GenericArg::Type(v.fold(folder)),
+            GenericArg::Const(v) => GenericArg::Const(v.fold(folder)),
+        }),
+        ..node
+    }
+}
+
+pub fn walk_bounds_fold<F: crate::Fold>(folder: &mut F, node: Bounds) -> Bounds {
+    Bounds {
+        items: node.items.map(|v| walk_path_fold(folder, v)),
+        ..node
+    }
+}
+
+pub fn walk_where_predicate_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: WherePredicate,
+) -> WherePredicate {
+    WherePredicate {
+        ty: node.ty.fold(folder),
+        bounds: walk_bounds_fold(folder, node.bounds),
+    }
+}
+
+pub fn walk_member_value_fold<F: crate::Fold>(folder: &mut F, node: MemberValue) -> MemberValue {
+    MemberValue {
+        expr: node.expr.map(|v| Box::new((*v).fold(folder))),
+        ..node
+    }
+}
+
+pub fn walk_expr_spread_fold<F: crate::Fold>(folder: &mut F, node: ExprSpread) -> ExprSpread {
+    ExprSpread {
+        expr: Box::new((*node.expr).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_type_spread_fold<F: crate::Fold>(folder: &mut F, node: TypeSpread) -> TypeSpread {
+    TypeSpread {
+        ty: node.ty.fold(folder),
+        ..node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::{
+        Fold, Syntax,
+        expr::{Expr, ExprFold, ExprParser, MatchFold},
+        fields::FieldsFold,
+        meta::MetaFold,
+        param::ParamFold,
+        pat::PatternFold,
+        stmt::StmtFold,
+        ty::TypeFold,
+    };
+
+    struct Ungroup;
+
+    impl ExprFold for Ungroup {
+        fn fold_expr(&mut self, node: Expr) -> Expr {
+            match node {
+                Expr::Group(v) => *v.inner,
+                v => v,
+            }
+        }
+    }
+
+    impl TypeFold for Ungroup {}
+    impl StmtFold for Ungroup {}
+    impl MatchFold for Ungroup {}
+    impl PatternFold for Ungroup {}
+    impl ParamFold for Ungroup {}
+    impl FieldsFold for Ungroup {}
+    impl MetaFold for Ungroup {}
+    impl Fold for Ungroup {}
+
+    #[test]
+    fn should_fold_bottom_up() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"((a)) + ((b * (c)))").cursor();
+        let expr = parser.parse_expr(&mut cursor)?.fold(&mut Ungroup);
+
+        debug_assert!(expr.is_arithmetic());
+        debug_assert!(expr.as_arithmetic().left.is_path());
+        debug_assert!(expr.as_arithmetic().right.is_arithmetic());
+
+        let right = expr.as_arithmetic().right.as_arithmetic();
+
+        debug_assert!(right.left.is_path());
+        debug_assert!(right.right.is_path());
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Comma, Gt, Lt, Punctuated};
+
+use crate::{
+    expr::{Expr, ExprParser},
+    ty::Type,
+};
+
+///
+/// ## Generic Args
+/// the arguments of a path section
+/// `Array::<T, 4>`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GenericArgs {
+    pub lt: Lt,
+    pub args: Punctuated<GenericArg, Comma>,
+    pub gt: Gt,
+}
+
+impl std::fmt::Display for GenericArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for GenericArgs {
+    fn span(&self) -> zinq_parse::Span {
+        Span::join(self.lt.span(), self.gt.span())
+    }
+}
+
+impl Peek for GenericArgs {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Lt>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for GenericArgs {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let lt = parser.parse::<Lt>(cursor)?;
+        let args = parser.parse::<Punctuated<GenericArg, Comma>>(cursor)?;
+        let gt = parser.parse::<Gt>(cursor)?;
+
+        Ok(Self { lt, args, gt })
+    }
+}
+
+///
+/// ## Generic Arg
+/// anything that parses as a type is a type argument,
+/// otherwise it is a const argument like `4`, `-1` or `(N + 1)`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GenericArg {
+    Type(Type),
+    Const(Expr),
+}
+
+impl GenericArg {
+    pub fn is_type(&self) -> bool {
+        matches!(self, Self::Type(_))
+    }
+
+    pub fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+}
+
+impl std::fmt::Display for GenericArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Type(v) => write!(f, "{}", v),
+            Self::Const(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Peek for GenericArg {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        match fork_parser.parse::<Self>(&mut fork) {
+            Err(_) => Ok(false),
+            Ok(_) => Ok(true),
+        }
+    }
+}
+
+impl Parse for GenericArg {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        if parser.peek::<Type>(cursor).unwrap_or(false) {
+            return Ok(Self::Type(parser.parse::<Type>(cursor)?));
+        }
+
+        // a full expression would read the closing `>` as a comparison
+        Ok(Self::Const(parser.parse_unary_expr(cursor)?))
+    }
+}
+
+impl Spanned for GenericArg {
+    fn span(&self) -> zinq_parse::Span {
+        match self {
+            Self::Type(v) => v.span(),
+            Self::Const(v) => v.span(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::GenericArgs;
+
+    #[test]
+    fn should_parse_types_and_consts() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"<T, &string, 4, (N + 1)>").cursor();
+        let syntax = parser.parse::<GenericArgs>(&mut cursor)?;
+        let kinds = syntax
+            .args
+            .iter()
+            .map(|arg| arg.value().is_const())
+            .collect::<Vec<_>>();
+
+        debug_assert_eq!(syntax.to_string(), "<T, &string, 4, (N + 1)>");
+        debug_assert_eq!(kinds, vec![false, false, true, true]);
+
+        Ok(())
+    }
+}
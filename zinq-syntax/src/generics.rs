@@ -1,15 +1,43 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Comma, Gt, Lt, Punctuated};
 
-use crate::param::TypeParam;
+use crate::{Syntax, param::GenericParam};
 
+///
+/// ## Generics
+/// the parameters a declaration is generic over
+/// `<T: Display, U = i32, const N: usize>`
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Generics {
     pub lt: Lt,
-    pub params: Punctuated<TypeParam, Comma>,
+    pub params: Punctuated<GenericParam, Comma>,
     pub gt: Gt,
 }
 
+impl Syntax for Generics {
+    fn name(&self) -> &str {
+        "Generics"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_generics(self)
+            .then(|| crate::walk_generics(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_generics_mut(self)
+            .then(|| crate::walk_generics_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::walk_generics_fold(folder, self);
+        folder.fold_generics(node)
+    }
+}
+
 impl std::fmt::Display for Generics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.span())
@@ -37,7 +65,7 @@ impl Parse for Generics {
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
         let lt = parser.parse::<Lt>(cursor)?;
-        let params = parser.parse::<Punctuated<TypeParam, Comma>>(cursor)?;
+        let params = parser.parse::<Punctuated<GenericParam, Comma>>(cursor)?;
         let gt = parser.parse::<Gt>(cursor)?;
 
         Ok(Self { lt, params, gt })
@@ -102,4 +130,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_with_default() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"<T, U: Cmp = i32>").cursor();
+
+        let syntax = parser.parse::<Generics>(&mut cursor)?;
+        let param = syntax.params[1].value().as_type();
+
+        debug_assert_eq!(syntax.to_string(), "<T, U: Cmp = i32>");
+        debug_assert_eq!(param.default.as_ref().unwrap().suffix.to_string(), "i32");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_with_const() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"<T, const N: usize>").cursor();
+
+        let syntax = parser.parse::<Generics>(&mut cursor)?;
+
+        debug_assert_eq!(syntax.params.len(), 2);
+        debug_assert!(syntax.params[0].value().is_type());
+        debug_assert!(syntax.params[1].value().is_const());
+        debug_assert_eq!(syntax.params[1].value().as_const().ident.to_string(), "N");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,87 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Apostrophe, Ident};
+
+///
+/// ## Label
+/// the name of a loop, `'outer` or `outer`
+///
+/// ```
+/// 'outer: loop {
+///     break 'outer;
+/// }
+/// ```
+///
+/// `break` only accepts the `'outer` form since
+/// `break outer` already breaks with a value
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    pub tick: Option<Apostrophe>,
+    pub name: Ident,
+}
+
+impl Label {
+    pub fn is_ticked(&self) -> bool {
+        self.tick.is_some()
+    }
+}
+
+impl std::fmt::Display for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for Label {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Apostrophe>(cursor).unwrap_or(false)
+            || parser.peek::<Ident>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for Label {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let tick = parser.parse::<Option<Apostrophe>>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+
+        Ok(Self { tick, name })
+    }
+}
+
+impl Spanned for Label {
+    fn span(&self) -> Span {
+        match &self.tick {
+            None => self.name.span(),
+            Some(tick) => Span::join(tick.span(), self.name.span()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::Label;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"'outer inner").cursor();
+        let outer = parser.parse::<Label>(&mut cursor)?;
+        let inner = parser.parse::<Label>(&mut cursor)?;
+
+        debug_assert!(outer.is_ticked());
+        debug_assert_eq!(outer.to_string(), "'outer");
+        debug_assert!(!inner.is_ticked());
+        debug_assert_eq!(inner.name.to_string(), "inner");
+
+        Ok(())
+    }
+}
@@ -1,32 +1,206 @@
 mod bounds;
 pub mod expr;
 pub mod fields;
+mod fold;
+mod generic_args;
 mod generics;
+mod label;
+pub mod macros;
 pub mod members;
 pub mod meta;
+mod module;
+mod package;
 pub mod param;
 pub mod pat;
 mod path;
+mod source_file;
 pub mod spread;
 pub mod stmt;
 pub mod ty;
 mod use_path;
 mod variant;
 mod visibility;
+mod visitor;
+mod visitor_mut;
+mod where_clause;
 
 pub use bounds::*;
+pub use fold::*;
+pub use generic_args::*;
 pub use generics::*;
+pub use label::*;
+pub use module::*;
+pub use package::*;
 pub use path::*;
+pub use source_file::*;
 pub use use_path::*;
 pub use variant::*;
 pub use visibility::*;
+pub use visitor::*;
+pub use visitor_mut::*;
+pub use where_clause::*;
+
+use zinq_parse::{Parse, ParseResult, Span, ZinqParser};
+use zinq_token::{Token, TokenCursor};
+
+///
+/// ## parse_tokens
+/// parse a syntax node from already lexed tokens,
+/// keeping the spans of the original tokens
+///
+/// ```
+/// let expr = parse_tokens::<Expr>(&args.inner_tokens()?)?;
+/// ```
+///
+pub fn parse_tokens<T: Parse>(tokens: &[Token]) -> zinq_error::Result<T> {
+    let mut parser = ZinqParser::new();
+    let mut cursor = TokenCursor::new(tokens);
+    let value = cursor.parse::<T>(&mut parser)?;
+
+    if let Some(token) = cursor.peek() {
+        return Err(cursor.error(&format!("unexpected token '{}'", token)));
+    }
+
+    Ok(value)
+}
+
+///
+/// ## parse_file
+/// read and parse a whole source file, module level
+/// problems are reported in the `ParseResult` diagnostic
+///
+/// ```
+/// let result = parse_file("./src/main.zq")?;
+/// ```
+///
+pub fn parse_file(path: &str) -> zinq_error::Result<ParseResult<SourceFile>> {
+    let mut parser = ZinqParser::new();
+    let mut cursor = Span::from_file(path)?.cursor();
+    let file = parser.parse::<SourceFile>(&mut cursor)?;
+
+    Ok(cursor.build(file))
+}
 
 pub trait Syntax {
     fn name(&self) -> &str;
-    fn accept<V: Visitor>(&self, visitor: &mut V);
+    fn accept<V: Visitor>(&self, visitor: &mut V) -> Visit;
+    fn accept_mut<V: VisitorMut>(&mut self, visitor: &mut V) -> Visit;
+    fn fold<F: Fold>(self, folder: &mut F) -> Self;
 }
 
+///
+/// ## Visitor
+/// hooks called on each node before its children,
+/// the returned `Visit` decides if they are walked
+///
+/// ```
+/// impl StmtVisitor for Names {
+///     fn visit_fn_stmt(&mut self, node: &FnStmt) -> Visit {
+///         self.0.push(node.name.to_string());
+///         Visit::Skip
+///     }
+/// }
+///
+/// file.accept(&mut names);
+/// ```
+///
+#[allow(unused)]
 pub trait Visitor:
-    ty::TypeVisitor + stmt::StmtVisitor + expr::ExprVisitor + pat::PatternVisitor + param::ParamVisitor
+    ty::TypeVisitor
+    + stmt::StmtVisitor
+    + expr::ExprVisitor
+    + pat::PatternVisitor
+    + param::ParamVisitor
+    + fields::FieldsVisitor
+    + meta::MetaVisitor
 {
+    fn visit_source_file(&mut self, node: &SourceFile) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_generics(&mut self, node: &Generics) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_where_clause(&mut self, node: &WhereClause) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_variant(&mut self, node: &Variant) -> Visit {
+        Visit::Continue
+    }
+}
+
+///
+/// ## Visitor Mut
+/// a `Visitor` that can edit each node in place
+/// before its children are walked
+///
+#[allow(unused)]
+pub trait VisitorMut:
+    ty::TypeVisitorMut
+    + stmt::StmtVisitorMut
+    + expr::ExprVisitorMut
+    + pat::PatternVisitorMut
+    + param::ParamVisitorMut
+    + fields::FieldsVisitorMut
+    + meta::MetaVisitorMut
+{
+    fn visit_source_file_mut(&mut self, node: &mut SourceFile) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_generics_mut(&mut self, node: &mut Generics) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_where_clause_mut(&mut self, node: &mut WhereClause) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_variant_mut(&mut self, node: &mut Variant) -> Visit {
+        Visit::Continue
+    }
+}
+
+///
+/// ## Fold
+/// rebuilds a tree bottom up, each hook gets a
+/// node whose children are already folded and
+/// returns the node that replaces it
+///
+/// ```
+/// impl ExprFold for Negate {
+///     fn fold_literal_expr(&mut self, node: LiteralExpr) -> LiteralExpr {
+///         ...
+///     }
+/// }
+///
+/// let expr = expr.fold(&mut negate);
+/// ```
+///
+pub trait Fold:
+    ty::TypeFold
+    + stmt::StmtFold
+    + expr::ExprFold
+    + pat::PatternFold
+    + param::ParamFold
+    + fields::FieldsFold
+    + meta::MetaFold
+{
+    fn fold_source_file(&mut self, node: SourceFile) -> SourceFile {
+        node
+    }
+
+    fn fold_generics(&mut self, node: Generics) -> Generics {
+        node
+    }
+
+    fn fold_where_clause(&mut self, node: WhereClause) -> WhereClause {
+        node
+    }
+
+    fn fold_variant(&mut self, node: Variant) -> Variant {
+        node
+    }
 }
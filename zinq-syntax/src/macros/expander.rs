@@ -0,0 +1,730 @@
+use std::collections::HashMap;
+
+use zinq_error::ZinqError;
+use zinq_parse::{Parse, Spanned};
+
+use crate::{
+    SourceFile, Syntax, Visit, VisitorMut,
+    expr::{Expr, ExprVisitorMut, MatchVisitorMut},
+    fields::FieldsVisitorMut,
+    macros::{Expansion, MacroCall, Matcher, RECURSION_LIMIT, Transcriber, macro_error},
+    meta::MetaVisitorMut,
+    param::ParamVisitorMut,
+    pat::{Pattern, PatternVisitorMut},
+    stmt::{BlockStmt, MacroStmt, ModStmt, Stmt, StmtVisitorMut},
+    ty::{Type, TypeVisitorMut},
+};
+
+///
+/// ## Expander
+/// the declarative macros of a program, keyed
+/// by name, and the pass that expands their
+/// calls between parsing and lowering
+///
+/// ```
+/// let mut expander = Expander::new();
+/// let file = expander.expand_file(file)?;
+/// let expr = expander.expand::<Expr>(&call)?;
+/// ```
+///
+/// calls in item and statement position are
+/// replaced by `expand_file` in every block of
+/// the file, including those nested in expressions,
+/// calls in expression, pattern and type position
+/// are kept in the tree and expanded with `expand`
+/// when they are lowered
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expander {
+    macros: HashMap<String, Vec<MacroRuleSet>>,
+}
+
+// a compiled `(pattern) => { body }` rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MacroRuleSet {
+    matchers: Vec<Matcher>,
+    transcribers: Vec<Transcriber>,
+}
+
+impl Expander {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.macros.contains_key(name)
+    }
+
+    ///
+    /// ## define
+    /// compile the rules of a macro definition,
+    /// a later definition replaces an earlier one
+    ///
+    pub fn define(&mut self, stmt: &MacroStmt) -> zinq_error::Result<&mut Self> {
+        let mut rules = vec![];
+
+        for rule in &stmt.rules {
+            rules.push(MacroRuleSet {
+                matchers: Matcher::compile(&rule.pattern)?,
+                transcribers: Transcriber::compile(&rule.body)?,
+            });
+        }
+
+        self.macros.insert(stmt.name.to_string(), rules);
+        Ok(self)
+    }
+
+    ///
+    /// ## expansion
+    /// transcribe the first rule whose pattern
+    /// matches the call arguments
+    ///
+    pub fn expansion(&self, call: &MacroCall) -> zinq_error::Result<Expansion> {
+        let name = call.name();
+        let Some(rules) = self.macros.get(&name) else {
+            return Err(macro_error(
+                call.span(),
+                &format!("cannot find macro '{}!'", name),
+            ));
+        };
+
+        for rule in rules {
+            if let Some(captures) = Matcher::matches(&rule.matchers, &call.args) {
+                let mut out = Expansion::new(call.span());
+                Transcriber::transcribe(&rule.transcribers, &captures, &mut out)?;
+                return Ok(out);
+            }
+        }
+
+        Err(macro_error(
+            call.args.span(),
+            &format!("no rules of '{}!' match these arguments", name),
+        ))
+    }
+
+    ///
+    /// ## expand
+    /// expand a call in expression, pattern or
+    /// type position and re-parse it as a `T`,
+    /// every call it expands to is expanded as well
+    ///
+    pub fn expand<T: Parse + Syntax>(&mut self, call: &MacroCall) -> zinq_error::Result<T> {
+        self.expand_at(call, 0)
+    }
+
+    ///
+    /// ## expand_stmts
+    /// expand a call in item or statement position,
+    /// nested statement calls are expanded as well
+    ///
+    pub fn expand_stmts(&mut self, call: &MacroCall) -> zinq_error::Result<Vec<Stmt>> {
+        self.expand_call(call, 0, false)
+    }
+
+    ///
+    /// ## expand_file
+    /// define every macro of the file, then replace
+    /// the item and statement calls of its modules
+    /// and of every block with their expansions
+    ///
+    pub fn expand_file(&mut self, mut file: SourceFile) -> zinq_error::Result<SourceFile> {
+        file.items = self.expand_items(file.items, 0, false)?;
+        Ok(file)
+    }
+
+    fn expand_at<T: Parse + Syntax>(
+        &mut self,
+        call: &MacroCall,
+        depth: usize,
+    ) -> zinq_error::Result<T> {
+        if depth >= RECURSION_LIMIT {
+            return Err(recursion_error(call));
+        }
+
+        let mut value = self.expansion(call)?.parse::<T>()?;
+        self.expand_nested(&mut value, depth + 1, true)?;
+        Ok(value)
+    }
+
+    fn expand_call(
+        &mut self,
+        call: &MacroCall,
+        depth: usize,
+        nested: bool,
+    ) -> zinq_error::Result<Vec<Stmt>> {
+        if depth >= RECURSION_LIMIT {
+            return Err(recursion_error(call));
+        }
+
+        let stmts = self.expansion(call)?.parse_many::<Stmt>()?;
+        self.expand_items(stmts, depth + 1, nested)
+    }
+
+    fn expand_items(
+        &mut self,
+        items: Vec<Stmt>,
+        depth: usize,
+        nested: bool,
+    ) -> zinq_error::Result<Vec<Stmt>> {
+        // definitions are visible to the whole list they appear in
+        for item in &items {
+            if let Stmt::Macro(v) = item {
+                self.define(v)?;
+            }
+        }
+
+        let mut out = vec![];
+
+        for mut item in items {
+            match item {
+                Stmt::MacroCall(v) => out.extend(self.expand_call(&v.call, depth, nested)?),
+                _ => {
+                    self.expand_nested(&mut item, depth, nested)?;
+                    out.push(item);
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn expand_nested<T: Syntax>(
+        &mut self,
+        node: &mut T,
+        depth: usize,
+        nested: bool,
+    ) -> zinq_error::Result<()> {
+        let mut calls = NestedCalls {
+            expander: self,
+            depth,
+            nested,
+            error: None,
+        };
+
+        node.accept_mut(&mut calls);
+
+        match calls.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+fn recursion_error(call: &MacroCall) -> ZinqError {
+    macro_error(
+        call.span(),
+        &format!("recursion limit reached while expanding '{}!'", call.name()),
+    )
+}
+
+// replaces the statement calls of every block and
+// module below a node and, when `nested`, the calls
+// in expression, pattern and type position
+struct NestedCalls<'a> {
+    expander: &'a mut Expander,
+    depth: usize,
+    nested: bool,
+    error: Option<ZinqError>,
+}
+
+impl NestedCalls<'_> {
+    fn replace<T: Parse + Syntax>(&mut self, call: &MacroCall, node: &mut T) -> Visit {
+        if !self.nested {
+            return Visit::Skip;
+        }
+
+        match self.expander.expand_at::<T>(call, self.depth) {
+            Ok(v) => {
+                *node = v;
+                Visit::Skip
+            }
+            Err(err) => self.stop(err),
+        }
+    }
+
+    fn replace_items(&mut self, items: &mut Vec<Stmt>) -> Visit {
+        let expanded = self
+            .expander
+            .expand_items(std::mem::take(items), self.depth, self.nested);
+
+        match expanded {
+            Ok(v) => {
+                *items = v;
+                Visit::Skip
+            }
+            Err(err) => self.stop(err),
+        }
+    }
+
+    fn stop(&mut self, err: ZinqError) -> Visit {
+        self.error = Some(err);
+        Visit::Stop
+    }
+}
+
+impl ExprVisitorMut for NestedCalls<'_> {
+    fn visit_expr_mut(&mut self, node: &mut Expr) -> Visit {
+        match node {
+            Expr::Macro(v) => {
+                let call = v.call.clone();
+                self.replace(&call, node)
+            }
+            _ => Visit::Continue,
+        }
+    }
+}
+
+impl PatternVisitorMut for NestedCalls<'_> {
+    fn visit_pattern_mut(&mut self, node: &mut Pattern) -> Visit {
+        match node {
+            Pattern::Macro(v) => {
+                let call = v.call.clone();
+                self.replace(&call, node)
+            }
+            _ => Visit::Continue,
+        }
+    }
+}
+
+impl TypeVisitorMut for NestedCalls<'_> {
+    fn visit_type_mut(&mut self, node: &mut Type) -> Visit {
+        match node {
+            Type::Macro(v) => {
+                let call = v.call.clone();
+                self.replace(&call, node)
+            }
+            _ => Visit::Continue,
+        }
+    }
+}
+
+impl StmtVisitorMut for NestedCalls<'_> {
+    fn visit_block_stmt_mut(&mut self, node: &mut BlockStmt) -> Visit {
+        // the statements are visited as they are expanded
+        if self.replace_items(&mut node.stmts).is_stop() {
+            return Visit::Stop;
+        }
+
+        if let Some(tail) = &mut node.tail
+            && tail.accept_mut(self).is_stop()
+        {
+            return Visit::Stop;
+        }
+
+        Visit::Skip
+    }
+
+    fn visit_mod_stmt_mut(&mut self, node: &mut ModStmt) -> Visit {
+        match &mut node.body {
+            Some(body) => self.replace_items(&mut body.items),
+            None => Visit::Skip,
+        }
+    }
+}
+
+impl MatchVisitorMut for NestedCalls<'_> {}
+impl ParamVisitorMut for NestedCalls<'_> {}
+impl FieldsVisitorMut for NestedCalls<'_> {}
+impl MetaVisitorMut for NestedCalls<'_> {}
+impl VisitorMut for NestedCalls<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::{Span, Spanned};
+
+    use crate::{
+        SourceFile, Syntax, Visit, Visitor,
+        expr::{Expr, ExprVisitor, MatchVisitor},
+        fields::FieldsVisitor,
+        macros::Expander,
+        meta::MetaVisitor,
+        param::ParamVisitor,
+        pat::{Pattern, PatternVisitor},
+        stmt::{LetStmt, MacroCallStmt, Stmt, StmtVisitor},
+        ty::{Type, TypeVisitor},
+    };
+
+    fn parse_file(src: &str) -> Result<(Expander, SourceFile)> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_str(src).cursor();
+        let file = parser.parse::<SourceFile>(&mut cursor)?;
+        let mut expander = Expander::new();
+        let file = expander.expand_file(file)?;
+
+        Ok((expander, file))
+    }
+
+    #[test]
+    fn should_expand_expr() -> Result<()> {
+        let (mut expander, file) = parse_file(
+            "macro sum {
+    ($($x:expr),*) => { 0 $(+ $x)* };
+}
+
+fn main() { sum!(1, a * 2, 3) }",
+        )?;
+
+        let main = file.items[1].as_fn();
+        let call = &main.block.tail.as_ref().unwrap().as_macro().call;
+        let expr = expander.expand::<Expr>(call)?;
+
+        // a multi token `$x:expr` keeps its precedence
+        debug_assert_eq!(expander.expansion(call)?.as_str(), "0 + 1 + ( a * 2 ) + 3");
+        debug_assert!(expr.is_arithmetic());
+        debug_assert!(expr.as_arithmetic().left.is_arithmetic());
+        debug_assert!(expr.as_arithmetic().left.as_arithmetic().right.is_group());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_stmts() -> Result<()> {
+        let (_, file) = parse_file(
+            "macro vars {
+    ($($name:ident = $value:expr),+) => { $(let $name = $value;)+ };
+}
+
+fn main() {
+    vars!(a = 1, b = a + 1);
+    a + b
+}",
+        )?;
+
+        let main = file.items[1].as_fn();
+
+        debug_assert_eq!(main.block.stmts.len(), 2);
+        debug_assert!(main.block.stmts.iter().all(|stmt| stmt.is_let()));
+        debug_assert_eq!(main.block.stmts[1].as_let().pattern.to_string(), "b");
+        debug_assert!(main.block.tail.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_items() -> Result<()> {
+        let (_, file) = parse_file(
+            "mod shapes {
+    unit!(Circle);
+    unit!(Square);
+}
+
+macro unit {
+    ($name:ident) => { pub struct $name {} };
+}",
+        )?;
+
+        let body = file.items[0].as_mod().body.as_ref().unwrap();
+
+        debug_assert_eq!(body.items.len(), 2);
+        debug_assert!(body.items.iter().all(|item| item.is_struct()));
+        debug_assert_eq!(body.items[1].as_struct().name.to_string(), "Square");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_pattern_and_type() -> Result<()> {
+        let (mut expander, file) = parse_file(
+            "macro pair {
+    ($t:ty) => { ($t, $t) };
+    ($a:pat, $b:pat) => { ($a, $b) };
+}
+
+fn main() { let pair!(x, _): pair!(u8) = p; }",
+        )?;
+
+        let main = file.items[1].as_fn();
+        let value = main.block.stmts[0].as_let();
+        let pattern = expander.expand::<Pattern>(&value.pattern.as_macro().call)?;
+        let ty = expander.expand::<Type>(&let_ty(value).as_macro().call)?;
+
+        debug_assert!(pattern.is_tuple());
+        debug_assert_eq!(pattern.as_tuple().items.len(), 2);
+        debug_assert!(ty.is_tuple());
+        debug_assert_eq!(ty.as_tuple().items.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_trace_spans() -> Result<()> {
+        let (expander, file) = parse_file(
+            "macro double {
+    ($x:expr) => { $x * 2 };
+}
+
+fn main() {
+    double!(value)
+}",
+        )?;
+
+        let main = file.items[1].as_fn();
+        let call = &main.block.tail.as_ref().unwrap().as_macro().call;
+        let expansion = expander.expansion(call)?;
+        let expr = expansion.parse::<Expr>()?;
+        let value = &expr.as_arithmetic().left;
+        let two = &expr.as_arithmetic().right;
+
+        // captured tokens keep the call's spans, copied tokens the rule's
+        debug_assert_eq!(value.span().start().line(), 5);
+        debug_assert_eq!(value.span().to_string(), "value");
+        debug_assert_eq!(two.span().start().line(), 1);
+        debug_assert_eq!(two.span().to_string(), "2");
+        debug_assert_eq!(expansion.call_site(), &call.span());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_keep_body_idents() -> Result<()> {
+        let (_, file) = parse_file(
+            "macro mk {
+    () => { fn hello() {} };
+}
+
+mk!();",
+        )?;
+
+        debug_assert_eq!(file.items.len(), 2);
+        debug_assert_eq!(file.items[1].as_fn().name.to_string(), "hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_calls_in_expansions() -> Result<()> {
+        let (_, file) = parse_file(
+            "macro unit {
+    ($name:ident) => { pub struct $name {} };
+}
+
+macro units {
+    ($($name:ident),*) => { $(unit!($name);)* };
+}
+
+units!(Circle, Square);",
+        )?;
+
+        let names = file.items[2..]
+            .iter()
+            .map(|item| item.as_struct().name.to_string())
+            .collect::<Vec<_>>();
+
+        debug_assert_eq!(names, vec!["Circle", "Square"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_nested_expr() -> Result<()> {
+        let (mut expander, file) = parse_file(
+            "macro max {
+    ($a:expr) => { $a };
+    ($a:expr, $($rest:expr),+) => {
+        { let b = max!($($rest),+); if $a > b { $a } else { b } }
+    };
+}
+
+fn main() {
+    let value = max!(1, 5, 3);
+}",
+        )?;
+
+        let main = file.items[1].as_fn();
+        let call = &let_init(&main.block.stmts[0]).as_macro().call;
+        let expr = expander.expand::<Expr>(call)?;
+        let inner = let_init(&expr.as_block().block.stmts[0]);
+
+        debug_assert!(inner.is_block());
+        debug_assert!(let_init(&inner.as_block().block.stmts[0]).is_literal());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_error_on_recursion_limit() -> Result<()> {
+        let (mut expander, file) = parse_file(
+            "macro m {
+    () => { m!() };
+}
+
+fn main() {
+    let m!() = m!();
+}",
+        )?;
+
+        let main = file.items[1].as_fn();
+        let call = &let_init(&main.block.stmts[0]).as_macro().call;
+        let err = expander.expand::<Expr>(call).unwrap_err();
+
+        debug_assert!(
+            err.to_string()
+                .contains("recursion limit reached while expanding 'm!'")
+        );
+
+        let call = &main.block.stmts[0].as_let().pattern.as_macro().call;
+        let err = expander.expand::<Pattern>(call).unwrap_err();
+
+        debug_assert!(
+            err.to_string()
+                .contains("recursion limit reached while expanding 'm!'")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_error_on_no_matching_rule() -> Result<()> {
+        let src = "macro one {
+    ($x:literal) => { $x };
+}
+
+fn main() { one!(a + b); }";
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_str(src).cursor();
+        let file = parser.parse::<SourceFile>(&mut cursor)?;
+        let err = Expander::new().expand_file(file).unwrap_err();
+
+        debug_assert!(
+            err.to_string()
+                .contains("no rules of 'one!' match these arguments")
+        );
+
+        let mut cursor = Span::from_bytes(b"fn main() { two!(); }").cursor();
+        let file = parser.parse::<SourceFile>(&mut cursor)?;
+        let err = Expander::new().expand_file(file).unwrap_err();
+
+        debug_assert!(err.to_string().contains("cannot find macro 'two!'"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_stmts_in_block_exprs() -> Result<()> {
+        let lets = expand_lets("fn main() { let x = { one!(); one }; }")?;
+
+        debug_assert_eq!(lets.calls, 0);
+        debug_assert_eq!(lets.names, ["x", "one"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_stmts_in_closures() -> Result<()> {
+        let lets = expand_lets("fn main() { let f = || { one!(); one }; }")?;
+
+        debug_assert_eq!(lets.calls, 0);
+        debug_assert_eq!(lets.names, ["f", "one"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_stmts_in_match_arms() -> Result<()> {
+        let lets = expand_lets("fn main() { match a { _ => { one!(); one } } }")?;
+
+        debug_assert_eq!(lets.calls, 0);
+        debug_assert_eq!(lets.names, ["one"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_stmts_in_let_else() -> Result<()> {
+        let lets = expand_lets("fn main() { let Some(x) = y else { one!(); return; }; }")?;
+
+        debug_assert_eq!(lets.calls, 0);
+        debug_assert_eq!(lets.names, ["Some(x)", "one"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_stmts_in_protocol_methods() -> Result<()> {
+        let lets = expand_lets("protocol P { fn f(&self) -> i32 { one!(); one } }")?;
+
+        debug_assert_eq!(lets.calls, 0);
+        debug_assert_eq!(lets.names, ["one"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_stmts_in_expansions() -> Result<()> {
+        let (mut expander, file) = parse_file(
+            "macro one {
+    () => { let one = 1; };
+}
+
+macro block {
+    () => { { one!(); one } };
+}
+
+fn main() { block!() }",
+        )?;
+
+        let main = file.items[2].as_fn();
+        let call = &main.block.tail.as_ref().unwrap().as_macro().call;
+        let expr = expander.expand::<Expr>(call)?;
+        let mut lets = Lets::default();
+
+        expr.accept(&mut lets);
+
+        debug_assert_eq!(lets.calls, 0);
+        debug_assert_eq!(lets.names, ["one"]);
+
+        Ok(())
+    }
+
+    fn let_ty(stmt: &LetStmt) -> &Type {
+        &stmt.ty.as_ref().unwrap().suffix
+    }
+
+    fn let_init(stmt: &Stmt) -> &Expr {
+        &stmt.as_let().init.as_ref().unwrap().suffix
+    }
+
+    #[derive(Default)]
+    struct Lets {
+        names: Vec<String>,
+        calls: usize,
+    }
+
+    impl StmtVisitor for Lets {
+        fn visit_let_stmt(&mut self, node: &LetStmt) -> Visit {
+            self.names.push(node.pattern.to_string());
+            Visit::Continue
+        }
+
+        fn visit_macro_call_stmt(&mut self, _: &MacroCallStmt) -> Visit {
+            self.calls += 1;
+            Visit::Continue
+        }
+    }
+
+    impl ExprVisitor for Lets {}
+    impl MatchVisitor for Lets {}
+    impl PatternVisitor for Lets {}
+    impl TypeVisitor for Lets {}
+    impl ParamVisitor for Lets {}
+    impl FieldsVisitor for Lets {}
+    impl MetaVisitor for Lets {}
+    impl Visitor for Lets {}
+
+    // expand `src` after a `one!()` that declares `one`
+    fn expand_lets(src: &str) -> Result<Lets> {
+        let (_, file) = parse_file(&format!(
+            "macro one {{
+    () => {{ let one = 1; }};
+}}
+
+{}",
+            src
+        ))?;
+
+        let mut lets = Lets::default();
+        file.accept(&mut lets);
+        Ok(lets)
+    }
+}
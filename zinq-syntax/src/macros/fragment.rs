@@ -0,0 +1,151 @@
+use std::cell::OnceCell;
+
+use zinq_parse::{Spanned, ZinqParser};
+use zinq_token::{Ident, Token, TokenCursor, TokenStream, TokenTree};
+
+use crate::{Path, expr::Expr, pat::Pattern, stmt::BlockStmt, ty::Type};
+
+///
+/// ## Fragment Kind
+/// what a `$name:kind` metavariable matches
+///
+/// - `expr` an expression
+/// - `ty` a type
+/// - `ident` an identifier
+/// - `pat` a pattern
+/// - `path` a path
+/// - `literal` a literal
+/// - `block` a `{ ... }` block
+/// - `tt` a single token tree
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FragmentKind {
+    Expr,
+    Ty,
+    Ident,
+    Pat,
+    Path,
+    Literal,
+    Block,
+    Tt,
+}
+
+impl FragmentKind {
+    pub fn from_ident(ident: &Ident) -> zinq_error::Result<Self> {
+        match ident.to_string().as_str() {
+            "expr" => Ok(Self::Expr),
+            "ty" => Ok(Self::Ty),
+            "ident" => Ok(Self::Ident),
+            "pat" => Ok(Self::Pat),
+            "path" => Ok(Self::Path),
+            "literal" => Ok(Self::Literal),
+            "block" => Ok(Self::Block),
+            "tt" => Ok(Self::Tt),
+            other => Err(super::macro_error(
+                ident.span(),
+                &format!("unknown fragment kind '{}'", other),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Expr => "expr",
+            Self::Ty => "ty",
+            Self::Ident => "ident",
+            Self::Pat => "pat",
+            Self::Path => "path",
+            Self::Literal => "literal",
+            Self::Block => "block",
+            Self::Tt => "tt",
+        }
+    }
+
+    ///
+    /// ## take
+    /// the number of `trees` from `at` a fragment
+    /// of this kind spans, `None` when they
+    /// don't start with one
+    ///
+    pub fn take(&self, trees: &Trees, at: usize) -> Option<usize> {
+        let first = trees.get(at)?;
+
+        match self {
+            Self::Tt => Some(1),
+            Self::Ident => matches!(first, TokenTree::Leaf(Token::Ident(_))).then_some(1),
+            Self::Literal => matches!(first, TokenTree::Leaf(Token::Literal(_))).then_some(1),
+            Self::Expr => take_parsed::<Expr>(trees, at),
+            Self::Ty => take_parsed::<Type>(trees, at),
+            Self::Pat => take_parsed::<Pattern>(trees, at),
+            Self::Path => take_parsed::<Path>(trees, at),
+            Self::Block => take_parsed::<BlockStmt>(trees, at),
+        }
+    }
+}
+
+impl std::fmt::Display for FragmentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+///
+/// ## Trees
+/// the trees of one group level, lexed into a
+/// single `TokenCursor` the first time a fragment
+/// has to be parsed from them
+///
+#[derive(Debug)]
+pub struct Trees<'a> {
+    trees: &'a [TokenTree],
+    tokens: OnceCell<Option<(TokenCursor, Vec<usize>)>>,
+}
+
+impl<'a> Trees<'a> {
+    pub fn new(trees: &'a [TokenTree]) -> Self {
+        Self {
+            trees,
+            tokens: OnceCell::new(),
+        }
+    }
+
+    // the cursor over every tree and the index
+    // of the first token of each, ending with the total
+    fn tokens(&self) -> Option<&(TokenCursor, Vec<usize>)> {
+        self.tokens
+            .get_or_init(|| {
+                let mut tokens = TokenStream::new();
+                let mut starts = vec![];
+
+                for tree in self.trees {
+                    starts.push(tokens.len());
+                    tokens.push(tree).ok()?;
+                }
+
+                starts.push(tokens.len());
+                Some((TokenCursor::new(&tokens), starts))
+            })
+            .as_ref()
+    }
+}
+
+impl std::ops::Deref for Trees<'_> {
+    type Target = [TokenTree];
+
+    fn deref(&self) -> &Self::Target {
+        self.trees
+    }
+}
+
+// parse the longest `T` from the tree at `at`, a
+// successful parse always ends between two trees
+fn take_parsed<T: zinq_parse::Parse>(trees: &Trees, at: usize) -> Option<usize> {
+    let (tokens, starts) = trees.tokens()?;
+    let mut parser = ZinqParser::new();
+    let mut cursor = tokens.fork();
+
+    cursor.seek(starts[at]).parse::<T>(&mut parser).ok()?;
+
+    let end = starts.binary_search(&cursor.index()).ok()?;
+    (end > at).then_some(end - at)
+}
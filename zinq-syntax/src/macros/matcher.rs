@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+
+use zinq_parse::Spanned;
+use zinq_token::{Ident, OpenDelim, Token, TokenTree};
+
+use crate::macros::{FragmentKind, Trees, macro_error};
+
+///
+/// ## Matcher
+/// one piece of a macro rule's pattern
+///
+/// - `fn` a token matched by its text
+/// - `(...)` a group with the same delimiter
+/// - `$x:expr` a fragment bound to `x`
+/// - `$(...),*` a repetition
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Matcher {
+    Token(Token),
+    Group {
+        open: OpenDelim,
+        inner: Vec<Matcher>,
+    },
+    Fragment {
+        name: Ident,
+        kind: FragmentKind,
+    },
+    Repeat {
+        inner: Vec<Matcher>,
+        sep: Option<Token>,
+        op: RepeatOp,
+    },
+}
+
+impl Matcher {
+    ///
+    /// ## compile
+    /// build the matchers of a rule pattern
+    /// from the trees between its delimiters
+    ///
+    pub fn compile(trees: &[TokenTree]) -> zinq_error::Result<Vec<Self>> {
+        let mut matchers = vec![];
+        let mut i = 0;
+
+        while let Some(tree) = trees.get(i) {
+            i += 1;
+
+            let dollar = match tree {
+                TokenTree::Group(group) => {
+                    matchers.push(Self::Group {
+                        open: group.open.clone(),
+                        inner: Self::compile(group)?,
+                    });
+
+                    continue;
+                }
+                TokenTree::Leaf(Token::Punct(p)) if p.is_dollar() => p,
+                TokenTree::Leaf(token) => {
+                    matchers.push(Self::Token(token.clone()));
+                    continue;
+                }
+            };
+
+            match trees.get(i) {
+                Some(TokenTree::Group(group)) if group.open.is_left_paren() => {
+                    let (sep, op, len) = RepeatOp::take(&trees[i + 1..])
+                        .ok_or_else(|| macro_error(group.span(), "expected '*', '+' or '?'"))?;
+
+                    matchers.push(Self::Repeat {
+                        inner: Self::compile(group)?,
+                        sep,
+                        op,
+                    });
+
+                    i += 1 + len;
+                }
+                Some(TokenTree::Leaf(Token::Ident(name))) => {
+                    let kind = match (trees.get(i + 1), trees.get(i + 2)) {
+                        (
+                            Some(TokenTree::Leaf(Token::Punct(colon))),
+                            Some(TokenTree::Leaf(Token::Ident(kind))),
+                        ) if colon.is_colon() => FragmentKind::from_ident(kind)?,
+                        _ => {
+                            return Err(macro_error(
+                                name.span(),
+                                &format!("expected a fragment kind, like '${}:expr'", name),
+                            ));
+                        }
+                    };
+
+                    matchers.push(Self::Fragment {
+                        name: name.clone(),
+                        kind,
+                    });
+
+                    i += 3;
+                }
+                _ => {
+                    return Err(macro_error(
+                        dollar.span(),
+                        "expected '$name:kind' or '$(...)'",
+                    ));
+                }
+            }
+        }
+
+        Ok(matchers)
+    }
+
+    ///
+    /// ## matches
+    /// match every tree of a call against the
+    /// matchers, returning the bound fragments
+    ///
+    pub fn matches(matchers: &[Self], trees: &[TokenTree]) -> Option<Captures> {
+        let mut captures = Captures::default();
+        let len = Self::match_prefix(matchers, &Trees::new(trees), 0, &mut captures)?;
+
+        (len == trees.len()).then_some(captures)
+    }
+
+    /// the metavariables bound by the matchers
+    pub fn names(matchers: &[Self]) -> Vec<String> {
+        let mut names = vec![];
+
+        for matcher in matchers {
+            match matcher {
+                Self::Token(_) => {}
+                Self::Group { inner, .. } | Self::Repeat { inner, .. } => {
+                    names.extend(Self::names(inner))
+                }
+                Self::Fragment { name, .. } => names.push(name.to_string()),
+            }
+        }
+
+        names
+    }
+
+    fn match_prefix(
+        matchers: &[Self],
+        trees: &Trees,
+        at: usize,
+        captures: &mut Captures,
+    ) -> Option<usize> {
+        let mut len = 0;
+
+        for matcher in matchers {
+            len += matcher.match_one(trees, at + len, captures)?;
+        }
+
+        Some(len)
+    }
+
+    fn match_one(&self, trees: &Trees, at: usize, captures: &mut Captures) -> Option<usize> {
+        match self {
+            Self::Token(token) => match trees.get(at)? {
+                TokenTree::Leaf(v) if v.to_string() == token.to_string() => Some(1),
+                _ => None,
+            },
+            Self::Group { open, inner } => match trees.get(at)? {
+                TokenTree::Group(group) if group.open.name() == open.name() => {
+                    let len = Self::match_prefix(inner, &Trees::new(group), 0, captures)?;
+                    (len == group.len()).then_some(1)
+                }
+                _ => None,
+            },
+            Self::Fragment { name, kind } => {
+                let len = kind.take(trees, at)?;
+
+                captures.insert(
+                    name.to_string(),
+                    Capture::One(*kind, trees[at..at + len].to_vec()),
+                );
+                Some(len)
+            }
+            Self::Repeat { inner, sep, op } => {
+                let mut end = at;
+                let mut runs: Vec<Captures> = vec![];
+
+                while !(op.is_zero_or_one() && runs.len() == 1) {
+                    let mut start = end;
+
+                    if let (Some(sep), false) = (sep, runs.is_empty()) {
+                        match trees.get(end) {
+                            Some(TokenTree::Leaf(v)) if v.to_string() == sep.to_string() => {
+                                start += 1
+                            }
+                            _ => break,
+                        }
+                    }
+
+                    let mut run = Captures::default();
+
+                    match Self::match_prefix(inner, trees, start, &mut run) {
+                        Some(n) if n > 0 => {
+                            end = start + n;
+                            runs.push(run);
+                        }
+                        _ => break,
+                    }
+                }
+
+                if op.is_one_or_more() && runs.is_empty() {
+                    return None;
+                }
+
+                // every name bound inside becomes a sequence, one per run
+                for name in Self::names(inner) {
+                    let items = runs
+                        .iter()
+                        .map(|run| run.get(&name).cloned().unwrap_or(Capture::Many(vec![])))
+                        .collect();
+
+                    captures.insert(name, Capture::Many(items));
+                }
+
+                Some(end - at)
+            }
+        }
+    }
+}
+
+///
+/// ## Repeat Op
+/// how often a `$(...)` repetition may match
+///
+/// - `*` zero or more
+/// - `+` one or more
+/// - `?` zero or one
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RepeatOp {
+    ZeroOrMore,
+    OneOrMore,
+    ZeroOrOne,
+}
+
+impl RepeatOp {
+    ///
+    /// ## take
+    /// the optional separator and operator following
+    /// a `$(...)` group, and the number of trees they use
+    ///
+    pub fn take(trees: &[TokenTree]) -> Option<(Option<Token>, Self, usize)> {
+        let op = |tree: Option<&TokenTree>| match tree? {
+            TokenTree::Leaf(token) => match token.to_string().as_str() {
+                "*" => Some(Self::ZeroOrMore),
+                "+" => Some(Self::OneOrMore),
+                "?" => Some(Self::ZeroOrOne),
+                _ => None,
+            },
+            TokenTree::Group(_) => None,
+        };
+
+        if let Some(op) = op(trees.first()) {
+            return Some((None, op, 1));
+        }
+
+        match trees.first()? {
+            TokenTree::Leaf(sep) => Some((Some(sep.clone()), op(trees.get(1))?, 2)),
+            TokenTree::Group(_) => None,
+        }
+    }
+
+    pub fn is_zero_or_more(&self) -> bool {
+        matches!(self, Self::ZeroOrMore)
+    }
+
+    pub fn is_one_or_more(&self) -> bool {
+        matches!(self, Self::OneOrMore)
+    }
+
+    pub fn is_zero_or_one(&self) -> bool {
+        matches!(self, Self::ZeroOrOne)
+    }
+}
+
+///
+/// ## Capture
+/// the trees bound to a metavariable, or one
+/// capture per run of an enclosing repetition
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Capture {
+    One(FragmentKind, Vec<TokenTree>),
+    Many(Vec<Capture>),
+}
+
+///
+/// ## Captures
+/// the metavariables bound by a matched rule
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Captures {
+    items: HashMap<String, Capture>,
+}
+
+impl Captures {
+    pub fn get(&self, name: &str) -> Option<&Capture> {
+        self.items.get(name)
+    }
+
+    pub fn insert(&mut self, name: String, capture: Capture) -> &mut Self {
+        self.items.insert(name, capture);
+        self
+    }
+}
@@ -0,0 +1,99 @@
+mod expander;
+mod fragment;
+mod matcher;
+mod registry;
+mod transcriber;
+
+pub use expander::*;
+pub use fragment::*;
+pub use matcher::*;
+pub use registry::*;
+pub use transcriber::*;
+
+use zinq_error::{Error, NOT_FOUND};
+use zinq_parse::{Parse, ParseError, Peek, Span, Spanned};
+use zinq_token::{Group, Not, OpenDelim};
+
+use crate::Path;
+
+/// how deep expansions may nest before they are reported
+pub(crate) const RECURSION_LIMIT: usize = 64;
+
+///
+/// ## Macro Call
+/// `name!(...)`, `name![...]` or `name! { ... }`,
+/// the arguments are kept as tokens until the
+/// `Expander` matches them against a rule
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroCall {
+    pub path: Path,
+    pub bang: Not,
+    pub args: Group,
+}
+
+impl MacroCall {
+    /// the name the macro was defined with
+    pub fn name(&self) -> String {
+        self.path.last().ident.to_string()
+    }
+
+    pub fn is_braced(&self) -> bool {
+        self.args.open.is_left_brace()
+    }
+}
+
+impl std::fmt::Display for MacroCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for MacroCall {
+    fn span(&self) -> Span {
+        Span::join(self.path.span(), self.args.span())
+    }
+}
+
+impl Peek for MacroCall {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        if fork_parser.parse::<Path>(&mut fork).is_err() {
+            return Ok(false);
+        }
+
+        if fork_parser.parse::<Not>(&mut fork).is_err() {
+            return Ok(false);
+        }
+
+        Ok(fork_parser.peek::<OpenDelim>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for MacroCall {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let path = parser.parse::<Path>(cursor)?;
+        let bang = parser.parse::<Not>(cursor)?;
+        let args = parser.parse::<Group>(cursor)?;
+
+        Ok(Self { path, bang, args })
+    }
+}
+
+// macro definitions and expansions fail
+// with a spanned error like the parser
+pub(crate) fn macro_error(span: Span, message: &str) -> zinq_error::ZinqError {
+    ParseError::from_error(
+        span,
+        Error::new().code(NOT_FOUND).message(message).build().into(),
+    )
+    .into()
+}
@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+
+use zinq_error::{Error, UNKNOWN, ZinqError};
+use zinq_parse::{ParseError, Spanned, ZinqParser};
+use zinq_token::{TokenCursor, TokenStream};
+
+use crate::{
+    Path, SourceFile,
+    macros::{RECURSION_LIMIT, macro_error},
+    meta::Attribute,
+    stmt::Stmt,
+};
+
+///
+/// ## Attr Output
+/// what an attribute handler splices into the
+/// module, either syntax or tokens that are
+/// parsed as statements
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttrOutput {
+    Stmts(Vec<Stmt>),
+    Tokens(TokenStream),
+}
+
+impl From<Stmt> for AttrOutput {
+    fn from(value: Stmt) -> Self {
+        Self::Stmts(vec![value])
+    }
+}
+
+impl From<Vec<Stmt>> for AttrOutput {
+    fn from(value: Vec<Stmt>) -> Self {
+        Self::Stmts(value)
+    }
+}
+
+impl From<TokenStream> for AttrOutput {
+    fn from(value: TokenStream) -> Self {
+        Self::Tokens(value)
+    }
+}
+
+///
+/// ## Attr Handler
+/// generates code for the item an attribute is
+/// on, any `Fn(&Stmt, &Attribute)` is a handler
+///
+/// ```
+/// registry.derive("ToString", |item: &Stmt, _: &Attribute| {
+///     let name = &item.as_struct().name;
+///     Ok(quote!(impl ToString for #name { ... })?.into())
+/// });
+/// ```
+///
+pub trait AttrHandler {
+    fn expand(&self, item: &Stmt, attr: &Attribute) -> zinq_error::Result<AttrOutput>;
+}
+
+impl<F> AttrHandler for F
+where
+    F: Fn(&Stmt, &Attribute) -> zinq_error::Result<AttrOutput>,
+{
+    fn expand(&self, item: &Stmt, attr: &Attribute) -> zinq_error::Result<AttrOutput> {
+        self(item, attr)
+    }
+}
+
+///
+/// ## Attr Kind
+/// - `Attr` the output replaces the item
+/// - `Derive` the output is added after the item
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttrKind {
+    Attr,
+    Derive,
+}
+
+impl AttrKind {
+    pub fn is_attr(&self) -> bool {
+        matches!(self, Self::Attr)
+    }
+
+    pub fn is_derive(&self) -> bool {
+        matches!(self, Self::Derive)
+    }
+}
+
+///
+/// ## Attr Registry
+/// attribute handlers keyed by their path, like
+/// `Clone` or `http::post`, and the pass that
+/// runs them over the items of a file
+///
+/// ```
+/// let mut registry = AttrRegistry::new();
+///
+/// registry
+///     .derive("Clone", derive_clone)
+///     .attr("http::post", route);
+///
+/// let file = registry.expand_file(file)?;
+/// ```
+///
+/// items are expanded in source order and the
+/// attributes of an item from first to last,
+/// each handled attribute is removed from the
+/// item before its handler runs, so a handler
+/// sees the attributes that are still pending,
+/// and the output is expanded in turn
+///
+/// attributes without a handler are left
+/// on the item for lowering
+///
+#[derive(Default)]
+pub struct AttrRegistry {
+    handlers: HashMap<String, (AttrKind, Box<dyn AttrHandler>)>,
+}
+
+impl AttrRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.handlers.contains_key(path)
+    }
+
+    ///
+    /// ## attr
+    /// register a handler whose output replaces
+    /// the item, the item is dropped unless the
+    /// handler returns it
+    ///
+    pub fn attr<H: AttrHandler + 'static>(&mut self, path: &str, handler: H) -> &mut Self {
+        self.handlers
+            .insert(path.to_string(), (AttrKind::Attr, Box::new(handler)));
+        self
+    }
+
+    ///
+    /// ## derive
+    /// register a handler whose output is added
+    /// after the item, which is kept
+    ///
+    pub fn derive<H: AttrHandler + 'static>(&mut self, path: &str, handler: H) -> &mut Self {
+        self.handlers
+            .insert(path.to_string(), (AttrKind::Derive, Box::new(handler)));
+        self
+    }
+
+    ///
+    /// ## expand_file
+    /// run the handlers over the items of the
+    /// file and of its inline modules
+    ///
+    pub fn expand_file(&self, mut file: SourceFile) -> zinq_error::Result<SourceFile> {
+        file.items = self.expand_items(file.items, 0)?;
+        Ok(file)
+    }
+
+    ///
+    /// ## expand_item
+    /// run the handlers of one item, returning
+    /// the statements that take its place
+    ///
+    pub fn expand_item(&self, item: Stmt) -> zinq_error::Result<Vec<Stmt>> {
+        self.expand_stmt(item, 0)
+    }
+
+    fn expand_items(&self, items: Vec<Stmt>, depth: usize) -> zinq_error::Result<Vec<Stmt>> {
+        let mut out = vec![];
+
+        for item in items {
+            out.extend(self.expand_stmt(item, depth)?);
+        }
+
+        Ok(out)
+    }
+
+    fn expand_stmt(&self, mut item: Stmt, depth: usize) -> zinq_error::Result<Vec<Stmt>> {
+        let Some(attr) = self.take_attr(&mut item) else {
+            if let Stmt::Mod(v) = &mut item
+                && let Some(body) = &mut v.body
+            {
+                body.items = self.expand_items(std::mem::take(&mut body.items), depth)?;
+            }
+
+            return Ok(vec![item]);
+        };
+
+        let key = path_key(&attr.path);
+
+        if depth >= RECURSION_LIMIT {
+            return Err(macro_error(
+                attr.span(),
+                &format!("recursion limit reached while expanding '#[{}]'", key),
+            ));
+        }
+
+        let (kind, handler) = &self.handlers[&key];
+        let stmts = match handler.expand(&item, &attr) {
+            Err(err) => return Err(attr_error(&attr, &format!("'#[{}]' failed", key), err)),
+            Ok(AttrOutput::Stmts(stmts)) => stmts,
+            Ok(AttrOutput::Tokens(tokens)) => parse_stmts(&tokens).map_err(|err| {
+                attr_error(&attr, &format!("'#[{}]' generated invalid code", key), err)
+            })?,
+        };
+
+        let mut out = vec![];
+
+        // a derive keeps the item, which may have more attributes to run
+        if kind.is_derive() {
+            out.extend(self.expand_stmt(item, depth)?);
+        }
+
+        out.extend(self.expand_items(stmts, depth + 1)?);
+        Ok(out)
+    }
+
+    // remove the first attribute with a handler from the item
+    fn take_attr(&self, item: &mut Stmt) -> Option<Attribute> {
+        let slot = item.meta_mut()?;
+        let meta = slot.as_mut()?;
+        let index = meta
+            .attrs
+            .iter()
+            .position(|attr| self.contains(&path_key(&attr.value().path)))?;
+        let attr = meta.attrs.remove(index);
+
+        if meta.attrs.is_empty() {
+            *slot = None;
+        }
+
+        Some(attr)
+    }
+}
+
+impl std::fmt::Debug for AttrRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.handlers.iter().map(|(path, (kind, _))| (path, kind)))
+            .finish()
+    }
+}
+
+// the registry key of a path, `http::post`
+fn path_key(path: &Path) -> String {
+    path.iter()
+        .map(|section| section.value().ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+fn parse_stmts(tokens: &TokenStream) -> zinq_error::Result<Vec<Stmt>> {
+    let mut parser = ZinqParser::new();
+    let mut cursor = TokenCursor::new(tokens);
+    let mut stmts = vec![];
+
+    while !cursor.eof() {
+        stmts.push(cursor.parse::<Stmt>(&mut parser)?);
+    }
+
+    Ok(stmts)
+}
+
+// handler errors are reported at the attribute
+fn attr_error(attr: &Attribute, message: &str, cause: ZinqError) -> ZinqError {
+    ParseError::from_error(
+        attr.span(),
+        Error::new()
+            .code(UNKNOWN)
+            .message(message)
+            .child(cause)
+            .build()
+            .into(),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use zinq_error::Result;
+    use zinq_parse::{Span, Spanned};
+    use zinq_token::quote;
+
+    use crate::{
+        SourceFile,
+        macros::{AttrRegistry, macro_error},
+        meta::Attribute,
+        stmt::Stmt,
+    };
+
+    fn parse_file(src: &str) -> Result<SourceFile> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_str(src).cursor();
+        parser.parse::<SourceFile>(&mut cursor)
+    }
+
+    #[test]
+    fn should_derive_from_tokens() -> Result<()> {
+        let mut registry = AttrRegistry::new();
+
+        registry.derive("Default", |item: &Stmt, _: &Attribute| {
+            let name = &item.as_struct().name;
+            Ok(quote!(impl #name { pub fn default() -> Self { Self {} } })?.into())
+        });
+
+        let file =
+            registry.expand_file(parse_file("#[Default, Print(pretty)]\nstruct User {}")?)?;
+
+        debug_assert_eq!(file.items.len(), 2);
+        debug_assert!(file.items[0].is_struct());
+        debug_assert!(file.items[1].is_impl());
+
+        // unhandled attributes stay on the item
+        let meta = file.items[0].meta().unwrap();
+
+        debug_assert_eq!(meta.attrs.len(), 1);
+        debug_assert_eq!(meta.attrs[0].value().path.to_string(), "Print");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_replace_with_attr() -> Result<()> {
+        let mut registry = AttrRegistry::new();
+
+        registry.attr("http::post", |item: &Stmt, attr: &Attribute| {
+            let path = &attr.args.as_ref().unwrap()[0];
+            let route = parse_file(&format!("const ROUTE: string = {};", path))?;
+
+            Ok(vec![item.clone(), route.items[0].clone()].into())
+        });
+
+        let file = registry.expand_file(parse_file(
+            "mod api {\n    #[http::post(\"/messages\")]\n    fn on_message() {}\n}",
+        )?)?;
+        let body = file.items[0].as_mod().body.as_ref().unwrap();
+
+        debug_assert_eq!(body.items.len(), 2);
+        debug_assert!(body.items[0].is_fn());
+        debug_assert!(body.items[0].meta().is_none());
+        debug_assert!(body.items[1].is_const());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expand_in_order() -> Result<()> {
+        let order = Rc::new(RefCell::new(vec![]));
+        let mut registry = AttrRegistry::new();
+
+        for name in ["A", "B", "C"] {
+            let order = order.clone();
+
+            registry.derive(name, move |item: &Stmt, _: &Attribute| {
+                let pending = item.meta().map_or(0, |meta| meta.attrs.len());
+                order.borrow_mut().push(format!("{}:{}", name, pending));
+                Ok(vec![].into())
+            });
+        }
+
+        registry.expand_file(parse_file("#[C, A]\nstruct X {}\n#[B]\nstruct Y {}")?)?;
+
+        debug_assert_eq!(*order.borrow(), vec!["C:1", "A:0", "B:0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_report_error_at_attribute() -> Result<()> {
+        let mut registry = AttrRegistry::new();
+
+        registry.derive("Clone", |item: &Stmt, _: &Attribute| {
+            Err(macro_error(item.span(), "only structs can derive Clone"))
+        });
+
+        let file = parse_file("#[Print, Clone]\nfn main() {}")?;
+        let err = registry.expand_file(file).unwrap_err();
+
+        debug_assert!(err.to_string().starts_with("[Clone] => "));
+        debug_assert!(err.to_string().contains("'#[Clone]' failed"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,335 @@
+use zinq_parse::{Parse, SourceMap, Span, Spanned, ZinqParser};
+use zinq_token::{CloseDelim, Ident, OpenDelim, Token, TokenTree};
+
+use crate::macros::{Capture, Captures, FragmentKind, RepeatOp, macro_error};
+
+///
+/// ## Transcriber
+/// one piece of a macro rule's expansion
+///
+/// - `a + 1` tokens copied as they are
+/// - `{...}` a group, transcribed recursively
+/// - `$x` the trees bound to `x`
+/// - `$(...),*` once per run of its metavariables
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Transcriber {
+    Token(Token),
+    Group {
+        open: OpenDelim,
+        inner: Vec<Transcriber>,
+        close: CloseDelim,
+    },
+    Var(Ident),
+    Repeat {
+        inner: Vec<Transcriber>,
+        sep: Option<Token>,
+        op: RepeatOp,
+    },
+}
+
+impl Transcriber {
+    ///
+    /// ## compile
+    /// build the transcribers of a rule expansion
+    /// from the trees between its delimiters
+    ///
+    pub fn compile(trees: &[TokenTree]) -> zinq_error::Result<Vec<Self>> {
+        let mut items = vec![];
+        let mut i = 0;
+
+        while let Some(tree) = trees.get(i) {
+            i += 1;
+
+            let dollar = match tree {
+                TokenTree::Group(group) => {
+                    items.push(Self::Group {
+                        open: group.open.clone(),
+                        inner: Self::compile(group)?,
+                        close: group.close.clone(),
+                    });
+
+                    continue;
+                }
+                TokenTree::Leaf(Token::Punct(p)) if p.is_dollar() => p,
+                TokenTree::Leaf(token) => {
+                    items.push(Self::Token(token.clone()));
+                    continue;
+                }
+            };
+
+            match trees.get(i) {
+                Some(TokenTree::Group(group)) if group.open.is_left_paren() => {
+                    let (sep, op, len) = RepeatOp::take(&trees[i + 1..])
+                        .ok_or_else(|| macro_error(group.span(), "expected '*', '+' or '?'"))?;
+
+                    items.push(Self::Repeat {
+                        inner: Self::compile(group)?,
+                        sep,
+                        op,
+                    });
+
+                    i += 1 + len;
+                }
+                Some(TokenTree::Leaf(Token::Ident(name))) => {
+                    items.push(Self::Var(name.clone()));
+                    i += 1;
+                }
+                _ => {
+                    return Err(macro_error(dollar.span(), "expected '$name' or '$(...)'"));
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    ///
+    /// ## transcribe
+    /// write the expansion into `out`, copied tokens
+    /// keep their span in the rule body and bound
+    /// trees the spans they had in the call
+    ///
+    pub fn transcribe(
+        items: &[Self],
+        captures: &Captures,
+        out: &mut Expansion,
+    ) -> zinq_error::Result<()> {
+        for item in items {
+            match item {
+                Self::Token(token) => {
+                    out.push(&token.to_string(), token.span());
+                }
+                Self::Group { open, inner, close } => {
+                    out.push(&open.to_string(), open.span());
+                    Self::transcribe(inner, captures, out)?;
+                    out.push(&close.to_string(), close.span());
+                }
+                Self::Var(name) => match captures.get(&name.to_string()) {
+                    None => {
+                        return Err(macro_error(
+                            name.span(),
+                            &format!("unknown metavariable '${}'", name),
+                        ));
+                    }
+                    Some(Capture::Many(_)) => {
+                        return Err(macro_error(
+                            name.span(),
+                            &format!("'${}' is still repeating here, use it in '$(...)'", name),
+                        ));
+                    }
+                    Some(Capture::One(kind, trees)) => {
+                        out.push_fragment(*kind, trees);
+                    }
+                },
+                Self::Repeat { inner, sep, .. } => {
+                    let names = Self::names(inner);
+                    let mut runs = None;
+
+                    for name in &names {
+                        if let Some(Capture::Many(items)) = captures.get(name) {
+                            match runs {
+                                Some(len) if len != items.len() => {
+                                    return Err(macro_error(
+                                        out.call_site().clone(),
+                                        &format!("'${}' repeats a different number of times", name),
+                                    ));
+                                }
+                                _ => runs = Some(items.len()),
+                            }
+                        }
+                    }
+
+                    let Some(runs) = runs else {
+                        return Err(macro_error(
+                            out.call_site().clone(),
+                            "'$(...)' has no repeating metavariable",
+                        ));
+                    };
+
+                    for run in 0..runs {
+                        let mut inner_captures = captures.clone();
+
+                        for name in &names {
+                            if let Some(Capture::Many(items)) = captures.get(name) {
+                                inner_captures.insert(name.clone(), items[run].clone());
+                            }
+                        }
+
+                        if let (Some(sep), true) = (sep, run > 0) {
+                            out.push(&sep.to_string(), sep.span());
+                        }
+
+                        Self::transcribe(inner, &inner_captures, out)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn names(items: &[Self]) -> Vec<String> {
+        let mut names = vec![];
+
+        for item in items {
+            match item {
+                Self::Token(_) => {}
+                Self::Group { inner, .. } | Self::Repeat { inner, .. } => {
+                    names.extend(Self::names(inner))
+                }
+                Self::Var(name) => names.push(name.to_string()),
+            }
+        }
+
+        names
+    }
+}
+
+///
+/// ## Expansion
+/// the printed output of a macro, every piece
+/// remembers the span it traces back to and the
+/// span of the call is kept beside them
+///
+#[derive(Debug, Default, Clone)]
+pub struct Expansion {
+    text: String,
+    entries: Vec<(usize, usize, Span)>,
+    call_site: Span,
+}
+
+impl Expansion {
+    pub fn new(call_site: Span) -> Self {
+        Self {
+            call_site,
+            ..Self::default()
+        }
+    }
+
+    /// the span of the call that was expanded
+    pub fn call_site(&self) -> &Span {
+        &self.call_site
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    ///
+    /// ## push
+    /// append `text` one space apart from the
+    /// previous piece, which never merges them
+    ///
+    pub fn push(&mut self, text: &str, span: Span) -> &mut Self {
+        if !self.text.is_empty() {
+            self.text.push(' ');
+        }
+
+        let start = self.text.len();
+        self.text.push_str(text);
+        self.entries.push((start, self.text.len(), span));
+        self
+    }
+
+    ///
+    /// ## push_fragment
+    /// append bound trees with their own spans, an
+    /// expression of more than one tree is wrapped
+    /// in `(...)` so it keeps its precedence
+    ///
+    pub fn push_fragment(&mut self, kind: FragmentKind, trees: &[TokenTree]) -> &mut Self {
+        let wrap = kind == FragmentKind::Expr && trees.len() > 1;
+        let span = match (trees.first(), trees.last()) {
+            (Some(first), Some(last)) => Span::join(first.span(), last.span()),
+            _ => Span::default(),
+        };
+
+        if wrap {
+            self.push("(", span.clone());
+        }
+
+        for tree in trees {
+            self.push_tree(tree);
+        }
+
+        if wrap {
+            self.push(")", span);
+        }
+
+        self
+    }
+
+    fn push_tree(&mut self, tree: &TokenTree) {
+        match tree {
+            TokenTree::Leaf(token) => {
+                self.push(&token.to_string(), token.span());
+            }
+            TokenTree::Group(group) => {
+                self.push(&group.open.to_string(), group.open.span());
+
+                for tree in group.iter() {
+                    self.push_tree(tree);
+                }
+
+                self.push(&group.close.to_string(), group.close.span());
+            }
+        }
+    }
+
+    ///
+    /// ## parse
+    /// parse the whole expansion as a `T`
+    ///
+    pub fn parse<T: Parse>(&self) -> zinq_error::Result<T> {
+        let mut items = self.parse_all::<T>(1)?;
+
+        match items.pop() {
+            Some(item) if items.is_empty() => Ok(item),
+            _ => Err(macro_error(self.span(), "expected a single item")),
+        }
+    }
+
+    ///
+    /// ## parse_many
+    /// parse the expansion as a sequence of `T`
+    ///
+    pub fn parse_many<T: Parse>(&self) -> zinq_error::Result<Vec<T>> {
+        self.parse_all::<T>(usize::MAX)
+    }
+
+    fn parse_all<T: Parse>(&self, max: usize) -> zinq_error::Result<Vec<T>> {
+        let mut parser = ZinqParser::new();
+        let mut cursor = Span::from_str(&self.text)
+            .cursor()
+            .with_map(SourceMap::new(self.entries.clone()));
+        let mut items = vec![];
+
+        while !cursor
+            .fork()
+            .next_while(|b, _| b.is_ascii_whitespace())?
+            .eof()
+        {
+            if items.len() == max {
+                return Err(macro_error(
+                    cursor.source_span(),
+                    "unexpected tokens after the macro expansion",
+                ));
+            }
+
+            items.push(parser.parse::<T>(&mut cursor)?);
+        }
+
+        Ok(items)
+    }
+}
+
+impl Spanned for Expansion {
+    fn span(&self) -> Span {
+        self.call_site.clone()
+    }
+}
@@ -65,6 +65,13 @@ pub struct MemberValue {
     pub expr: Option<Box<Expr>>,
 }
 
+impl MemberValue {
+    /// `Struct { a }`, the value is the variable `a`
+    pub fn is_shorthand(&self) -> bool {
+        self.expr.is_none()
+    }
+}
+
 impl std::fmt::Display for MemberValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.span())
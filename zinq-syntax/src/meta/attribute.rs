@@ -1,12 +1,49 @@
-use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Enclosed, LParen, RParen, Token, TokenStream};
+use zinq_parse::{Diagnostic, Parse, Peek, Span, Spanned};
+use zinq_token::{Group, LParen, Token, TokenStream, TokenTree};
 
-use crate::Path;
+use crate::{Path, Syntax, expr::Expr, meta::MetaItem};
 
+///
+/// ## Attribute
+/// `Print(pretty)`, the raw `args` are kept for
+/// attributes that take expressions, `items` are
+/// the args that parse as a `MetaItem`, any arg
+/// that is neither a `MetaItem` nor an `Expr` is
+/// reported as a diagnostic
+///
+/// ```text
+/// If(a + 1 == b)   // args only
+/// If(test, => x)   // `test` item, `=> x` invalid
+/// ```
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Attribute {
     pub path: Path,
-    pub args: Option<Enclosed<LParen, TokenStream, RParen>>,
+    pub args: Option<Group>,
+    pub items: Vec<MetaItem>,
+}
+
+impl Syntax for Attribute {
+    fn name(&self) -> &str {
+        "Meta::Attribute"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_attribute(self)
+            .then(|| crate::meta::walk_attribute(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_attribute_mut(self)
+            .then(|| crate::meta::walk_attribute_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::meta::walk_attribute_fold(folder, self);
+        folder.fold_attribute(node)
+    }
 }
 
 impl std::fmt::Display for Attribute {
@@ -41,27 +78,50 @@ impl Parse for Attribute {
     ) -> zinq_error::Result<Self> {
         let path = parser.parse::<Path>(cursor)?;
 
-        if parser.peek::<LParen>(cursor).unwrap_or(false) {
-            let mut args = TokenStream::new();
-            let start = parser.parse::<LParen>(cursor)?;
-
-            while !parser.peek::<RParen>(cursor)? {
-                let token = parser.parse::<Token>(cursor)?;
-                args.push(&token)?;
-            }
-
-            let end = parser.parse::<RParen>(cursor)?;
-
+        if !parser.peek::<LParen>(cursor).unwrap_or(false) {
             return Ok(Self {
                 path,
-                args: Some(Enclosed {
-                    start,
-                    end,
-                    inner: args,
-                }),
+                args: None,
+                items: vec![],
             });
         }
 
-        Ok(Self { path, args: None })
+        let args = parser.parse::<Group>(cursor)?;
+        let mut items = vec![];
+
+        for arg in
+            args.split(|tree| matches!(tree, TokenTree::Leaf(Token::Punct(p)) if p.is_comma()))
+        {
+            // a trailing comma leaves an empty argument
+            let (Some(first), Some(last)) = (arg.first(), arg.last()) else {
+                continue;
+            };
+
+            let mut tokens = TokenStream::new();
+
+            for tree in arg {
+                tokens.push(tree)?;
+            }
+
+            match crate::parse_tokens::<MetaItem>(&tokens) {
+                Ok(item) => items.push(item),
+                Err(_) if crate::parse_tokens::<Expr>(&tokens).is_ok() => {}
+                Err(_) => {
+                    cursor.diagnose(
+                        Diagnostic::invalid_meta_error(Span::join(first.span(), last.span()))
+                            .message(
+                                "expected `path`, `path(...)`, `path = literal` or an expression",
+                            )
+                            .build(),
+                    );
+                }
+            };
+        }
+
+        Ok(Self {
+            path,
+            args: Some(args),
+            items,
+        })
     }
 }
@@ -0,0 +1,24 @@
+use crate::Syntax;
+
+use super::*;
+
+pub trait MetaFold {
+    fn fold_meta(&mut self, node: Meta) -> Meta {
+        node
+    }
+
+    fn fold_attribute(&mut self, node: Attribute) -> Attribute {
+        node
+    }
+}
+
+pub fn walk_meta_fold<F: crate::Fold>(folder: &mut F, node: Meta) -> Meta {
+    Meta {
+        attrs: node.attrs.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_attribute_fold<F: crate::Fold>(_folder: &mut F, node: Attribute) -> Attribute {
+    node
+}
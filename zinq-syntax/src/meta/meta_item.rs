@@ -0,0 +1,166 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Comma, Eq, LParen, Literal, Punctuated, RParen};
+
+use crate::Path;
+
+///
+/// ## Meta Item
+/// a structured attribute argument
+///
+/// - `test`
+/// - `all(test, debug)`
+/// - `feature = "x"`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MetaItem {
+    Path(Path),
+    List(MetaList),
+    NameValue(MetaNameValue),
+}
+
+impl MetaItem {
+    /// the path every form starts with
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Path(v) => v,
+            Self::List(v) => &v.path,
+            Self::NameValue(v) => &v.path,
+        }
+    }
+
+    pub fn is_path(&self) -> bool {
+        matches!(self, Self::Path(_))
+    }
+
+    pub fn is_list(&self) -> bool {
+        matches!(self, Self::List(_))
+    }
+
+    pub fn is_name_value(&self) -> bool {
+        matches!(self, Self::NameValue(_))
+    }
+
+    pub fn as_path(&self) -> &Path {
+        match self {
+            Self::Path(v) => v,
+            v => panic!("expected Path, received {}", v),
+        }
+    }
+
+    pub fn as_list(&self) -> &MetaList {
+        match self {
+            Self::List(v) => v,
+            v => panic!("expected MetaList, received {}", v),
+        }
+    }
+
+    pub fn as_name_value(&self) -> &MetaNameValue {
+        match self {
+            Self::NameValue(v) => v,
+            v => panic!("expected MetaNameValue, received {}", v),
+        }
+    }
+}
+
+impl std::fmt::Display for MetaItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for MetaItem {
+    fn span(&self) -> Span {
+        match self {
+            Self::Path(v) => v.span(),
+            Self::List(v) => v.span(),
+            Self::NameValue(v) => v.span(),
+        }
+    }
+}
+
+impl Peek for MetaItem {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Path>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for MetaItem {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let path = parser.parse::<Path>(cursor)?;
+
+        if parser.peek::<LParen>(cursor).unwrap_or(false) {
+            let left_paren = parser.parse::<LParen>(cursor)?;
+            let items = parser.parse::<Punctuated<MetaItem, Comma>>(cursor)?;
+            let right_paren = parser.parse::<RParen>(cursor)?;
+
+            return Ok(Self::List(MetaList {
+                path,
+                left_paren,
+                items,
+                right_paren,
+            }));
+        }
+
+        if parser.peek::<Eq>(cursor).unwrap_or(false) {
+            let eq = parser.parse::<Eq>(cursor)?;
+            let value = parser.parse::<Literal>(cursor)?;
+
+            return Ok(Self::NameValue(MetaNameValue { path, eq, value }));
+        }
+
+        Ok(Self::Path(path))
+    }
+}
+
+///
+/// ## Meta List
+/// `all(test, debug)`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetaList {
+    pub path: Path,
+    pub left_paren: LParen,
+    pub items: Punctuated<MetaItem, Comma>,
+    pub right_paren: RParen,
+}
+
+impl std::fmt::Display for MetaList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for MetaList {
+    fn span(&self) -> Span {
+        Span::join(self.path.span(), self.right_paren.span())
+    }
+}
+
+///
+/// ## Meta Name Value
+/// `feature = "x"`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetaNameValue {
+    pub path: Path,
+    pub eq: Eq,
+    pub value: Literal,
+}
+
+impl std::fmt::Display for MetaNameValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for MetaNameValue {
+    fn span(&self) -> Span {
+        Span::join(self.path.span(), self.value.span())
+    }
+}
@@ -1,8 +1,18 @@
 mod attribute;
+mod fold;
+mod meta_item;
+mod visitor;
+mod visitor_mut;
 
 pub use attribute::*;
+pub use fold::*;
+pub use meta_item::*;
+pub use visitor::*;
+pub use visitor_mut::*;
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Comma, LBracket, Pound, Punctuated, RBracket, Suffixed};
+use zinq_token::{Comma, LBracket, Not, Pound, Punctuated, RBracket, Suffixed};
+
+use crate::Syntax;
 
 ///
 /// ## Meta
@@ -18,14 +28,50 @@ use zinq_token::{Comma, LBracket, Pound, Punctuated, RBracket, Suffixed};
 /// }
 /// ```
 ///
+/// inner attributes (`#![...]`) apply to the
+/// enclosing file instead of the next item
+///
+/// outer attributes are acted on by the
+/// handlers of an `AttrRegistry`
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Meta {
     pub pound: Pound,
+    pub bang: Option<Not>,
     pub left_bracket: LBracket,
     pub attrs: Punctuated<Attribute, Comma>,
     pub right_bracket: RBracket,
 }
 
+impl Meta {
+    pub fn is_inner(&self) -> bool {
+        self.bang.is_some()
+    }
+}
+
+impl Syntax for Meta {
+    fn name(&self) -> &str {
+        "Meta"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_meta(self)
+            .then(|| crate::meta::walk_meta(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_meta_mut(self)
+            .then(|| crate::meta::walk_meta_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::meta::walk_meta_fold(folder, self);
+        folder.fold_meta(node)
+    }
+}
+
 impl std::fmt::Display for Meta {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.span())
@@ -45,7 +91,8 @@ impl Peek for Meta {
     ) -> zinq_error::Result<bool> {
         Ok(parser
             .peek::<Suffixed<Pound, LBracket>>(cursor)
-            .unwrap_or(false))
+            .unwrap_or(false)
+            || parser.peek::<Suffixed<Pound, Not>>(cursor).unwrap_or(false))
     }
 }
 
@@ -55,12 +102,14 @@ impl Parse for Meta {
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
         let pound = parser.parse::<Pound>(cursor)?;
+        let bang = parser.parse::<Option<Not>>(cursor)?;
         let left_bracket = parser.parse::<LBracket>(cursor)?;
         let attrs = parser.parse::<Punctuated<Attribute, Comma>>(cursor)?;
         let right_bracket = parser.parse::<RBracket>(cursor)?;
 
         Ok(Self {
             pound,
+            bang,
             left_bracket,
             attrs,
             right_bracket,
@@ -73,9 +122,17 @@ mod tests {
     use std::ops::Index;
 
     use zinq_error::Result;
-    use zinq_parse::Span;
+    use zinq_parse::{Span, diagnostic::INVALID_META_ERROR};
+
+    use zinq_parse::Spanned;
 
-    use crate::meta::Meta;
+    use crate::{
+        Path,
+        expr::Expr,
+        fields::Fields,
+        meta::Meta,
+        stmt::{BlockStmt, EnumStmt, FnStmt, Stmt, StructStmt},
+    };
 
     #[test]
     fn should_parse_one() -> Result<()> {
@@ -111,4 +168,197 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_nested_arguments() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"#[If(all(test, debug), [1, 2])]").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let meta = parser.parse::<Meta>(&mut cursor)?;
+        let args = meta.attrs.index(0).value().args.as_ref().unwrap();
+
+        debug_assert_eq!(args.len(), 4);
+        debug_assert!(args[1].is_group());
+        debug_assert_eq!(args[1].to_string(), "(test, debug)");
+        debug_assert_eq!(args[3].to_string(), "[1, 2]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_arguments_from_tokens() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"#[If(\n    a + 1 == b\n), Print(std::fmt)]").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let meta = parser.parse::<Meta>(&mut cursor)?;
+        let cond = meta.attrs.index(0).value().args.as_ref().unwrap();
+        let path = meta.attrs.index(1).value().args.as_ref().unwrap();
+        let expr = crate::parse_tokens::<Expr>(&cond.inner_tokens()?)?;
+
+        debug_assert!(expr.is_cmp());
+        debug_assert_eq!(expr.to_string(), "a + 1 == b");
+        debug_assert_eq!(expr.span().start().line(), 1);
+        debug_assert_eq!(expr.span().start().column(), 4);
+        debug_assert_eq!(
+            crate::parse_tokens::<Path>(&path.inner_tokens()?)?.to_string(),
+            "std::fmt"
+        );
+        debug_assert!(crate::parse_tokens::<Path>(&cond.inner_tokens()?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_error_on_unbalanced_arguments() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"#[Print(pretty])]").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+
+        debug_assert!(parser.parse::<Meta>(&mut cursor).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_meta_items() -> Result<()> {
+        let mut cursor =
+            Span::from_bytes(b"#[If(feature = \"x\", all(test, debug)), Clone]").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let meta = parser.parse::<Meta>(&mut cursor)?;
+        let items = &meta.attrs.index(0).value().items;
+
+        debug_assert!(cursor.build(()).diagnostic.is_none());
+        debug_assert_eq!(items.len(), 2);
+        debug_assert!(items[0].is_name_value());
+        debug_assert_eq!(items[0].path().to_string(), "feature");
+        debug_assert_eq!(items[0].as_name_value().value.to_string(), "\"x\"");
+        debug_assert!(items[1].is_list());
+        debug_assert_eq!(items[1].as_list().items.len(), 2);
+        debug_assert!(meta.attrs.index(1).value().items.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_keep_expression_arguments() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"#[Assert(a + 1 == b, [1, 2], test)]").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let meta = parser.parse::<Meta>(&mut cursor)?;
+        let result = cursor.build(meta);
+        let attr = result.value.attrs.index(0).value();
+        let args = attr.args.as_ref().unwrap().inner_tokens()?;
+        let cond = args.split(|tree| tree.to_string() == ",").next().unwrap();
+
+        debug_assert!(result.diagnostic.is_none());
+        debug_assert_eq!(attr.items.len(), 1);
+        debug_assert_eq!(attr.items[0].to_string(), "test");
+        debug_assert!(crate::parse_tokens::<Expr>(cond)?.is_cmp());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_report_invalid_meta_items() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"#[If(test, => x, a + b, fn)]").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let meta = parser.parse::<Meta>(&mut cursor)?;
+        let result = cursor.build(meta);
+        let mut errors = vec![];
+        let mut stack = vec![result.diagnostic.expect("expected diagnostic")];
+
+        // merged forks nest their diagnostics
+        while let Some(diagnostic) = stack.pop() {
+            if diagnostic.code == INVALID_META_ERROR {
+                errors.push(diagnostic.span.to_string());
+            }
+
+            stack.extend(diagnostic.children);
+        }
+
+        errors.sort();
+        debug_assert_eq!(result.value.attrs.index(0).value().items.len(), 1);
+        debug_assert_eq!(errors, vec!["=> x", "fn"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_inner_attributes_on_block() -> Result<()> {
+        let mut cursor =
+            Span::from_bytes(b"{ #![Inline] #![Trace(level = 2)] let a = 1; a }").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let block = parser.parse::<BlockStmt>(&mut cursor)?;
+
+        debug_assert_eq!(block.attrs.len(), 2);
+        debug_assert!(block.attrs.iter().all(|meta| meta.is_inner()));
+        debug_assert_eq!(block.stmts.len(), 1);
+        debug_assert!(block.tail.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_meta_on_fields_variants_and_params() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"struct A { #[Skip] a: u8, b: u8 }").cursor();
+        let value = parser.parse::<StructStmt>(&mut cursor)?;
+        let Fields::Named(fields) = &value.fields else {
+            panic!("expected named fields");
+        };
+
+        debug_assert!(fields.index(0).value().meta.is_some());
+        debug_assert_eq!(fields.index(0).value().to_string(), "#[Skip] a: u8");
+        debug_assert!(fields.index(1).value().meta.is_none());
+
+        let mut cursor = Span::from_bytes(b"enum B { #[Default] A, B(#[Skip] u8) }").cursor();
+        let value = parser.parse::<EnumStmt>(&mut cursor)?;
+
+        let Fields::Indexed(fields) = &value.variants.index(1).value().fields else {
+            panic!("expected indexed fields");
+        };
+
+        debug_assert!(value.variants.index(0).value().meta.is_some());
+        debug_assert!(fields.index(0).value().meta.is_some());
+
+        let mut cursor =
+            Span::from_bytes(b"fn c<#[Doc] T, #[Doc] const N: u8>(#[Unused] a: T) {}").cursor();
+        let value = parser.parse::<FnStmt>(&mut cursor)?;
+        let generics = value.generics.as_ref().unwrap();
+
+        debug_assert!(
+            generics.params.iter().all(|param| param
+                .value()
+                .span()
+                .to_string()
+                .starts_with("#[Doc]"))
+        );
+        debug_assert!(value.params.index(0).value().meta.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_meta_on_statements() -> Result<()> {
+        let mut cursor = Span::from_bytes(
+            b"{ #[Allow(unused)] let a = 1; #[Trace] loop { #[Cold] break; } #[Inline] a }",
+        )
+        .cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let block = parser.parse::<BlockStmt>(&mut cursor)?;
+
+        debug_assert_eq!(block.stmts.len(), 3);
+        debug_assert!(block.tail.is_none());
+
+        let Stmt::Let(let_stmt) = &block.stmts[0] else {
+            panic!("expected let statement");
+        };
+
+        debug_assert_eq!(let_stmt.to_string(), "#[Allow(unused)] let a = 1;");
+
+        let Stmt::Loop(loop_stmt) = &block.stmts[1] else {
+            panic!("expected loop statement");
+        };
+
+        debug_assert!(loop_stmt.meta.is_some());
+        debug_assert!(loop_stmt.body.stmts[0].as_break().meta.is_some());
+        debug_assert!(block.stmts[2].as_expr().meta.is_some());
+
+        Ok(())
+    }
 }
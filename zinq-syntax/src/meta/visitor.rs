@@ -0,0 +1,27 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait MetaVisitor {
+    fn visit_meta(&mut self, node: &Meta) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_attribute(&mut self, node: &Attribute) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_meta<V: crate::Visitor>(visitor: &mut V, node: &Meta) -> Visit {
+    for item in node.attrs.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_attribute<V: crate::Visitor>(_visitor: &mut V, _node: &Attribute) -> Visit {
+    Visit::Continue
+}
@@ -0,0 +1,27 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait MetaVisitorMut {
+    fn visit_meta_mut(&mut self, node: &mut Meta) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_attribute_mut(&mut self, node: &mut Attribute) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_meta_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Meta) -> Visit {
+    for item in node.attrs.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_attribute_mut<V: crate::VisitorMut>(_visitor: &mut V, _node: &mut Attribute) -> Visit {
+    Visit::Continue
+}
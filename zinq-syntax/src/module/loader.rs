@@ -0,0 +1,408 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use zinq_error::Result;
+use zinq_parse::{Diagnostic, ParseResult, Spanned};
+
+use crate::{Module, parse_file, stmt::Stmt};
+
+///
+/// ## ModuleLoader
+/// loads a module tree from a root file, resolving
+/// `mod name;` to `name.zq` or `name/mod.zq` in the
+/// directory of the declaring module, which is the
+/// file's own directory for the root and `mod.zq`,
+/// and `stem/` for any other file or inline module
+///
+/// ```text
+/// main.zq    mod a;
+/// a.zq       mod b;  // a/b.zq or a/b/mod.zq
+/// ```
+///
+/// missing files, cycles and duplicate modules are
+/// reported in the `ParseResult` diagnostic
+///
+/// ```
+/// let result = ModuleLoader::new().load("./src/main.zq")?;
+/// ```
+///
+#[derive(Debug, Default, Clone)]
+pub struct ModuleLoader {
+    stack: Vec<PathBuf>,
+    loaded: HashMap<PathBuf, Vec<String>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl ModuleLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// ## load
+    /// parse the root file and every module it declares
+    ///
+    pub fn load(mut self, root: &str) -> Result<ParseResult<Module>> {
+        let path = PathBuf::from(root);
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let (module, span) = self.load_file(&path, name, vec![], None)?;
+
+        if self.diagnostics.is_empty() {
+            return Ok(ParseResult::from_value(module));
+        }
+
+        Ok(ParseResult {
+            value: module,
+            diagnostic: Some(Diagnostic::noop(span).children(&self.diagnostics).build()),
+        })
+    }
+
+    fn load_file(
+        &mut self,
+        path: &Path,
+        name: String,
+        module_path: Vec<String>,
+        vis: Option<crate::Visibility>,
+    ) -> Result<(Module, zinq_parse::Span)> {
+        let key = key(path);
+        let file = path.to_string_lossy().to_string();
+        let result = parse_file(&file)?;
+
+        if let Some(diagnostic) = result.diagnostic {
+            self.diagnostics.push(diagnostic);
+        }
+
+        self.stack.push(key.clone());
+        self.loaded.insert(key, module_path.clone());
+
+        let mut dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+
+        if !module_path.is_empty()
+            && path.file_name().is_some_and(|name| name != "mod.zq")
+            && let Some(stem) = path.file_stem()
+        {
+            dir.push(stem);
+        }
+
+        let children = self.load_items(&result.value.items, &dir, &module_path)?;
+
+        self.stack.pop();
+
+        let module = Module {
+            name,
+            path: module_path,
+            vis,
+            file: Some(file),
+            items: result.value.items.clone(),
+            children,
+        };
+
+        Ok((module, result.value.span()))
+    }
+
+    fn load_items(
+        &mut self,
+        items: &[Stmt],
+        dir: &Path,
+        module_path: &[String],
+    ) -> Result<Vec<Module>> {
+        let mut children: Vec<Module> = vec![];
+
+        for item in items.iter().filter(|item| item.is_mod()) {
+            let stmt = item.as_mod();
+            let name = stmt.name.to_string();
+            let mut child_path = module_path.to_vec();
+            child_path.push(name.clone());
+
+            if children.iter().any(|child| child.name == name) {
+                self.diagnostics.push(
+                    Diagnostic::duplicate_module_error(stmt.span())
+                        .message(&format!("module '{}' is already declared", name))
+                        .build(),
+                );
+
+                continue;
+            }
+
+            if let Some(body) = &stmt.body {
+                children.push(Module {
+                    name: name.clone(),
+                    vis: Some(stmt.vis.clone()),
+                    file: None,
+                    items: body.items.clone(),
+                    children: self.load_items(&body.items, &dir.join(&name), &child_path)?,
+                    path: child_path,
+                });
+
+                continue;
+            }
+
+            let candidates = [
+                dir.join(format!("{}.zq", name)),
+                dir.join(&name).join("mod.zq"),
+            ];
+
+            let found = candidates
+                .iter()
+                .filter(|candidate| candidate.is_file())
+                .collect::<Vec<_>>();
+
+            let path = match found.as_slice() {
+                [path] => *path,
+                [] => {
+                    self.diagnostics.push(
+                        Diagnostic::module_not_found_error(stmt.span())
+                            .message(&format!(
+                                "module '{}' not found, expected '{}' or '{}'",
+                                name,
+                                candidates[0].display(),
+                                candidates[1].display()
+                            ))
+                            .build(),
+                    );
+
+                    continue;
+                }
+                _ => {
+                    self.diagnostics.push(
+                        Diagnostic::duplicate_module_error(stmt.span())
+                            .message(&format!(
+                                "module '{}' found at both '{}' and '{}'",
+                                name,
+                                candidates[0].display(),
+                                candidates[1].display()
+                            ))
+                            .build(),
+                    );
+
+                    continue;
+                }
+            };
+
+            let key = key(path);
+
+            if self.stack.contains(&key) {
+                self.diagnostics.push(
+                    Diagnostic::cyclic_module_error(stmt.span())
+                        .message(&format!(
+                            "module '{}' includes '{}' which is already being loaded",
+                            name,
+                            path.display()
+                        ))
+                        .build(),
+                );
+
+                continue;
+            }
+
+            if let Some(existing) = self.loaded.get(&key) {
+                self.diagnostics.push(
+                    Diagnostic::duplicate_module_error(stmt.span())
+                        .message(&format!(
+                            "'{}' is already loaded as module '{}'",
+                            path.display(),
+                            existing.join("::")
+                        ))
+                        .build(),
+                );
+
+                continue;
+            }
+
+            let (child, _) = self.load_file(path, name, child_path, Some(stmt.vis.clone()))?;
+            children.push(child);
+        }
+
+        Ok(children)
+    }
+}
+
+// the same file can be reached through different
+// relative paths, so compare canonical ones
+fn key(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use zinq_error::Result;
+    use zinq_parse::diagnostic::{
+        CYCLIC_MODULE_ERROR, DUPLICATE_MODULE_ERROR, MODULE_NOT_FOUND_ERROR,
+    };
+
+    use crate::ModuleLoader;
+
+    fn setup(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("zinq-loader-{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        for (path, src) in files {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, src).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn should_load_tree() -> Result<()> {
+        let dir = setup(
+            "tree",
+            &[
+                (
+                    "main.zq",
+                    "mod a;\npub mod b;\nmod c {\n    pub mod d;\n}\n",
+                ),
+                ("a.zq", "fn a() {}\n"),
+                ("b/mod.zq", "pub(mod) mod e;\n"),
+                ("b/e.zq", "struct E {}\n"),
+                ("c/d.zq", "fn d() {}\n"),
+            ],
+        );
+
+        let result = ModuleLoader::new().load(&dir.join("main.zq").to_string_lossy())?;
+        let root = result.value;
+
+        debug_assert!(result.diagnostic.is_none());
+        debug_assert!(root.is_root());
+        debug_assert_eq!(root.name, "main");
+        debug_assert_eq!(root.children.len(), 3);
+        debug_assert!(!root.child("a").unwrap().is_pub());
+        debug_assert!(root.child("b").unwrap().is_pub());
+        debug_assert!(root.child("c").unwrap().is_inline());
+        debug_assert!(root.find(&["c", "d"]).unwrap().items[0].is_fn());
+        debug_assert!(root.find(&["c", "d"]).unwrap().is_pub());
+        debug_assert_eq!(root.find(&["c", "d"]).unwrap().to_string(), "c::d");
+        debug_assert!(
+            root.find(&["b", "e"])
+                .unwrap()
+                .vis
+                .as_ref()
+                .unwrap()
+                .is_mod()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_report_missing_and_duplicate() -> Result<()> {
+        let dir = setup(
+            "missing",
+            &[
+                ("main.zq", "mod a;\nmod a;\nmod b;\nmod c;\n"),
+                ("a.zq", ""),
+                ("c.zq", ""),
+                ("c/mod.zq", ""),
+            ],
+        );
+
+        let result = ModuleLoader::new().load(&dir.join("main.zq").to_string_lossy())?;
+        let diagnostic = result.diagnostic.expect("expected diagnostic");
+        let codes = diagnostic
+            .children
+            .iter()
+            .map(|child| child.code)
+            .collect::<Vec<_>>();
+
+        debug_assert_eq!(result.value.children.len(), 1);
+        debug_assert_eq!(
+            codes,
+            vec![
+                DUPLICATE_MODULE_ERROR,
+                MODULE_NOT_FOUND_ERROR,
+                DUPLICATE_MODULE_ERROR
+            ]
+        );
+        debug_assert_eq!(diagnostic.children[1].span.to_string(), "mod b;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_resolve_children_under_the_file_stem() -> Result<()> {
+        let dir = setup(
+            "stem",
+            &[
+                (
+                    "main.zq",
+                    "mod a;
+mod b;
+",
+                ),
+                (
+                    "a.zq", "mod b;
+",
+                ),
+                (
+                    "a/b.zq",
+                    "fn inner() {}
+",
+                ),
+                (
+                    "b.zq",
+                    "fn outer() {}
+",
+                ),
+            ],
+        );
+
+        let result = ModuleLoader::new().load(&dir.join("main.zq").to_string_lossy())?;
+        let root = result.value;
+        let inner = root.find(&["a", "b"]).unwrap();
+        let outer = root.find(&["b"]).unwrap();
+
+        debug_assert!(result.diagnostic.is_none());
+        debug_assert!(inner.file.as_ref().unwrap().ends_with("a/b.zq"));
+        debug_assert_eq!(inner.items[0].as_fn().name.to_string(), "inner");
+        debug_assert_eq!(outer.items[0].as_fn().name.to_string(), "outer");
+
+        Ok(())
+    }
+
+    // a cycle needs a file reachable from its own
+    // module directory, which only a link can do
+    #[cfg(unix)]
+    #[test]
+    fn should_report_cycles() -> Result<()> {
+        let dir = setup(
+            "cycle",
+            &[
+                (
+                    "main.zq", "mod a;
+",
+                ),
+                (
+                    "a.zq", "mod b;
+",
+                ),
+                (
+                    "a/b.zq", "mod c;
+",
+                ),
+            ],
+        );
+
+        std::fs::create_dir_all(dir.join("a/b")).unwrap();
+        std::os::unix::fs::symlink(dir.join("a.zq"), dir.join("a/b/c.zq")).unwrap();
+
+        let result = ModuleLoader::new().load(&dir.join("main.zq").to_string_lossy())?;
+        let diagnostic = result.diagnostic.expect("expected diagnostic");
+
+        debug_assert_eq!(diagnostic.children.len(), 1);
+        debug_assert_eq!(diagnostic.children[0].code, CYCLIC_MODULE_ERROR);
+        debug_assert!(result.value.find(&["a", "b"]).is_some());
+        debug_assert!(result.value.find(&["a", "b", "c"]).is_none());
+
+        Ok(())
+    }
+}
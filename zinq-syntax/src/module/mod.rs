@@ -0,0 +1,71 @@
+mod loader;
+
+pub use loader::*;
+
+use crate::{Visibility, stmt::Stmt};
+
+///
+/// ## Module
+/// a node of the module tree, either a
+/// file or an inline `mod name { ... }`
+///
+/// ```
+/// let result = ModuleLoader::new().load("./src/main.zq")?;
+/// let utils = result.value.find(&["utils"]);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Module {
+    pub name: String,
+    pub path: Vec<String>,
+    pub vis: Option<Visibility>,
+    pub file: Option<String>,
+    pub items: Vec<Stmt>,
+    pub children: Vec<Module>,
+}
+
+impl Module {
+    pub fn is_root(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.file.is_none()
+    }
+
+    ///
+    /// ## is_pub
+    /// the root module is always public, every
+    /// other module follows its declaration
+    ///
+    pub fn is_pub(&self) -> bool {
+        match &self.vis {
+            None => true,
+            Some(vis) => vis.is_pub(),
+        }
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    ///
+    /// ## find
+    /// a descendant by its path relative to this module
+    ///
+    pub fn find(&self, path: &[&str]) -> Option<&Self> {
+        match path.split_first() {
+            None => Some(self),
+            Some((name, rest)) => self.child(name)?.find(rest),
+        }
+    }
+}
+
+impl std::fmt::Display for Module {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_root() {
+            true => write!(f, "{}", &self.name),
+            false => write!(f, "{}", self.path.join("::")),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Package, SemiColon};
+
+use crate::Path;
+
+///
+/// ## PackageDecl
+/// the package a file belongs to
+///
+/// ```
+/// package std::collections;
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackageDecl {
+    pub keyword: Package,
+    pub path: Path,
+    pub semi: SemiColon,
+}
+
+impl std::fmt::Display for PackageDecl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for PackageDecl {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        // `package` is contextual, so it must be followed by a path
+        if fork_parser.parse::<Package>(&mut fork).is_err() {
+            return Ok(false);
+        }
+
+        Ok(fork_parser.peek::<Path>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for PackageDecl {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let keyword = parser.parse::<Package>(cursor)?;
+        let path = parser.parse::<Path>(cursor)?;
+        let semi = parser.parse::<SemiColon>(cursor)?;
+
+        Ok(Self {
+            keyword,
+            path,
+            semi,
+        })
+    }
+}
+
+impl Spanned for PackageDecl {
+    fn span(&self) -> Span {
+        Span::join(self.keyword.span(), self.semi.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::PackageDecl;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"package std::collections;").cursor();
+        let package = parser.parse::<PackageDecl>(&mut cursor)?;
+
+        debug_assert_eq!(package.path.len(), 2);
+        debug_assert_eq!(package.to_string(), "package std::collections;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_peek_ident() -> Result<()> {
+        let parser = zinq_parse::ZinqParser;
+        let cursor = Span::from_bytes(b"package = 1;").cursor();
+
+        debug_assert!(!parser.peek::<PackageDecl>(&cursor)?);
+
+        Ok(())
+    }
+}
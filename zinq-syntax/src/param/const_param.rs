@@ -0,0 +1,131 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Colon, Const, Eq, Ident, Suffixed};
+
+use crate::{
+    Syntax,
+    expr::{Expr, ExprParser},
+    meta::Meta,
+    ty::Type,
+};
+
+///
+/// ## Const Param
+/// `const <name>: <type>` or `const <name>: <type> = <default>`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstParam {
+    pub meta: Option<Meta>,
+    pub keyword: Const,
+    pub ident: Ident,
+    pub ty: Suffixed<Colon, Type>,
+    pub default: Option<Suffixed<Eq, Box<Expr>>>,
+}
+
+impl std::fmt::Display for ConstParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ConstParam {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Const>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for ConstParam {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let keyword = parser.parse::<Const>(cursor)?;
+        let ident = parser.parse::<Ident>(cursor)?;
+        let ty = parser.parse::<Suffixed<Colon, Type>>(cursor)?;
+        let mut default = None;
+
+        // a full expression would read the closing `>` as a comparison
+        if parser.peek::<Eq>(cursor).unwrap_or(false) {
+            let eq = parser.parse::<Eq>(cursor)?;
+            let value = parser.parse_unary_expr(cursor)?;
+
+            default = Some(Suffixed {
+                inner: eq,
+                suffix: Box::new(value),
+            });
+        }
+
+        Ok(Self {
+            meta,
+            keyword,
+            ident,
+            ty,
+            default,
+        })
+    }
+}
+
+impl Spanned for ConstParam {
+    fn span(&self) -> Span {
+        let start = match &self.meta {
+            None => self.keyword.span(),
+            Some(meta) => meta.span(),
+        };
+
+        match &self.default {
+            None => Span::join(start, self.ty.span()),
+            Some(default) => Span::join(start, default.span()),
+        }
+    }
+}
+
+impl Syntax for ConstParam {
+    fn name(&self) -> &str {
+        "Param::Const"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_const_param(self)
+            .then(|| crate::param::walk_const_param(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_const_param_mut(self)
+            .then(|| crate::param::walk_const_param_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::param::walk_const_param_fold(folder, self);
+        folder.fold_const_param(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::param::ConstParam;
+
+    #[test]
+    fn should_parse_with_default() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"const N: usize = 4>").cursor();
+        let param = parser.parse::<ConstParam>(&mut cursor)?;
+
+        debug_assert_eq!(param.ident.to_string(), "N");
+        debug_assert_eq!(param.ty.suffix.to_string(), "usize");
+        debug_assert_eq!(param.to_string(), "const N: usize = 4");
+
+        Ok(())
+    }
+}
@@ -1,13 +1,45 @@
-use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Colon, Ident};
+use zinq_error::{Error, NOT_FOUND};
+use zinq_parse::{Parse, ParseError, Peek, Span, Spanned};
+use zinq_token::{Colon, Comma, Ident, Punctuated, Suffixed};
 
-use crate::{Syntax, ty::Type};
+use crate::{Syntax, meta::Meta, ty::Type};
 
+///
+/// ## Fn Param
+/// `<name>: <type>`, the type can only
+/// be left out by closure parameters
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FnParam {
+    pub meta: Option<Meta>,
     pub name: Ident,
-    pub colon: Colon,
-    pub ty: Type,
+    pub ty: Option<Suffixed<Colon, Type>>,
+}
+
+impl FnParam {
+    pub fn is_typed(&self) -> bool {
+        self.ty.is_some()
+    }
+
+    ///
+    /// ## expect_typed
+    /// error at the first parameter without
+    /// a type, used by function declarations
+    ///
+    pub(crate) fn expect_typed(params: &Punctuated<Self, Comma>) -> zinq_error::Result<()> {
+        match params.iter().find(|param| !param.value().is_typed()) {
+            None => Ok(()),
+            Some(param) => Err(ParseError::from_error(
+                param.value().span(),
+                Error::new()
+                    .code(NOT_FOUND)
+                    .message("expected parameter type")
+                    .build()
+                    .into(),
+            )
+            .into()),
+        }
+    }
 }
 
 impl std::fmt::Display for FnParam {
@@ -36,17 +68,25 @@ impl Parse for FnParam {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let name = parser.parse::<Ident>(cursor)?;
-        let colon = parser.parse::<Colon>(cursor)?;
-        let ty = parser.parse::<Type>(cursor)?;
+        let ty = parser.parse::<Option<Suffixed<Colon, Type>>>(cursor)?;
 
-        Ok(Self { name, colon, ty })
+        Ok(Self { meta, name, ty })
     }
 }
 
 impl Spanned for FnParam {
     fn span(&self) -> Span {
-        Span::join(self.name.span(), self.ty.span())
+        let start = match &self.meta {
+            None => self.name.span(),
+            Some(meta) => meta.span(),
+        };
+
+        match &self.ty {
+            None => Span::join(start, self.name.span()),
+            Some(ty) => Span::join(start, ty.span()),
+        }
     }
 }
 
@@ -55,8 +95,20 @@ impl Syntax for FnParam {
         "Param::Fn"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_fn_param(self);
-        self.ty.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_fn_param(self)
+            .then(|| crate::param::walk_fn_param(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_fn_param_mut(self)
+            .then(|| crate::param::walk_fn_param_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::param::walk_fn_param_fold(folder, self);
+        folder.fold_fn_param(node)
     }
 }
@@ -0,0 +1,75 @@
+use zinq_token::Suffixed;
+
+use crate::Syntax;
+
+use super::*;
+
+pub trait ParamFold {
+    fn fold_const_param(&mut self, node: ConstParam) -> ConstParam {
+        node
+    }
+
+    fn fold_fn_param(&mut self, node: FnParam) -> FnParam {
+        node
+    }
+
+    fn fold_self_param(&mut self, node: SelfParam) -> SelfParam {
+        node
+    }
+
+    fn fold_type_param(&mut self, node: TypeParam) -> TypeParam {
+        node
+    }
+}
+
+pub fn walk_const_param_fold<F: crate::Fold>(folder: &mut F, node: ConstParam) -> ConstParam {
+    ConstParam {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ty: Suffixed {
+            suffix: node.ty.suffix.fold(folder),
+            ..node.ty
+        },
+        default: node.default.map(|v| Suffixed {
+            suffix: Box::new((*v.suffix).fold(folder)),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_fn_param_fold<F: crate::Fold>(folder: &mut F, node: FnParam) -> FnParam {
+    FnParam {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ty: node.ty.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_self_param_fold<F: crate::Fold>(folder: &mut F, node: SelfParam) -> SelfParam {
+    SelfParam {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_type_param_fold<F: crate::Fold>(folder: &mut F, node: TypeParam) -> TypeParam {
+    TypeParam {
+        meta: node.meta.map(|v| v.fold(folder)),
+        bounds: node.bounds.map(|v| crate::walk_bounds_fold(folder, v)),
+        default: node.default.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_generic_param_fold<F: crate::Fold>(folder: &mut F, node: GenericParam) -> GenericParam {
+    match node {
+        GenericParam::Type(v) => GenericParam::Type(v.fold(folder)),
+        GenericParam::Const(v) => GenericParam::Const(v.fold(folder)),
+    }
+}
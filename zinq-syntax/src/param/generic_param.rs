@@ -0,0 +1,115 @@
+use zinq_parse::{Parse, Peek, Spanned};
+
+use crate::{
+    Syntax,
+    param::{ConstParam, TypeParam},
+};
+
+///
+/// ## Generic Param
+/// a declared type parameter `T: Bound = Default`
+/// or value parameter `const N: usize`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GenericParam {
+    Type(TypeParam),
+    Const(ConstParam),
+}
+
+impl GenericParam {
+    pub fn is_type(&self) -> bool {
+        matches!(self, Self::Type(_))
+    }
+
+    pub fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+
+    pub fn as_type(&self) -> &TypeParam {
+        match self {
+            Self::Type(v) => v,
+            v => panic!("expected TypeParam, received {}", v.name()),
+        }
+    }
+
+    pub fn as_const(&self) -> &ConstParam {
+        match self {
+            Self::Const(v) => v,
+            v => panic!("expected ConstParam, received {}", v.name()),
+        }
+    }
+}
+
+impl From<TypeParam> for GenericParam {
+    fn from(value: TypeParam) -> Self {
+        Self::Type(value)
+    }
+}
+
+impl From<ConstParam> for GenericParam {
+    fn from(value: ConstParam) -> Self {
+        Self::Const(value)
+    }
+}
+
+impl Syntax for GenericParam {
+    fn name(&self) -> &str {
+        match self {
+            Self::Type(v) => v.name(),
+            Self::Const(v) => v.name(),
+        }
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        crate::param::walk_generic_param(visitor, self)
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        crate::param::walk_generic_param_mut(visitor, self)
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        crate::param::walk_generic_param_fold(folder, self)
+    }
+}
+
+impl std::fmt::Display for GenericParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Type(v) => write!(f, "{}", v),
+            Self::Const(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Peek for GenericParam {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<ConstParam>(cursor).unwrap_or(false)
+            || parser.peek::<TypeParam>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for GenericParam {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        if parser.peek::<ConstParam>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<ConstParam>(cursor)?.into());
+        }
+
+        Ok(parser.parse::<TypeParam>(cursor)?.into())
+    }
+}
+
+impl Spanned for GenericParam {
+    fn span(&self) -> zinq_parse::Span {
+        match self {
+            Self::Type(v) => v.span(),
+            Self::Const(v) => v.span(),
+        }
+    }
+}
@@ -1,15 +1,17 @@
+mod const_param;
 mod fn_param;
+mod fold;
+mod generic_param;
 mod self_param;
 mod type_param;
+mod visitor;
+mod visitor_mut;
 
+pub use const_param::*;
 pub use fn_param::*;
+pub use fold::*;
+pub use generic_param::*;
 pub use self_param::*;
 pub use type_param::*;
-
-pub trait ParamVisitor {
-    #![allow(unused)]
-
-    fn visit_fn_param(&mut self, node: &FnParam) {}
-    fn visit_self_param(&mut self, node: &SelfParam) {}
-    fn visit_type_param(&mut self, node: &TypeParam) {}
-}
+pub use visitor::*;
+pub use visitor_mut::*;
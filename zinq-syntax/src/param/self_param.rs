@@ -1,10 +1,11 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{And, Mut, SelfValue};
 
-use crate::Syntax;
+use crate::{Syntax, meta::Meta};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SelfParam {
+    pub meta: Option<Meta>,
     pub and: Option<And>,
     pub mutable: Option<Mut>,
     pub keyword: SelfValue,
@@ -36,11 +37,13 @@ impl Parse for SelfParam {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let and = parser.parse::<Option<And>>(cursor)?;
         let mutable = parser.parse::<Option<Mut>>(cursor)?;
         let keyword = parser.parse::<SelfValue>(cursor)?;
 
         Ok(Self {
+            meta,
             and,
             mutable,
             keyword,
@@ -60,6 +63,10 @@ impl Spanned for SelfParam {
             first = v.span().clone();
         }
 
+        if let Some(v) = &self.meta {
+            first = v.span();
+        }
+
         Span::join(first, self.keyword.span())
     }
 }
@@ -69,7 +76,20 @@ impl Syntax for SelfParam {
         "Param::Self"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_self_param(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_self_param(self)
+            .then(|| crate::param::walk_self_param(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_self_param_mut(self)
+            .then(|| crate::param::walk_self_param_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::param::walk_self_param_fold(folder, self);
+        folder.fold_self_param(node)
     }
 }
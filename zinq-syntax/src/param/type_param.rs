@@ -1,12 +1,18 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::Ident;
+use zinq_token::{Eq, Ident, Suffixed};
 
-use crate::{Bounds, Syntax};
+use crate::{Bounds, Syntax, meta::Meta, ty::Type};
 
+///
+/// ## Type Param
+/// `T`, `T: <bounds>` or `T: <bounds> = <default>`
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeParam {
+    pub meta: Option<Meta>,
     pub ident: Ident,
     pub bounds: Option<Bounds>,
+    pub default: Option<Suffixed<Eq, Type>>,
 }
 
 impl std::fmt::Display for TypeParam {
@@ -20,7 +26,11 @@ impl Peek for TypeParam {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<Ident>(cursor).unwrap_or(false))
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Ident>(&fork).unwrap_or(false))
     }
 }
 
@@ -29,20 +39,36 @@ impl Parse for TypeParam {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let ident = parser.parse::<Ident>(cursor)?;
         let bounds = parser.parse::<Option<Bounds>>(cursor)?;
+        let default = parser.parse::<Option<Suffixed<Eq, Type>>>(cursor)?;
 
-        Ok(Self { ident, bounds })
+        Ok(Self {
+            meta,
+            ident,
+            bounds,
+            default,
+        })
     }
 }
 
 impl Spanned for TypeParam {
     fn span(&self) -> Span {
+        let start = match &self.meta {
+            None => self.ident.span(),
+            Some(meta) => meta.span(),
+        };
+
+        if let Some(default) = &self.default {
+            return Span::join(start, default.span());
+        }
+
         if let Some(bounds) = &self.bounds {
-            return Span::join(self.ident.span(), bounds.span());
+            return Span::join(start, bounds.span());
         }
 
-        self.ident.span()
+        Span::join(start, self.ident.span())
     }
 }
 
@@ -51,7 +77,20 @@ impl Syntax for TypeParam {
         "Param::Type"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_type_param(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_type_param(self)
+            .then(|| crate::param::walk_type_param(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_type_param_mut(self)
+            .then(|| crate::param::walk_type_param_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::param::walk_type_param_fold(folder, self);
+        folder.fold_type_param(node)
     }
 }
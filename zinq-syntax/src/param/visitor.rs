@@ -0,0 +1,80 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait ParamVisitor {
+    fn visit_const_param(&mut self, node: &ConstParam) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_fn_param(&mut self, node: &FnParam) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_self_param(&mut self, node: &SelfParam) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_type_param(&mut self, node: &TypeParam) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_const_param<V: crate::Visitor>(visitor: &mut V, node: &ConstParam) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.ty.suffix.accept(visitor));
+
+    if let Some(v) = &node.default {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_fn_param<V: crate::Visitor>(visitor: &mut V, node: &FnParam) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.ty {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_self_param<V: crate::Visitor>(visitor: &mut V, node: &SelfParam) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_type_param<V: crate::Visitor>(visitor: &mut V, node: &TypeParam) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.bounds {
+        try_visit!(crate::walk_bounds(visitor, v));
+    }
+
+    if let Some(v) = &node.default {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_generic_param<V: crate::Visitor>(visitor: &mut V, node: &GenericParam) -> Visit {
+    match node {
+        GenericParam::Type(v) => v.accept(visitor),
+        GenericParam::Const(v) => v.accept(visitor),
+    }
+}
@@ -0,0 +1,83 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait ParamVisitorMut {
+    fn visit_const_param_mut(&mut self, node: &mut ConstParam) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_fn_param_mut(&mut self, node: &mut FnParam) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_self_param_mut(&mut self, node: &mut SelfParam) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_type_param_mut(&mut self, node: &mut TypeParam) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_const_param_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ConstParam) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.ty.suffix.accept_mut(visitor));
+
+    if let Some(v) = &mut node.default {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_fn_param_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut FnParam) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.ty {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_self_param_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut SelfParam) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_type_param_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut TypeParam) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.bounds {
+        try_visit!(crate::walk_bounds_mut(visitor, v));
+    }
+
+    if let Some(v) = &mut node.default {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_generic_param_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut GenericParam,
+) -> Visit {
+    match node {
+        GenericParam::Type(v) => v.accept_mut(visitor),
+        GenericParam::Const(v) => v.accept_mut(visitor),
+    }
+}
@@ -0,0 +1,61 @@
+use zinq_parse::{Span, Spanned};
+use zinq_token::{At, Ident, Mut};
+
+use crate::{Syntax, pat::Pattern};
+
+///
+/// ## Bind Pattern
+/// - `n @ 1..=5`
+/// - `mut v @ Some(_)`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BindPattern {
+    pub mutable: Option<Mut>,
+    pub name: Ident,
+    pub at: At,
+    pub inner: Box<Pattern>,
+}
+
+impl From<BindPattern> for Pattern {
+    fn from(value: BindPattern) -> Self {
+        Self::Bind(Box::new(value))
+    }
+}
+
+impl std::fmt::Display for BindPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for BindPattern {
+    fn span(&self) -> zinq_parse::Span {
+        match &self.mutable {
+            None => Span::join(self.name.span(), self.inner.span()),
+            Some(mutable) => Span::join(mutable.span(), self.inner.span()),
+        }
+    }
+}
+
+impl Syntax for BindPattern {
+    fn name(&self) -> &str {
+        "Pattern::Bind"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_bind_pattern(self)
+            .then(|| crate::pat::walk_bind_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_bind_pattern_mut(self)
+            .then(|| crate::pat::walk_bind_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_bind_pattern_fold(folder, self);
+        folder.fold_bind_pattern(node)
+    }
+}
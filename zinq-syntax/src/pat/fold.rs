@@ -0,0 +1,188 @@
+use crate::Syntax;
+
+use super::*;
+
+pub trait PatternFold {
+    fn fold_pattern(&mut self, node: Pattern) -> Pattern {
+        node
+    }
+
+    fn fold_bind_pattern(&mut self, node: BindPattern) -> BindPattern {
+        node
+    }
+
+    fn fold_group_pattern(&mut self, node: GroupPattern) -> GroupPattern {
+        node
+    }
+
+    fn fold_ident_pattern(&mut self, node: IdentPattern) -> IdentPattern {
+        node
+    }
+
+    fn fold_literal_pattern(&mut self, node: LiteralPattern) -> LiteralPattern {
+        node
+    }
+
+    fn fold_macro_pattern(&mut self, node: MacroPattern) -> MacroPattern {
+        node
+    }
+
+    fn fold_or_pattern(&mut self, node: OrPattern) -> OrPattern {
+        node
+    }
+
+    fn fold_path_pattern(&mut self, node: PathPattern) -> PathPattern {
+        node
+    }
+
+    fn fold_range_pattern(&mut self, node: RangePattern) -> RangePattern {
+        node
+    }
+
+    fn fold_ref_pattern(&mut self, node: RefPattern) -> RefPattern {
+        node
+    }
+
+    fn fold_spread_pattern(&mut self, node: SpreadPattern) -> SpreadPattern {
+        node
+    }
+
+    fn fold_struct_pattern(&mut self, node: StructPattern) -> StructPattern {
+        node
+    }
+
+    fn fold_tuple_pattern(&mut self, node: TuplePattern) -> TuplePattern {
+        node
+    }
+
+    fn fold_tuple_struct_pattern(&mut self, node: TupleStructPattern) -> TupleStructPattern {
+        node
+    }
+
+    fn fold_wild_pattern(&mut self, node: WildPattern) -> WildPattern {
+        node
+    }
+}
+
+pub fn walk_pattern_fold<F: crate::Fold>(folder: &mut F, node: Pattern) -> Pattern {
+    match node {
+        Pattern::Wild(v) => Pattern::Wild(v.fold(folder)),
+        Pattern::Path(v) => Pattern::Path(v.fold(folder)),
+        Pattern::Ident(v) => Pattern::Ident(v.fold(folder)),
+        Pattern::Literal(v) => Pattern::Literal(v.fold(folder)),
+        Pattern::Range(v) => Pattern::Range(Box::new((*v).fold(folder))),
+        Pattern::Ref(v) => Pattern::Ref(v.fold(folder)),
+        Pattern::Spread(v) => Pattern::Spread(v.fold(folder)),
+        Pattern::Struct(v) => Pattern::Struct(v.fold(folder)),
+        Pattern::Tuple(v) => Pattern::Tuple(v.fold(folder)),
+        Pattern::TupleStruct(v) => Pattern::TupleStruct(v.fold(folder)),
+        Pattern::Group(v) => Pattern::Group(v.fold(folder)),
+        Pattern::Bind(v) => Pattern::Bind(Box::new((*v).fold(folder))),
+        Pattern::Or(v) => Pattern::Or(v.fold(folder)),
+        Pattern::Macro(v) => Pattern::Macro(Box::new((*v).fold(folder))),
+    }
+}
+
+pub fn walk_bind_pattern_fold<F: crate::Fold>(folder: &mut F, node: BindPattern) -> BindPattern {
+    BindPattern {
+        inner: Box::new((*node.inner).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_group_pattern_fold<F: crate::Fold>(folder: &mut F, node: GroupPattern) -> GroupPattern {
+    GroupPattern {
+        inner: Box::new((*node.inner).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_ident_pattern_fold<F: crate::Fold>(
+    _folder: &mut F,
+    node: IdentPattern,
+) -> IdentPattern {
+    node
+}
+
+pub fn walk_literal_pattern_fold<F: crate::Fold>(
+    _folder: &mut F,
+    node: LiteralPattern,
+) -> LiteralPattern {
+    node
+}
+
+pub fn walk_macro_pattern_fold<F: crate::Fold>(
+    _folder: &mut F,
+    node: MacroPattern,
+) -> MacroPattern {
+    node
+}
+
+pub fn walk_or_pattern_fold<F: crate::Fold>(folder: &mut F, node: OrPattern) -> OrPattern {
+    OrPattern {
+        left: Box::new((*node.left).fold(folder)),
+        right: Box::new((*node.right).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_path_pattern_fold<F: crate::Fold>(folder: &mut F, node: PathPattern) -> PathPattern {
+    PathPattern {
+        path: crate::walk_path_fold(folder, node.path),
+    }
+}
+
+pub fn walk_range_pattern_fold<F: crate::Fold>(folder: &mut F, node: RangePattern) -> RangePattern {
+    RangePattern {
+        start: node.start.map(|v| v.fold(folder)),
+        end: node.end.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_ref_pattern_fold<F: crate::Fold>(folder: &mut F, node: RefPattern) -> RefPattern {
+    RefPattern {
+        inner: Box::new((*node.inner).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_spread_pattern_fold<F: crate::Fold>(
+    _folder: &mut F,
+    node: SpreadPattern,
+) -> SpreadPattern {
+    node
+}
+
+pub fn walk_struct_pattern_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: StructPattern,
+) -> StructPattern {
+    StructPattern {
+        path: crate::walk_path_fold(folder, node.path),
+        fields: node.fields.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_tuple_pattern_fold<F: crate::Fold>(folder: &mut F, node: TuplePattern) -> TuplePattern {
+    TuplePattern {
+        items: node.items.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_tuple_struct_pattern_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: TupleStructPattern,
+) -> TupleStructPattern {
+    TupleStructPattern {
+        path: crate::walk_path_fold(folder, node.path),
+        items: node.items.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_wild_pattern_fold<F: crate::Fold>(_folder: &mut F, node: WildPattern) -> WildPattern {
+    node
+}
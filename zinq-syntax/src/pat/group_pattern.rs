@@ -63,8 +63,20 @@ impl Syntax for GroupPattern {
         "Pattern::Group"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_group_pattern(self);
-        self.inner.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_group_pattern(self)
+            .then(|| crate::pat::walk_group_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_group_pattern_mut(self)
+            .then(|| crate::pat::walk_group_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_group_pattern_fold(folder, self);
+        folder.fold_group_pattern(node)
     }
 }
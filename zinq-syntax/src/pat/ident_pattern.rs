@@ -0,0 +1,77 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Ident, Mut, Suffixed};
+
+use crate::{Syntax, pat::Pattern};
+
+///
+/// ## Ident Pattern
+/// a mutable binding, `mut a`, a plain
+/// `a` is read as a `PathPattern`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdentPattern {
+    pub mutable: Mut,
+    pub name: Ident,
+}
+
+impl From<IdentPattern> for Pattern {
+    fn from(value: IdentPattern) -> Self {
+        Self::Ident(value)
+    }
+}
+
+impl std::fmt::Display for IdentPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for IdentPattern {
+    fn span(&self) -> zinq_parse::Span {
+        Span::join(self.mutable.span(), self.name.span())
+    }
+}
+
+impl Peek for IdentPattern {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Suffixed<Mut, Ident>>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for IdentPattern {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let mutable = parser.parse::<Mut>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+
+        Ok(Self { mutable, name })
+    }
+}
+
+impl Syntax for IdentPattern {
+    fn name(&self) -> &str {
+        "Pattern::Ident"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_ident_pattern(self)
+            .then(|| crate::pat::walk_ident_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_ident_pattern_mut(self)
+            .then(|| crate::pat::walk_ident_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_ident_pattern_fold(folder, self);
+        folder.fold_ident_pattern(node)
+    }
+}
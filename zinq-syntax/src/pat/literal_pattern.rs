@@ -1,18 +1,26 @@
-use zinq_parse::{Parse, Peek, Spanned};
-use zinq_token::Literal;
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Literal, Minus, Suffixed};
 
 use crate::{Syntax, pat::Pattern};
 
 ///
 /// ## Literal Pattern
 /// `0 => ..` <br>
+/// `-1 => ..` <br>
 /// `"test" => ..`
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LiteralPattern {
+    pub minus: Option<Minus>,
     pub literal: Literal,
 }
 
+impl LiteralPattern {
+    pub fn is_negative(&self) -> bool {
+        self.minus.is_some()
+    }
+}
+
 impl std::ops::Deref for LiteralPattern {
     type Target = Literal;
 
@@ -23,7 +31,10 @@ impl std::ops::Deref for LiteralPattern {
 
 impl From<Literal> for LiteralPattern {
     fn from(value: Literal) -> Self {
-        Self { literal: value }
+        Self {
+            minus: None,
+            literal: value,
+        }
     }
 }
 
@@ -41,7 +52,10 @@ impl std::fmt::Display for LiteralPattern {
 
 impl Spanned for LiteralPattern {
     fn span(&self) -> zinq_parse::Span {
-        self.literal.span()
+        match &self.minus {
+            None => self.literal.span(),
+            Some(minus) => Span::join(minus.span(), self.literal.span()),
+        }
     }
 }
 
@@ -50,7 +64,10 @@ impl Peek for LiteralPattern {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<Literal>(cursor).unwrap_or(false))
+        Ok(parser.peek::<Literal>(cursor).unwrap_or(false)
+            || parser
+                .peek::<Suffixed<Minus, Literal>>(cursor)
+                .unwrap_or(false))
     }
 }
 
@@ -59,8 +76,10 @@ impl Parse for LiteralPattern {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let minus = parser.parse::<Option<Minus>>(cursor)?;
         let literal = parser.parse::<Literal>(cursor)?;
-        Ok(Self { literal })
+
+        Ok(Self { minus, literal })
     }
 }
 
@@ -69,7 +88,20 @@ impl Syntax for LiteralPattern {
         "Pattern::Literal"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_literal_pattern(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_literal_pattern(self)
+            .then(|| crate::pat::walk_literal_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_literal_pattern_mut(self)
+            .then(|| crate::pat::walk_literal_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_literal_pattern_fold(folder, self);
+        folder.fold_literal_pattern(node)
     }
 }
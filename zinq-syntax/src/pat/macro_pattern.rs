@@ -0,0 +1,72 @@
+use zinq_parse::{Parse, Peek, Spanned};
+
+use crate::{Syntax, macros::MacroCall, pat::Pattern};
+
+///
+/// ## Macro Pattern
+/// `point!(x, y)`, expanded before lowering
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroPattern {
+    pub call: MacroCall,
+}
+
+impl From<MacroPattern> for Pattern {
+    fn from(value: MacroPattern) -> Self {
+        Self::Macro(Box::new(value))
+    }
+}
+
+impl std::fmt::Display for MacroPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for MacroPattern {
+    fn span(&self) -> zinq_parse::Span {
+        self.call.span()
+    }
+}
+
+impl Peek for MacroPattern {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<MacroCall>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for MacroPattern {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let call = parser.parse::<MacroCall>(cursor)?;
+        Ok(Self { call })
+    }
+}
+
+impl Syntax for MacroPattern {
+    fn name(&self) -> &str {
+        "Pattern::Macro"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_pattern(self)
+            .then(|| crate::pat::walk_macro_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_pattern_mut(self)
+            .then(|| crate::pat::walk_macro_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_macro_pattern_fold(folder, self);
+        folder.fold_macro_pattern(node)
+    }
+}
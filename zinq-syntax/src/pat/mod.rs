@@ -1,27 +1,41 @@
+mod bind_pattern;
+mod fold;
 mod group_pattern;
+mod ident_pattern;
 mod literal_pattern;
+mod macro_pattern;
 mod or_pattern;
 mod parser;
 mod path_pattern;
+mod range_pattern;
 mod ref_pattern;
 mod spread_pattern;
 mod struct_pattern;
 mod tuple_pattern;
+mod tuple_struct_pattern;
 mod visitor;
+mod visitor_mut;
 mod wild_pattern;
 
 use std::any::type_name_of_val;
 
+pub use bind_pattern::*;
+pub use fold::*;
 pub use group_pattern::*;
+pub use ident_pattern::*;
 pub use literal_pattern::*;
+pub use macro_pattern::*;
 pub use or_pattern::*;
 pub use parser::*;
 pub use path_pattern::*;
+pub use range_pattern::*;
 pub use ref_pattern::*;
 pub use spread_pattern::*;
 pub use struct_pattern::*;
 pub use tuple_pattern::*;
+pub use tuple_struct_pattern::*;
 pub use visitor::*;
+pub use visitor_mut::*;
 pub use wild_pattern::*;
 
 use zinq_parse::{Parse, Peek, Spanned};
@@ -33,13 +47,18 @@ use crate::Syntax;
 pub enum Pattern {
     Wild(WildPattern),
     Path(PathPattern),
+    Ident(IdentPattern),
     Literal(LiteralPattern),
+    Range(Box<RangePattern>),
     Ref(RefPattern),
     Spread(SpreadPattern),
     Struct(StructPattern),
     Tuple(TuplePattern),
+    TupleStruct(TupleStructPattern),
     Group(GroupPattern),
+    Bind(Box<BindPattern>),
     Or(OrPattern),
+    Macro(Box<MacroPattern>),
 }
 
 impl Pattern {
@@ -57,6 +76,10 @@ impl Pattern {
         }
     }
 
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+
     pub fn is_literal(&self) -> bool {
         match self {
             Self::Literal(_) => true,
@@ -64,6 +87,10 @@ impl Pattern {
         }
     }
 
+    pub fn is_range(&self) -> bool {
+        matches!(self, Self::Range(_))
+    }
+
     pub fn is_ref(&self) -> bool {
         match self {
             Self::Ref(_) => true,
@@ -92,6 +119,10 @@ impl Pattern {
         }
     }
 
+    pub fn is_tuple_struct(&self) -> bool {
+        matches!(self, Self::TupleStruct(_))
+    }
+
     pub fn is_group(&self) -> bool {
         match self {
             Self::Group(_) => true,
@@ -99,6 +130,10 @@ impl Pattern {
         }
     }
 
+    pub fn is_bind(&self) -> bool {
+        matches!(self, Self::Bind(_))
+    }
+
     pub fn is_or(&self) -> bool {
         match self {
             Self::Or(_) => true,
@@ -106,6 +141,31 @@ impl Pattern {
         }
     }
 
+    pub fn is_macro(&self) -> bool {
+        matches!(self, Self::Macro(_))
+    }
+
+    ///
+    /// ## is_refutable
+    /// a pattern with a literal or range somewhere can
+    /// fail to match whatever the type of the value,
+    /// paths and tuple structs like `Some(v)` depend
+    /// on the type and are not counted
+    ///
+    pub fn is_refutable(&self) -> bool {
+        match self {
+            Self::Literal(_) | Self::Range(_) => true,
+            Self::Ref(v) => v.inner.is_refutable(),
+            Self::Group(v) => v.inner.is_refutable(),
+            Self::Bind(v) => v.inner.is_refutable(),
+            Self::Or(v) => v.left.is_refutable() && v.right.is_refutable(),
+            Self::Tuple(v) => v.items.iter().any(|item| item.value().is_refutable()),
+            Self::TupleStruct(v) => v.items.iter().any(|item| item.value().is_refutable()),
+            Self::Struct(v) => v.fields.iter().any(|field| field.value().is_refutable()),
+            _ => false,
+        }
+    }
+
     pub fn as_wild(&self) -> &WildPattern {
         match self {
             Self::Wild(v) => v,
@@ -120,6 +180,13 @@ impl Pattern {
         }
     }
 
+    pub fn as_ident(&self) -> &IdentPattern {
+        match self {
+            Self::Ident(v) => v,
+            v => panic!("expected IdentPattern, received {}", type_name_of_val(v)),
+        }
+    }
+
     pub fn as_literal(&self) -> &LiteralPattern {
         match self {
             Self::Literal(v) => v,
@@ -127,6 +194,13 @@ impl Pattern {
         }
     }
 
+    pub fn as_range(&self) -> &RangePattern {
+        match self {
+            Self::Range(v) => v,
+            v => panic!("expected RangePattern, received {}", type_name_of_val(v)),
+        }
+    }
+
     pub fn as_ref(&self) -> &RefPattern {
         match self {
             Self::Ref(v) => v,
@@ -155,6 +229,16 @@ impl Pattern {
         }
     }
 
+    pub fn as_tuple_struct(&self) -> &TupleStructPattern {
+        match self {
+            Self::TupleStruct(v) => v,
+            v => panic!(
+                "expected TupleStructPattern, received {}",
+                type_name_of_val(v)
+            ),
+        }
+    }
+
     pub fn as_group(&self) -> &GroupPattern {
         match self {
             Self::Group(v) => v,
@@ -162,12 +246,26 @@ impl Pattern {
         }
     }
 
+    pub fn as_bind(&self) -> &BindPattern {
+        match self {
+            Self::Bind(v) => v,
+            v => panic!("expected BindPattern, received {}", type_name_of_val(v)),
+        }
+    }
+
     pub fn as_or(&self) -> &OrPattern {
         match self {
             Self::Or(v) => v,
             v => panic!("expected OrPattern, received {}", type_name_of_val(v)),
         }
     }
+
+    pub fn as_macro(&self) -> &MacroPattern {
+        match self {
+            Self::Macro(v) => v,
+            v => panic!("expected MacroPattern, received {}", type_name_of_val(v)),
+        }
+    }
 }
 
 impl std::fmt::Display for Pattern {
@@ -175,13 +273,18 @@ impl std::fmt::Display for Pattern {
         match self {
             Self::Wild(v) => write!(f, "{}", v),
             Self::Path(v) => write!(f, "{}", v),
+            Self::Ident(v) => write!(f, "{}", v),
             Self::Literal(v) => write!(f, "{}", v),
+            Self::Range(v) => write!(f, "{}", v),
             Self::Ref(v) => write!(f, "{}", v),
             Self::Spread(v) => write!(f, "{}", v),
             Self::Struct(v) => write!(f, "{}", v),
             Self::Tuple(v) => write!(f, "{}", v),
+            Self::TupleStruct(v) => write!(f, "{}", v),
             Self::Group(v) => write!(f, "{}", v),
+            Self::Bind(v) => write!(f, "{}", v),
             Self::Or(v) => write!(f, "{}", v),
+            Self::Macro(v) => write!(f, "{}", v),
         }
     }
 }
@@ -191,13 +294,18 @@ impl Spanned for Pattern {
         match self {
             Self::Wild(v) => v.span(),
             Self::Path(v) => v.span(),
+            Self::Ident(v) => v.span(),
             Self::Literal(v) => v.span(),
+            Self::Range(v) => v.span(),
             Self::Ref(v) => v.span(),
             Self::Spread(v) => v.span(),
             Self::Struct(v) => v.span(),
             Self::Tuple(v) => v.span(),
+            Self::TupleStruct(v) => v.span(),
             Self::Group(v) => v.span(),
+            Self::Bind(v) => v.span(),
             Self::Or(v) => v.span(),
+            Self::Macro(v) => v.span(),
         }
     }
 }
@@ -229,31 +337,37 @@ impl Parse for Pattern {
 impl Syntax for Pattern {
     fn name(&self) -> &str {
         match self {
+            Self::Bind(v) => v.name(),
             Self::Group(v) => v.name(),
+            Self::Ident(v) => v.name(),
             Self::Literal(v) => v.name(),
+            Self::Macro(v) => v.name(),
             Self::Or(v) => v.name(),
             Self::Path(v) => v.name(),
+            Self::Range(v) => v.name(),
             Self::Ref(v) => v.name(),
             Self::Spread(v) => v.name(),
             Self::Struct(v) => v.name(),
             Self::Tuple(v) => v.name(),
+            Self::TupleStruct(v) => v.name(),
             Self::Wild(v) => v.name(),
         }
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_pattern(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_pattern(self)
+            .then(|| crate::pat::walk_pattern(visitor, self))
+    }
 
-        match self {
-            Self::Group(v) => v.accept(visitor),
-            Self::Literal(v) => v.accept(visitor),
-            Self::Or(v) => v.accept(visitor),
-            Self::Path(v) => v.accept(visitor),
-            Self::Ref(v) => v.accept(visitor),
-            Self::Spread(v) => v.accept(visitor),
-            Self::Struct(v) => v.accept(visitor),
-            Self::Tuple(v) => v.accept(visitor),
-            Self::Wild(v) => v.accept(visitor),
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_pattern_mut(self)
+            .then(|| crate::pat::walk_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_pattern_fold(folder, self);
+        folder.fold_pattern(node)
     }
 }
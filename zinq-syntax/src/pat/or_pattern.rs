@@ -37,9 +37,20 @@ impl Syntax for OrPattern {
         "Pattern::Or"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_or_pattern(self);
-        self.left.accept(visitor);
-        self.right.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_or_pattern(self)
+            .then(|| crate::pat::walk_or_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_or_pattern_mut(self)
+            .then(|| crate::pat::walk_or_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_or_pattern_fold(folder, self);
+        folder.fold_or_pattern(node)
     }
 }
@@ -1,6 +1,6 @@
 use zinq_error::Result;
 use zinq_parse::{Cursor, ZinqParser};
-use zinq_token::{And, Comma, Ident, LParen, Mut, Or, Suffixed};
+use zinq_token::{And, At, Comma, Ident, LParen, Mut, Or, Suffixed};
 
 use crate::pat::*;
 
@@ -9,6 +9,7 @@ pub trait PatternParser {
     fn parse_or_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern>;
     fn parse_tuple_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern>;
     fn parse_struct_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern>;
+    fn parse_bind_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern>;
     fn parse_ref_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern>;
     fn parse_primary_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern>;
 }
@@ -19,11 +20,11 @@ impl PatternParser for ZinqParser {
     }
 
     fn parse_or_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern> {
-        let mut pat = self.parse_tuple_pattern(cursor)?;
+        let mut pat = self.parse_bind_pattern(cursor)?;
 
         while self.peek::<Or>(cursor).unwrap_or(false) {
             let or = self.parse::<Or>(cursor)?;
-            let right = self.parse_tuple_pattern(cursor)?;
+            let right = self.parse_bind_pattern(cursor)?;
 
             pat = OrPattern {
                 left: Box::new(pat),
@@ -48,13 +49,44 @@ impl PatternParser for ZinqParser {
     }
 
     fn parse_struct_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern> {
+        if self.peek::<MacroPattern>(cursor).unwrap_or(false) {
+            return Ok(self.parse::<MacroPattern>(cursor)?.into());
+        }
+
         if self.peek::<StructPattern>(cursor).unwrap_or(false) {
             return Ok(self.parse::<StructPattern>(cursor)?.into());
         }
 
+        if self.peek::<TupleStructPattern>(cursor).unwrap_or(false) {
+            return Ok(self.parse::<TupleStructPattern>(cursor)?.into());
+        }
+
         self.parse_primary_pattern(cursor)
     }
 
+    fn parse_bind_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern> {
+        if self.peek::<Suffixed<Ident, At>>(cursor).unwrap_or(false)
+            || self
+                .peek::<Suffixed<Mut, Suffixed<Ident, At>>>(cursor)
+                .unwrap_or(false)
+        {
+            let mutable = self.parse::<Option<Mut>>(cursor)?;
+            let name = self.parse::<Ident>(cursor)?;
+            let at = self.parse::<At>(cursor)?;
+            let inner = self.parse_ref_pattern(cursor)?;
+
+            return Ok(BindPattern {
+                mutable,
+                name,
+                at,
+                inner: Box::new(inner),
+            }
+            .into());
+        }
+
+        self.parse_ref_pattern(cursor)
+    }
+
     fn parse_ref_pattern(&mut self, cursor: &mut Cursor) -> Result<Pattern> {
         if self.peek::<And>(cursor).unwrap_or(false) {
             let and = self.parse::<And>(cursor)?;
@@ -77,6 +109,11 @@ impl PatternParser for ZinqParser {
             return Ok(self.parse::<WildPattern>(cursor)?.into());
         }
 
+        // before the spread, which would read `..=5` as `..`
+        if self.peek::<RangePattern>(cursor).unwrap_or(false) {
+            return Ok(self.parse::<RangePattern>(cursor)?.into());
+        }
+
         if self.peek::<SpreadPattern>(cursor).unwrap_or(false) {
             return Ok(self.parse::<SpreadPattern>(cursor)?.into());
         }
@@ -85,6 +122,10 @@ impl PatternParser for ZinqParser {
             return Ok(self.parse::<LiteralPattern>(cursor)?.into());
         }
 
+        if self.peek::<IdentPattern>(cursor).unwrap_or(false) {
+            return Ok(self.parse::<IdentPattern>(cursor)?.into());
+        }
+
         if self.peek::<PathPattern>(cursor).unwrap_or(false) {
             return Ok(self.parse::<PathPattern>(cursor)?.into());
         }
@@ -54,7 +54,20 @@ impl Syntax for PathPattern {
         "Pattern::Path"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_path_pattern(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_path_pattern(self)
+            .then(|| crate::pat::walk_path_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_path_pattern_mut(self)
+            .then(|| crate::pat::walk_path_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_path_pattern_fold(folder, self);
+        folder.fold_path_pattern(node)
     }
 }
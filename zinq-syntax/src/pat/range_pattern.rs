@@ -0,0 +1,167 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{DotDot, DotDotEq, Suffixed};
+
+use crate::{
+    Syntax,
+    pat::{LiteralPattern, Pattern},
+};
+
+///
+/// ## Range Pattern
+/// `'a'..='z' => ..` <br>
+/// `-5..=-1 => ..` <br>
+/// `0.. => ..` <br>
+/// `..=5 => ..`
+///
+/// either bound may be left out, but not both
+/// since a lone `..` is a spread
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangePattern {
+    pub start: Option<LiteralPattern>,
+    pub limit: RangeLimit,
+    pub end: Option<LiteralPattern>,
+}
+
+impl RangePattern {
+    pub fn is_inclusive(&self) -> bool {
+        self.limit.is_inclusive()
+    }
+}
+
+impl From<RangePattern> for Pattern {
+    fn from(value: RangePattern) -> Self {
+        Self::Range(Box::new(value))
+    }
+}
+
+impl std::fmt::Display for RangePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for RangePattern {
+    fn span(&self) -> zinq_parse::Span {
+        let start = match &self.start {
+            None => self.limit.span(),
+            Some(v) => v.span(),
+        };
+
+        match &self.end {
+            None => Span::join(start, self.limit.span()),
+            Some(end) => Span::join(start, end.span()),
+        }
+    }
+}
+
+impl Peek for RangePattern {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser
+            .peek::<Suffixed<LiteralPattern, DotDot>>(cursor)
+            .unwrap_or(false)
+            || parser.peek::<DotDotEq>(cursor).unwrap_or(false)
+            || parser
+                .peek::<Suffixed<DotDot, LiteralPattern>>(cursor)
+                .unwrap_or(false))
+    }
+}
+
+impl Parse for RangePattern {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let start = parser.parse::<Option<LiteralPattern>>(cursor)?;
+        let limit = parser.parse::<RangeLimit>(cursor)?;
+        let end = match limit.is_inclusive() || start.is_none() {
+            true => Some(parser.parse::<LiteralPattern>(cursor)?),
+            false => parser.parse::<Option<LiteralPattern>>(cursor)?,
+        };
+
+        Ok(Self { start, limit, end })
+    }
+}
+
+impl Syntax for RangePattern {
+    fn name(&self) -> &str {
+        "Pattern::Range"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_range_pattern(self)
+            .then(|| crate::pat::walk_range_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_range_pattern_mut(self)
+            .then(|| crate::pat::walk_range_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_range_pattern_fold(folder, self);
+        folder.fold_range_pattern(node)
+    }
+}
+
+///
+/// ## Range Limit
+/// `..` excludes the end of a range, `..=` includes it
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RangeLimit {
+    Exclusive(DotDot),
+    Inclusive(DotDotEq),
+}
+
+impl RangeLimit {
+    pub fn is_exclusive(&self) -> bool {
+        matches!(self, Self::Exclusive(_))
+    }
+
+    pub fn is_inclusive(&self) -> bool {
+        matches!(self, Self::Inclusive(_))
+    }
+}
+
+impl std::fmt::Display for RangeLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for RangeLimit {
+    fn span(&self) -> zinq_parse::Span {
+        match self {
+            Self::Exclusive(v) => v.span(),
+            Self::Inclusive(v) => v.span(),
+        }
+    }
+}
+
+impl Peek for RangeLimit {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<DotDot>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for RangeLimit {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        if parser.peek::<DotDotEq>(cursor).unwrap_or(false) {
+            return Ok(Self::Inclusive(parser.parse::<DotDotEq>(cursor)?));
+        }
+
+        Ok(Self::Exclusive(parser.parse::<DotDot>(cursor)?))
+    }
+}
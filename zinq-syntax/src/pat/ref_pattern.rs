@@ -38,8 +38,20 @@ impl Syntax for RefPattern {
         "Pattern::Ref"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_ref_pattern(self);
-        self.inner.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_ref_pattern(self)
+            .then(|| crate::pat::walk_ref_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_ref_pattern_mut(self)
+            .then(|| crate::pat::walk_ref_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_ref_pattern_fold(folder, self);
+        folder.fold_ref_pattern(node)
     }
 }
@@ -68,7 +68,20 @@ impl Syntax for SpreadPattern {
         "Pattern::Spread"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_spread_pattern(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_spread_pattern(self)
+            .then(|| crate::pat::walk_spread_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_spread_pattern_mut(self)
+            .then(|| crate::pat::walk_spread_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_spread_pattern_fold(folder, self);
+        folder.fold_spread_pattern(node)
     }
 }
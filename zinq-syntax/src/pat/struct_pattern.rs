@@ -68,11 +68,20 @@ impl Syntax for StructPattern {
         "Pattern::Struct"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_struct_pattern(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_struct_pattern(self)
+            .then(|| crate::pat::walk_struct_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_struct_pattern_mut(self)
+            .then(|| crate::pat::walk_struct_pattern_mut(visitor, self))
+    }
 
-        for field in self.fields.iter() {
-            field.value().accept(visitor);
-        }
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_struct_pattern_fold(folder, self);
+        folder.fold_struct_pattern(node)
     }
 }
@@ -63,11 +63,20 @@ impl Syntax for TuplePattern {
         "Pattern::Tuple"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_tuple_pattern(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_pattern(self)
+            .then(|| crate::pat::walk_tuple_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_pattern_mut(self)
+            .then(|| crate::pat::walk_tuple_pattern_mut(visitor, self))
+    }
 
-        for item in self.items.iter() {
-            item.value().accept(visitor);
-        }
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_tuple_pattern_fold(folder, self);
+        folder.fold_tuple_pattern(node)
     }
 }
@@ -0,0 +1,87 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Comma, LParen, Punctuated, RParen, Suffixed};
+
+use crate::{Path, Syntax, pat::Pattern};
+
+///
+/// ## Tuple Struct Pattern
+/// `Some(v)` or `Point(x, _)`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleStructPattern {
+    pub path: Path,
+    pub left_paren: LParen,
+    pub items: Punctuated<Pattern, Comma>,
+    pub right_paren: RParen,
+}
+
+impl From<TupleStructPattern> for Pattern {
+    fn from(value: TupleStructPattern) -> Self {
+        Self::TupleStruct(value)
+    }
+}
+
+impl std::fmt::Display for TupleStructPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for TupleStructPattern {
+    fn span(&self) -> zinq_parse::Span {
+        Span::join(self.path.span(), self.right_paren.span())
+    }
+}
+
+impl Peek for TupleStructPattern {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser
+            .peek::<Suffixed<Path, LParen>>(cursor)
+            .unwrap_or(false))
+    }
+}
+
+impl Parse for TupleStructPattern {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let path = parser.parse::<Path>(cursor)?;
+        let left_paren = parser.parse::<LParen>(cursor)?;
+        let items = parser.parse::<Punctuated<Pattern, Comma>>(cursor)?;
+        let right_paren = parser.parse::<RParen>(cursor)?;
+
+        Ok(Self {
+            path,
+            left_paren,
+            items,
+            right_paren,
+        })
+    }
+}
+
+impl Syntax for TupleStructPattern {
+    fn name(&self) -> &str {
+        "Pattern::TupleStruct"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_struct_pattern(self)
+            .then(|| crate::pat::walk_tuple_struct_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_struct_pattern_mut(self)
+            .then(|| crate::pat::walk_tuple_struct_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_tuple_struct_pattern_fold(folder, self);
+        folder.fold_tuple_struct_pattern(node)
+    }
+}
@@ -1,16 +1,180 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
 use super::*;
 
 pub trait PatternVisitor {
-    #![allow(unused)]
-
-    fn visit_pattern(&mut self, node: &Pattern) {}
-    fn visit_group_pattern(&mut self, node: &GroupPattern) {}
-    fn visit_literal_pattern(&mut self, node: &LiteralPattern) {}
-    fn visit_or_pattern(&mut self, node: &OrPattern) {}
-    fn visit_path_pattern(&mut self, node: &PathPattern) {}
-    fn visit_ref_pattern(&mut self, node: &RefPattern) {}
-    fn visit_spread_pattern(&mut self, node: &SpreadPattern) {}
-    fn visit_struct_pattern(&mut self, node: &StructPattern) {}
-    fn visit_tuple_pattern(&mut self, node: &TuplePattern) {}
-    fn visit_wild_pattern(&mut self, node: &WildPattern) {}
+    fn visit_pattern(&mut self, node: &Pattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_bind_pattern(&mut self, node: &BindPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_group_pattern(&mut self, node: &GroupPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ident_pattern(&mut self, node: &IdentPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_literal_pattern(&mut self, node: &LiteralPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_pattern(&mut self, node: &MacroPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_or_pattern(&mut self, node: &OrPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_path_pattern(&mut self, node: &PathPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_range_pattern(&mut self, node: &RangePattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ref_pattern(&mut self, node: &RefPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_spread_pattern(&mut self, node: &SpreadPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_struct_pattern(&mut self, node: &StructPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_pattern(&mut self, node: &TuplePattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_struct_pattern(&mut self, node: &TupleStructPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_wild_pattern(&mut self, node: &WildPattern) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_pattern<V: crate::Visitor>(visitor: &mut V, node: &Pattern) -> Visit {
+    match node {
+        Pattern::Wild(v) => v.accept(visitor),
+        Pattern::Path(v) => v.accept(visitor),
+        Pattern::Ident(v) => v.accept(visitor),
+        Pattern::Literal(v) => v.accept(visitor),
+        Pattern::Range(v) => v.accept(visitor),
+        Pattern::Ref(v) => v.accept(visitor),
+        Pattern::Spread(v) => v.accept(visitor),
+        Pattern::Struct(v) => v.accept(visitor),
+        Pattern::Tuple(v) => v.accept(visitor),
+        Pattern::TupleStruct(v) => v.accept(visitor),
+        Pattern::Group(v) => v.accept(visitor),
+        Pattern::Bind(v) => v.accept(visitor),
+        Pattern::Or(v) => v.accept(visitor),
+        Pattern::Macro(v) => v.accept(visitor),
+    }
+}
+
+pub fn walk_bind_pattern<V: crate::Visitor>(visitor: &mut V, node: &BindPattern) -> Visit {
+    try_visit!(node.inner.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_group_pattern<V: crate::Visitor>(visitor: &mut V, node: &GroupPattern) -> Visit {
+    try_visit!(node.inner.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_ident_pattern<V: crate::Visitor>(_visitor: &mut V, _node: &IdentPattern) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_literal_pattern<V: crate::Visitor>(_visitor: &mut V, _node: &LiteralPattern) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_macro_pattern<V: crate::Visitor>(_visitor: &mut V, _node: &MacroPattern) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_or_pattern<V: crate::Visitor>(visitor: &mut V, node: &OrPattern) -> Visit {
+    try_visit!(node.left.accept(visitor));
+    try_visit!(node.right.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_path_pattern<V: crate::Visitor>(visitor: &mut V, node: &PathPattern) -> Visit {
+    try_visit!(crate::walk_path(visitor, &node.path));
+
+    Visit::Continue
+}
+
+pub fn walk_range_pattern<V: crate::Visitor>(visitor: &mut V, node: &RangePattern) -> Visit {
+    if let Some(v) = &node.start {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.end {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_ref_pattern<V: crate::Visitor>(visitor: &mut V, node: &RefPattern) -> Visit {
+    try_visit!(node.inner.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_spread_pattern<V: crate::Visitor>(_visitor: &mut V, _node: &SpreadPattern) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_struct_pattern<V: crate::Visitor>(visitor: &mut V, node: &StructPattern) -> Visit {
+    try_visit!(crate::walk_path(visitor, &node.path));
+
+    for item in node.fields.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_pattern<V: crate::Visitor>(visitor: &mut V, node: &TuplePattern) -> Visit {
+    for item in node.items.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_struct_pattern<V: crate::Visitor>(
+    visitor: &mut V,
+    node: &TupleStructPattern,
+) -> Visit {
+    try_visit!(crate::walk_path(visitor, &node.path));
+
+    for item in node.items.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_wild_pattern<V: crate::Visitor>(_visitor: &mut V, _node: &WildPattern) -> Visit {
+    Visit::Continue
 }
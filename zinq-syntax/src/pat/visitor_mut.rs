@@ -0,0 +1,213 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait PatternVisitorMut {
+    fn visit_pattern_mut(&mut self, node: &mut Pattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_bind_pattern_mut(&mut self, node: &mut BindPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_group_pattern_mut(&mut self, node: &mut GroupPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ident_pattern_mut(&mut self, node: &mut IdentPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_literal_pattern_mut(&mut self, node: &mut LiteralPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_pattern_mut(&mut self, node: &mut MacroPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_or_pattern_mut(&mut self, node: &mut OrPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_path_pattern_mut(&mut self, node: &mut PathPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_range_pattern_mut(&mut self, node: &mut RangePattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ref_pattern_mut(&mut self, node: &mut RefPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_spread_pattern_mut(&mut self, node: &mut SpreadPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_struct_pattern_mut(&mut self, node: &mut StructPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_pattern_mut(&mut self, node: &mut TuplePattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_struct_pattern_mut(&mut self, node: &mut TupleStructPattern) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_wild_pattern_mut(&mut self, node: &mut WildPattern) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_pattern_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Pattern) -> Visit {
+    match node {
+        Pattern::Wild(v) => v.accept_mut(visitor),
+        Pattern::Path(v) => v.accept_mut(visitor),
+        Pattern::Ident(v) => v.accept_mut(visitor),
+        Pattern::Literal(v) => v.accept_mut(visitor),
+        Pattern::Range(v) => v.accept_mut(visitor),
+        Pattern::Ref(v) => v.accept_mut(visitor),
+        Pattern::Spread(v) => v.accept_mut(visitor),
+        Pattern::Struct(v) => v.accept_mut(visitor),
+        Pattern::Tuple(v) => v.accept_mut(visitor),
+        Pattern::TupleStruct(v) => v.accept_mut(visitor),
+        Pattern::Group(v) => v.accept_mut(visitor),
+        Pattern::Bind(v) => v.accept_mut(visitor),
+        Pattern::Or(v) => v.accept_mut(visitor),
+        Pattern::Macro(v) => v.accept_mut(visitor),
+    }
+}
+
+pub fn walk_bind_pattern_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut BindPattern,
+) -> Visit {
+    try_visit!(node.inner.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_group_pattern_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut GroupPattern,
+) -> Visit {
+    try_visit!(node.inner.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_ident_pattern_mut<V: crate::VisitorMut>(
+    _visitor: &mut V,
+    _node: &mut IdentPattern,
+) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_literal_pattern_mut<V: crate::VisitorMut>(
+    _visitor: &mut V,
+    _node: &mut LiteralPattern,
+) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_macro_pattern_mut<V: crate::VisitorMut>(
+    _visitor: &mut V,
+    _node: &mut MacroPattern,
+) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_or_pattern_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut OrPattern) -> Visit {
+    try_visit!(node.left.accept_mut(visitor));
+    try_visit!(node.right.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_path_pattern_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut PathPattern,
+) -> Visit {
+    try_visit!(crate::walk_path_mut(visitor, &mut node.path));
+
+    Visit::Continue
+}
+
+pub fn walk_range_pattern_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut RangePattern,
+) -> Visit {
+    if let Some(v) = &mut node.start {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.end {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_ref_pattern_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut RefPattern) -> Visit {
+    try_visit!(node.inner.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_spread_pattern_mut<V: crate::VisitorMut>(
+    _visitor: &mut V,
+    _node: &mut SpreadPattern,
+) -> Visit {
+    Visit::Continue
+}
+
+pub fn walk_struct_pattern_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut StructPattern,
+) -> Visit {
+    try_visit!(crate::walk_path_mut(visitor, &mut node.path));
+
+    for item in node.fields.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_pattern_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut TuplePattern,
+) -> Visit {
+    for item in node.items.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_struct_pattern_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut TupleStructPattern,
+) -> Visit {
+    try_visit!(crate::walk_path_mut(visitor, &mut node.path));
+
+    for item in node.items.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_wild_pattern_mut<V: crate::VisitorMut>(
+    _visitor: &mut V,
+    _node: &mut WildPattern,
+) -> Visit {
+    Visit::Continue
+}
@@ -69,7 +69,20 @@ impl Syntax for WildPattern {
         "Pattern::Wild"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_wild_pattern(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_wild_pattern(self)
+            .then(|| crate::pat::walk_wild_pattern(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_wild_pattern_mut(self)
+            .then(|| crate::pat::walk_wild_pattern_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::pat::walk_wild_pattern_fold(folder, self);
+        folder.fold_wild_pattern(node)
     }
 }
@@ -0,0 +1,215 @@
+use zinq_parse::{Diagnostic, Parse, Peek, Span, Spanned};
+use zinq_token::{Not, Pound, Suffixed};
+
+use crate::{PackageDecl, Syntax, meta::Meta, stmt::Stmt};
+
+///
+/// ## SourceFile
+/// a whole file, its optional package header,
+/// inner attributes and module level items
+///
+/// ```
+/// package app;
+///
+/// #![Print(pretty)]
+///
+/// use std::io;
+///
+/// fn main() { ... }
+/// ```
+///
+/// statements that are only legal inside a block,
+/// like a top level `return`, are reported as
+/// diagnostics and left out of `items`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceFile {
+    pub package: Option<PackageDecl>,
+    pub attrs: Vec<Meta>,
+    pub items: Vec<Stmt>,
+    span: Span,
+}
+
+impl Syntax for SourceFile {
+    fn name(&self) -> &str {
+        "SourceFile"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_source_file(self)
+            .then(|| crate::walk_source_file(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_source_file_mut(self)
+            .then(|| crate::walk_source_file_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::walk_source_file_fold(folder, self);
+        folder.fold_source_file(node)
+    }
+}
+
+impl std::fmt::Display for SourceFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for SourceFile {
+    fn peek(_: &zinq_parse::Cursor, _: &zinq_parse::ZinqParser) -> zinq_error::Result<bool> {
+        Ok(true)
+    }
+}
+
+impl Parse for SourceFile {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let start = cursor.source_span();
+        let package = parser.parse::<Option<PackageDecl>>(cursor)?;
+        let mut attrs = vec![];
+        let mut items = vec![];
+
+        while parser.peek::<Suffixed<Pound, Not>>(cursor).unwrap_or(false) {
+            attrs.push(parser.parse::<Meta>(cursor)?);
+        }
+
+        while !cursor
+            .fork()
+            .next_while(|b, _| b.is_ascii_whitespace())?
+            .eof()
+        {
+            if let Some(item) = parse_item(cursor, parser)? {
+                items.push(item);
+            }
+        }
+
+        cursor.next_while(|b, _| b.is_ascii_whitespace())?;
+
+        Ok(Self {
+            package,
+            attrs,
+            items,
+            span: Span::join(start, cursor.source_span()),
+        })
+    }
+}
+
+///
+/// ## parse_item
+/// parse the next statement, reporting it
+/// and returning `None` when it is not
+/// legal at module level
+///
+pub(crate) fn parse_item(
+    cursor: &mut zinq_parse::Cursor,
+    parser: &mut zinq_parse::ZinqParser,
+) -> zinq_error::Result<Option<Stmt>> {
+    let stmt = parser.parse::<Stmt>(cursor)?;
+
+    if !stmt.is_item() {
+        cursor.diagnose(
+            Diagnostic::item_not_allowed_error(stmt.span())
+                .message(&format!("{} is not allowed at module level", stmt.name()))
+                .build(),
+        );
+
+        return Ok(None);
+    }
+
+    Ok(Some(stmt))
+}
+
+impl Spanned for SourceFile {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::{Span, diagnostic::ITEM_NOT_ALLOWED_ERROR};
+
+    use crate::SourceFile;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"package app::core;\n\n#![Print(pretty)]\n\nmod tests;\nuse std::io;\n\nstruct User {}\n\nfn main() {}\n",
+        )
+        .cursor();
+
+        let file = parser.parse::<SourceFile>(&mut cursor)?;
+        let result = cursor.build(file);
+
+        debug_assert!(result.diagnostic.is_none());
+        debug_assert_eq!(
+            result.value.package.as_ref().unwrap().path.to_string(),
+            "app::core"
+        );
+        debug_assert_eq!(result.value.attrs.len(), 1);
+        debug_assert!(result.value.attrs[0].is_inner());
+        debug_assert_eq!(result.value.items.len(), 4);
+        debug_assert!(result.value.items[0].is_mod());
+        debug_assert!(result.value.items[3].is_fn());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_without_package() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"  \n").cursor();
+        let file = parser.parse::<SourceFile>(&mut cursor)?;
+
+        debug_assert!(file.package.is_none());
+        debug_assert!(file.items.is_empty());
+        debug_assert!(cursor.eof());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_report_top_level_return() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"fn main() {}\nreturn 1;\n").cursor();
+        let file = parser.parse::<SourceFile>(&mut cursor)?;
+        let result = cursor.build(file);
+        let diagnostic = result.diagnostic.expect("expected diagnostic");
+        let error = &diagnostic.children[0].children[0];
+
+        debug_assert_eq!(result.value.items.len(), 1);
+        debug_assert_eq!(error.code, ITEM_NOT_ALLOWED_ERROR);
+        debug_assert_eq!(error.span.to_string(), "return 1;");
+        debug_assert_eq!(error.span.start().line(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_const_and_alias_items() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"const MAX: u32 = 10;
+pub type Id = u64;
+fn main() {}
+",
+        )
+        .cursor();
+        let file = parser.parse::<SourceFile>(&mut cursor)?;
+        let result = cursor.build(file);
+
+        debug_assert!(result.diagnostic.is_none());
+        debug_assert!(result.value.items[0].is_const());
+        debug_assert!(result.value.items[1].is_type_alias());
+
+        Ok(())
+    }
+}
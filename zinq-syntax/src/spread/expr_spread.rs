@@ -0,0 +1,52 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::DotDot;
+
+use crate::expr::Expr;
+
+///
+/// ## Expr Spread
+/// ```
+/// let b = User {
+///     name: "b",
+///     ..a
+/// };
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExprSpread {
+    pub op: DotDot,
+    pub expr: Box<Expr>,
+}
+
+impl std::fmt::Display for ExprSpread {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ExprSpread {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<DotDot>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for ExprSpread {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let op = parser.parse::<DotDot>(cursor)?;
+        let expr = parser.parse::<Box<Expr>>(cursor)?;
+
+        Ok(Self { op, expr })
+    }
+}
+
+impl Spanned for ExprSpread {
+    fn span(&self) -> Span {
+        Span::join(self.op.span(), self.expr.span())
+    }
+}
@@ -1,3 +1,5 @@
+mod expr_spread;
 mod type_spread;
 
+pub use expr_spread::*;
 pub use type_spread::*;
@@ -1,15 +1,46 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{LBrace, RBrace};
+use zinq_token::{LBrace, Not, Pound, RBrace, Suffixed};
 
-use crate::{Syntax, stmt::Stmt};
+use crate::{
+    Syntax,
+    expr::{BlockExpr, Expr, IfElseExpr, MacroExpr},
+    meta::Meta,
+    stmt::{ExprStmt, Stmt},
+};
 
+///
+/// ## Block Statement
+/// `{ <stmts> <tail> }`, the tail is a final
+/// expression without a `;` and is the value
+/// of the block
+///
+/// a trailing `if` with an `else` or a nested
+/// block is also read as the tail, so both
+/// of these blocks evaluate to `a`
+///
+/// ```
+/// { if c { a } else { b } }
+/// { let x = 1; a }
+/// ```
+///
+/// inner attributes (`#![...]`) open the
+/// block and apply to the whole block
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BlockStmt {
     pub left_brace: LBrace,
+    pub attrs: Vec<Meta>,
     pub stmts: Vec<Stmt>,
+    pub tail: Option<Box<Expr>>,
     pub right_brace: RBrace,
 }
 
+impl BlockStmt {
+    pub fn has_tail(&self) -> bool {
+        self.tail.is_some()
+    }
+}
+
 impl From<BlockStmt> for Stmt {
     fn from(value: BlockStmt) -> Self {
         Self::Block(value)
@@ -21,12 +52,21 @@ impl Syntax for BlockStmt {
         "Stmt::Block"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_block_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_block_stmt(self)
+            .then(|| crate::stmt::walk_block_stmt(visitor, self))
+    }
 
-        for stmt in self.stmts.iter() {
-            stmt.accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_block_stmt_mut(self)
+            .then(|| crate::stmt::walk_block_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_block_stmt_fold(folder, self);
+        folder.fold_block_stmt(node)
     }
 }
 
@@ -51,17 +91,50 @@ impl Parse for BlockStmt {
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
         let left_brace = parser.parse::<LBrace>(cursor)?;
+        let mut attrs = vec![];
         let mut stmts = vec![];
+        let mut tail = None;
+
+        while parser.peek::<Suffixed<Pound, Not>>(cursor).unwrap_or(false) {
+            attrs.push(parser.parse::<Meta>(cursor)?);
+        }
 
         while !cursor.eof() && !parser.peek::<RBrace>(cursor).unwrap_or(false) {
-            stmts.push(parser.parse::<Stmt>(cursor)?);
+            let stmt = parser.parse::<Stmt>(cursor)?;
+
+            if !parser.peek::<RBrace>(cursor).unwrap_or(false) {
+                stmts.push(stmt);
+                continue;
+            }
+
+            match stmt {
+                // attributed statements keep their meta, so they are never the tail
+                Stmt::Expr(ExprStmt {
+                    meta: None,
+                    expr,
+                    semi: None,
+                }) => tail = Some(Box::new(expr)),
+                Stmt::If(v) if v.meta.is_none() && v.else_stmt.is_some() => {
+                    tail = Some(Box::new(IfElseExpr::from(v).into()))
+                }
+                Stmt::Block(v) => tail = Some(Box::new(BlockExpr::from(v).into())),
+                // `m!(..)` ends the block as a value, `m! {..}` stays an item
+                Stmt::MacroCall(v)
+                    if v.meta.is_none() && v.semi.is_none() && !v.call.is_braced() =>
+                {
+                    tail = Some(Box::new(MacroExpr { call: v.call }.into()))
+                }
+                v => stmts.push(v),
+            };
         }
 
         let right_brace = parser.parse::<RBrace>(cursor)?;
 
         Ok(Self {
             left_brace,
+            attrs,
             stmts,
+            tail,
             right_brace,
         })
     }
@@ -0,0 +1,168 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Apostrophe, Break, SemiColon};
+
+use crate::{Label, Syntax, expr::Expr, meta::Meta, stmt::Stmt};
+
+///
+/// ## Break Statement
+/// `break 'label <value>;`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BreakStmt {
+    pub meta: Option<Meta>,
+    pub keyword: Break,
+    pub label: Option<Label>,
+    pub value: Option<Expr>,
+    pub semi: Option<SemiColon>,
+}
+
+impl From<BreakStmt> for Stmt {
+    fn from(value: BreakStmt) -> Self {
+        Self::Break(value)
+    }
+}
+
+impl Syntax for BreakStmt {
+    fn name(&self) -> &str {
+        "Stmt::Break"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_break_stmt(self)
+            .then(|| crate::stmt::walk_break_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_break_stmt_mut(self)
+            .then(|| crate::stmt::walk_break_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_break_stmt_fold(folder, self);
+        folder.fold_break_stmt(node)
+    }
+}
+
+impl std::fmt::Display for BreakStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for BreakStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Break>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for BreakStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let keyword = parser.parse::<Break>(cursor)?;
+        let mut label = None;
+
+        // a bare identifier is a value, labels need the tick
+        if parser.peek::<Apostrophe>(cursor).unwrap_or(false) {
+            label = Some(parser.parse::<Label>(cursor)?);
+        }
+
+        let value = parser.parse::<Option<Expr>>(cursor)?;
+        let semi = parser.parse::<Option<SemiColon>>(cursor)?;
+
+        Ok(Self {
+            meta,
+            keyword,
+            label,
+            value,
+            semi,
+        })
+    }
+}
+
+impl Spanned for BreakStmt {
+    fn span(&self) -> Span {
+        let start = match &self.meta {
+            None => self.keyword.span(),
+            Some(meta) => meta.span(),
+        };
+
+        if let Some(semi) = &self.semi {
+            return Span::join(start, semi.span());
+        }
+
+        if let Some(value) = &self.value {
+            return Span::join(start, value.span());
+        }
+
+        if let Some(label) = &self.label {
+            return Span::join(start, label.span());
+        }
+
+        Span::join(start, self.keyword.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::StmtParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"break;").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.is_break());
+        debug_assert!(stmt.as_break().label.is_none());
+        debug_assert!(stmt.as_break().value.is_none());
+        debug_assert_eq!(stmt.to_string(), "break;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_label_and_value() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"break 'outer total + 1;").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert_eq!(
+            stmt.as_break().label.as_ref().unwrap().to_string(),
+            "'outer"
+        );
+        debug_assert_eq!(
+            stmt.as_break().value.as_ref().unwrap().to_string(),
+            "total + 1"
+        );
+        debug_assert_eq!(stmt.to_string(), "break 'outer total + 1;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_value() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"break outer").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.as_break().label.is_none());
+        debug_assert_eq!(stmt.as_break().value.as_ref().unwrap().to_string(), "outer");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Colon, Const, Eq, Ident, SemiColon, Suffixed};
+
+use crate::{Syntax, Visibility, expr::Expr, meta::Meta, stmt::Stmt, ty::Type};
+
+///
+/// ## Const Statement
+/// `const <name>: <type> = <value>;`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstStmt {
+    pub meta: Option<Meta>,
+    pub vis: Visibility,
+    pub keyword: Const,
+    pub name: Ident,
+    pub ty: Suffixed<Colon, Type>,
+    pub init: Suffixed<Eq, Expr>,
+    pub semi: SemiColon,
+}
+
+impl From<ConstStmt> for Stmt {
+    fn from(value: ConstStmt) -> Self {
+        Self::Const(value)
+    }
+}
+
+impl Syntax for ConstStmt {
+    fn name(&self) -> &str {
+        "Stmt::Const"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_const_stmt(self)
+            .then(|| crate::stmt::walk_const_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_const_stmt_mut(self)
+            .then(|| crate::stmt::walk_const_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_const_stmt_fold(folder, self);
+        folder.fold_const_stmt(node)
+    }
+}
+
+impl std::fmt::Display for ConstStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ConstStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Visibility>(&mut fork)?;
+        Ok(fork_parser.peek::<Const>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for ConstStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let vis = parser.parse::<Visibility>(cursor)?;
+        let keyword = parser.parse::<Const>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+        let ty = parser.parse::<Suffixed<Colon, Type>>(cursor)?;
+        let init = parser.parse::<Suffixed<Eq, Expr>>(cursor)?;
+        let semi = parser.parse::<SemiColon>(cursor)?;
+
+        Ok(Self {
+            meta,
+            vis,
+            keyword,
+            name,
+            ty,
+            init,
+            semi,
+        })
+    }
+}
+
+impl Spanned for ConstStmt {
+    fn span(&self) -> Span {
+        if let Some(meta) = &self.meta {
+            return Span::join(meta.span(), self.semi.span());
+        }
+
+        Span::join(self.vis.span(), self.semi.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::{ConstStmt, StmtParser};
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"pub const MAX_USERS: u32 = 10 + 2;").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.is_const());
+        debug_assert!(stmt.is_item());
+        debug_assert!(stmt.as_const().vis.is_pub());
+        debug_assert_eq!(stmt.as_const().init.suffix.to_string(), "10 + 2");
+        debug_assert_eq!(stmt.to_string(), "pub const MAX_USERS: u32 = 10 + 2;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_require_type() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"const MAX = 10;").cursor();
+
+        debug_assert!(parser.parse::<ConstStmt>(&mut cursor).is_err());
+
+        Ok(())
+    }
+}
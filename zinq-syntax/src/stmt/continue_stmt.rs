@@ -0,0 +1,123 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Continue, SemiColon};
+
+use crate::{Label, Syntax, meta::Meta, stmt::Stmt};
+
+///
+/// ## Continue Statement
+/// `continue 'label;`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContinueStmt {
+    pub meta: Option<Meta>,
+    pub keyword: Continue,
+    pub label: Option<Label>,
+    pub semi: Option<SemiColon>,
+}
+
+impl From<ContinueStmt> for Stmt {
+    fn from(value: ContinueStmt) -> Self {
+        Self::Continue(value)
+    }
+}
+
+impl Syntax for ContinueStmt {
+    fn name(&self) -> &str {
+        "Stmt::Continue"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_continue_stmt(self)
+            .then(|| crate::stmt::walk_continue_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_continue_stmt_mut(self)
+            .then(|| crate::stmt::walk_continue_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_continue_stmt_fold(folder, self);
+        folder.fold_continue_stmt(node)
+    }
+}
+
+impl std::fmt::Display for ContinueStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ContinueStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Continue>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for ContinueStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let keyword = parser.parse::<Continue>(cursor)?;
+        let label = parser.parse::<Option<Label>>(cursor)?;
+        let semi = parser.parse::<Option<SemiColon>>(cursor)?;
+
+        Ok(Self {
+            meta,
+            keyword,
+            label,
+            semi,
+        })
+    }
+}
+
+impl Spanned for ContinueStmt {
+    fn span(&self) -> Span {
+        let start = match &self.meta {
+            None => self.keyword.span(),
+            Some(meta) => meta.span(),
+        };
+
+        if let Some(semi) = &self.semi {
+            return Span::join(start, semi.span());
+        }
+
+        if let Some(label) = &self.label {
+            return Span::join(start, label.span());
+        }
+
+        Span::join(start, self.keyword.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::StmtParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"continue outer;").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.is_continue());
+        debug_assert!(!stmt.as_continue().label.as_ref().unwrap().is_ticked());
+        debug_assert_eq!(stmt.to_string(), "continue outer;");
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Comma, Enum, Ident, LBrace, Punctuated, RBrace};
 
-use crate::{Generics, Syntax, Variant, Visibility, meta::Meta, stmt::Stmt};
+use crate::{Generics, Syntax, Variant, Visibility, WhereClause, meta::Meta, stmt::Stmt};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EnumStmt {
@@ -10,6 +10,7 @@ pub struct EnumStmt {
     pub keyword: Enum,
     pub name: Ident,
     pub generics: Option<Generics>,
+    pub where_clause: Option<WhereClause>,
     pub left_brace: LBrace,
     pub variants: Punctuated<Variant, Comma>,
     pub right_brace: RBrace,
@@ -32,8 +33,21 @@ impl Syntax for EnumStmt {
         "Stmt::Enum"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_enum_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_enum_stmt(self)
+            .then(|| crate::stmt::walk_enum_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_enum_stmt_mut(self)
+            .then(|| crate::stmt::walk_enum_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_enum_stmt_fold(folder, self);
+        folder.fold_enum_stmt(node)
     }
 }
 
@@ -61,6 +75,7 @@ impl Parse for EnumStmt {
         let keyword = parser.parse::<Enum>(cursor)?;
         let name = parser.parse::<Ident>(cursor)?;
         let generics = parser.parse::<Option<Generics>>(cursor)?;
+        let where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
         let left_brace = parser.parse::<LBrace>(cursor)?;
         let variants = parser.parse::<Punctuated<Variant, Comma>>(cursor)?;
         let right_brace = parser.parse::<RBrace>(cursor)?;
@@ -71,6 +86,7 @@ impl Parse for EnumStmt {
             keyword,
             name,
             generics,
+            where_clause,
             left_brace,
             variants,
             right_brace,
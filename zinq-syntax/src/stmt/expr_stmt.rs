@@ -1,10 +1,11 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::SemiColon;
 
-use crate::{Syntax, expr::Expr, stmt::Stmt};
+use crate::{Syntax, expr::Expr, meta::Meta, stmt::Stmt};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExprStmt {
+    pub meta: Option<Meta>,
     pub expr: Expr,
     pub semi: Option<SemiColon>,
 }
@@ -20,9 +21,21 @@ impl Syntax for ExprStmt {
         "Stmt::Expr"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_expr_stmt(self);
-        self.expr.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_expr_stmt(self)
+            .then(|| crate::stmt::walk_expr_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_expr_stmt_mut(self)
+            .then(|| crate::stmt::walk_expr_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_expr_stmt_fold(folder, self);
+        folder.fold_expr_stmt(node)
     }
 }
 
@@ -43,19 +56,24 @@ impl Parse for ExprStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let expr = parser.parse::<Expr>(cursor)?;
         let semi = parser.parse::<Option<SemiColon>>(cursor)?;
 
-        Ok(Self { expr, semi })
+        Ok(Self { meta, expr, semi })
     }
 }
 
 impl Spanned for ExprStmt {
     fn span(&self) -> Span {
-        if let Some(semi) = &self.semi {
-            return Span::join(self.expr.span(), semi.span());
-        }
+        let start = match &self.meta {
+            None => self.expr.span(),
+            Some(meta) => meta.span(),
+        };
 
-        self.expr.span()
+        match &self.semi {
+            None => Span::join(start, self.expr.span()),
+            Some(semi) => Span::join(start, semi.span()),
+        }
     }
 }
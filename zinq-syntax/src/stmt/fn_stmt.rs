@@ -2,7 +2,7 @@ use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Comma, Fn, Ident, LParen, Punctuated, RArrow, RParen, Suffixed};
 
 use crate::{
-    Generics, Syntax, Visibility,
+    Generics, Syntax, Visibility, WhereClause,
     meta::Meta,
     param::FnParam,
     stmt::{BlockStmt, Stmt},
@@ -24,12 +24,13 @@ pub struct FnStmt {
     pub params: Punctuated<FnParam, Comma>,
     pub right_paren: RParen,
     pub return_ty: Option<Suffixed<RArrow, Type>>,
+    pub where_clause: Option<WhereClause>,
     pub block: BlockStmt,
 }
 
 impl From<FnStmt> for Stmt {
     fn from(value: FnStmt) -> Self {
-        Self::Fn(value)
+        Self::Fn(Box::new(value))
     }
 }
 
@@ -38,14 +39,21 @@ impl Syntax for FnStmt {
         "Stmt::Fn"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_fn_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_fn_stmt(self)
+            .then(|| crate::stmt::walk_fn_stmt(visitor, self))
+    }
 
-        for param in self.params.iter() {
-            param.value().accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_fn_stmt_mut(self)
+            .then(|| crate::stmt::walk_fn_stmt_mut(visitor, self))
+    }
 
-        self.block.accept(visitor);
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_fn_stmt_fold(folder, self);
+        folder.fold_fn_stmt(node)
     }
 }
 
@@ -81,8 +89,11 @@ impl Parse for FnStmt {
         let generics = parser.parse::<Option<Generics>>(cursor)?;
         let left_paren = parser.parse::<LParen>(cursor)?;
         let params = parser.parse::<Punctuated<FnParam, Comma>>(cursor)?;
+        FnParam::expect_typed(&params)?;
+
         let right_paren = parser.parse::<RParen>(cursor)?;
         let return_ty = parser.parse::<Option<Suffixed<RArrow, Type>>>(cursor)?;
+        let where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
         let block = parser.parse::<BlockStmt>(cursor)?;
 
         Ok(Self {
@@ -95,6 +106,7 @@ impl Parse for FnStmt {
             params,
             right_paren,
             return_ty,
+            where_clause,
             block,
         })
     }
@@ -183,4 +195,29 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_require_param_types() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"fn stuff(a: string, b) { }").cursor();
+
+        debug_assert!(parser.parse::<FnStmt>(&mut cursor).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_with_where() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor =
+            Span::from_bytes(b"fn print<T>(value: T) -> string where T: Display + Debug { }")
+                .cursor();
+        let ty = parser.parse::<FnStmt>(&mut cursor)?;
+        let where_clause = ty.where_clause.as_ref().unwrap();
+
+        debug_assert_eq!(where_clause.to_string(), "where T: Display + Debug");
+        debug_assert_eq!(ty.return_ty.unwrap().suffix.to_string(), "string");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,449 @@
+use zinq_token::Suffixed;
+
+use crate::Syntax;
+
+use super::*;
+
+pub trait StmtFold {
+    fn fold_stmt(&mut self, node: Stmt) -> Stmt {
+        node
+    }
+
+    fn fold_impl_stmt(&mut self, node: ImplStmt) -> ImplStmt {
+        node
+    }
+
+    fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
+        node
+    }
+
+    fn fold_const_stmt(&mut self, node: ConstStmt) -> ConstStmt {
+        node
+    }
+
+    fn fold_enum_stmt(&mut self, node: EnumStmt) -> EnumStmt {
+        node
+    }
+
+    fn fold_expr_stmt(&mut self, node: ExprStmt) -> ExprStmt {
+        node
+    }
+
+    fn fold_fn_stmt(&mut self, node: FnStmt) -> FnStmt {
+        node
+    }
+
+    fn fold_for_stmt(&mut self, node: ForStmt) -> ForStmt {
+        node
+    }
+
+    fn fold_if_stmt(&mut self, node: IfStmt) -> IfStmt {
+        node
+    }
+
+    fn fold_let_stmt(&mut self, node: LetStmt) -> LetStmt {
+        node
+    }
+
+    fn fold_mod_stmt(&mut self, node: ModStmt) -> ModStmt {
+        node
+    }
+
+    fn fold_protocol_stmt(&mut self, node: ProtocolStmt) -> ProtocolStmt {
+        node
+    }
+
+    fn fold_return_stmt(&mut self, node: ReturnStmt) -> ReturnStmt {
+        node
+    }
+
+    fn fold_struct_stmt(&mut self, node: StructStmt) -> StructStmt {
+        node
+    }
+
+    fn fold_type_alias_stmt(&mut self, node: TypeAliasStmt) -> TypeAliasStmt {
+        node
+    }
+
+    fn fold_use_stmt(&mut self, node: UseStmt) -> UseStmt {
+        node
+    }
+
+    fn fold_while_stmt(&mut self, node: WhileStmt) -> WhileStmt {
+        node
+    }
+
+    fn fold_loop_stmt(&mut self, node: LoopStmt) -> LoopStmt {
+        node
+    }
+
+    fn fold_break_stmt(&mut self, node: BreakStmt) -> BreakStmt {
+        node
+    }
+
+    fn fold_continue_stmt(&mut self, node: ContinueStmt) -> ContinueStmt {
+        node
+    }
+
+    fn fold_macro_stmt(&mut self, node: MacroStmt) -> MacroStmt {
+        node
+    }
+
+    fn fold_macro_call_stmt(&mut self, node: MacroCallStmt) -> MacroCallStmt {
+        node
+    }
+
+    fn fold_impl_method(&mut self, node: ImplMethod) -> ImplMethod {
+        node
+    }
+
+    fn fold_protocol_method(&mut self, node: ProtocolMethod) -> ProtocolMethod {
+        node
+    }
+
+    fn fold_protocol_const(&mut self, node: ProtocolConst) -> ProtocolConst {
+        node
+    }
+
+    fn fold_protocol_type(&mut self, node: ProtocolType) -> ProtocolType {
+        node
+    }
+}
+
+pub fn walk_stmt_fold<F: crate::Fold>(folder: &mut F, node: Stmt) -> Stmt {
+    match node {
+        Stmt::Block(v) => Stmt::Block(v.fold(folder)),
+        Stmt::Expr(v) => Stmt::Expr(v.fold(folder)),
+        Stmt::Let(v) => Stmt::Let(v.fold(folder)),
+        Stmt::Mod(v) => Stmt::Mod(v.fold(folder)),
+        Stmt::Struct(v) => Stmt::Struct(v.fold(folder)),
+        Stmt::Const(v) => Stmt::Const(v.fold(folder)),
+        Stmt::TypeAlias(v) => Stmt::TypeAlias(v.fold(folder)),
+        Stmt::Fn(v) => Stmt::Fn(Box::new((*v).fold(folder))),
+        Stmt::Impl(v) => Stmt::Impl(v.fold(folder)),
+        Stmt::Protocol(v) => Stmt::Protocol(v.fold(folder)),
+        Stmt::Use(v) => Stmt::Use(v.fold(folder)),
+        Stmt::Return(v) => Stmt::Return(v.fold(folder)),
+        Stmt::If(v) => Stmt::If(v.fold(folder)),
+        Stmt::Enum(v) => Stmt::Enum(v.fold(folder)),
+        Stmt::For(v) => Stmt::For(v.fold(folder)),
+        Stmt::While(v) => Stmt::While(v.fold(folder)),
+        Stmt::Loop(v) => Stmt::Loop(v.fold(folder)),
+        Stmt::Break(v) => Stmt::Break(v.fold(folder)),
+        Stmt::Continue(v) => Stmt::Continue(v.fold(folder)),
+        Stmt::Macro(v) => Stmt::Macro(v.fold(folder)),
+        Stmt::MacroCall(v) => Stmt::MacroCall(v.fold(folder)),
+    }
+}
+
+pub fn walk_impl_stmt_fold<F: crate::Fold>(folder: &mut F, node: ImplStmt) -> ImplStmt {
+    ImplStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        protocol: node.protocol.map(|v| Suffixed {
+            inner: crate::walk_path_fold(folder, v.inner),
+            ..v
+        }),
+        for_ty: node.for_ty.fold(folder),
+        where_clause: node.where_clause.map(|v| v.fold(folder)),
+        stmts: node.stmts.into_iter().map(|v| v.fold(folder)).collect(),
+        ..node
+    }
+}
+
+pub fn walk_block_stmt_fold<F: crate::Fold>(folder: &mut F, node: BlockStmt) -> BlockStmt {
+    BlockStmt {
+        attrs: node.attrs.into_iter().map(|v| v.fold(folder)).collect(),
+        stmts: node.stmts.into_iter().map(|v| v.fold(folder)).collect(),
+        tail: node.tail.map(|v| Box::new((*v).fold(folder))),
+        ..node
+    }
+}
+
+pub fn walk_const_stmt_fold<F: crate::Fold>(folder: &mut F, node: ConstStmt) -> ConstStmt {
+    ConstStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ty: Suffixed {
+            suffix: node.ty.suffix.fold(folder),
+            ..node.ty
+        },
+        init: Suffixed {
+            suffix: node.init.suffix.fold(folder),
+            ..node.init
+        },
+        ..node
+    }
+}
+
+pub fn walk_enum_stmt_fold<F: crate::Fold>(folder: &mut F, node: EnumStmt) -> EnumStmt {
+    EnumStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        where_clause: node.where_clause.map(|v| v.fold(folder)),
+        variants: node.variants.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_expr_stmt_fold<F: crate::Fold>(folder: &mut F, node: ExprStmt) -> ExprStmt {
+    ExprStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        expr: node.expr.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_fn_stmt_fold<F: crate::Fold>(folder: &mut F, node: FnStmt) -> FnStmt {
+    FnStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        params: node.params.map(|v| v.fold(folder)),
+        return_ty: node.return_ty.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        where_clause: node.where_clause.map(|v| v.fold(folder)),
+        block: node.block.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_for_stmt_fold<F: crate::Fold>(folder: &mut F, node: ForStmt) -> ForStmt {
+    ForStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        pattern: node.pattern.fold(folder),
+        expr: node.expr.fold(folder),
+        body: node.body.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_if_stmt_fold<F: crate::Fold>(folder: &mut F, node: IfStmt) -> IfStmt {
+    IfStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        binding: node
+            .binding
+            .map(|(keyword, v, eq)| (keyword, v.fold(folder), eq)),
+        cond: node.cond.fold(folder),
+        then_stmt: node.then_stmt.fold(folder),
+        else_stmt: node
+            .else_stmt
+            .map(|(keyword, v)| (keyword, Box::new((*v).fold(folder)))),
+        ..node
+    }
+}
+
+pub fn walk_let_stmt_fold<F: crate::Fold>(folder: &mut F, node: LetStmt) -> LetStmt {
+    LetStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        pattern: node.pattern.fold(folder),
+        ty: node.ty.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        init: node.init.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        else_block: node.else_block.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_mod_stmt_fold<F: crate::Fold>(folder: &mut F, node: ModStmt) -> ModStmt {
+    ModStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        body: node.body.map(|body| ModBody {
+            items: body.items.into_iter().map(|v| v.fold(folder)).collect(),
+            ..body
+        }),
+        ..node
+    }
+}
+
+pub fn walk_protocol_stmt_fold<F: crate::Fold>(folder: &mut F, node: ProtocolStmt) -> ProtocolStmt {
+    ProtocolStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        bounds: node.bounds.map(|v| crate::walk_bounds_fold(folder, v)),
+        where_clause: node.where_clause.map(|v| v.fold(folder)),
+        items: node.items.into_iter().map(|v| v.fold(folder)).collect(),
+        ..node
+    }
+}
+
+pub fn walk_return_stmt_fold<F: crate::Fold>(folder: &mut F, node: ReturnStmt) -> ReturnStmt {
+    ReturnStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        right: node.right.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_struct_stmt_fold<F: crate::Fold>(folder: &mut F, node: StructStmt) -> StructStmt {
+    StructStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        where_clause: node.where_clause.map(|v| v.fold(folder)),
+        fields: node.fields.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_type_alias_stmt_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: TypeAliasStmt,
+) -> TypeAliasStmt {
+    TypeAliasStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        ty: node.ty.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_use_stmt_fold<F: crate::Fold>(folder: &mut F, node: UseStmt) -> UseStmt {
+    UseStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_while_stmt_fold<F: crate::Fold>(folder: &mut F, node: WhileStmt) -> WhileStmt {
+    WhileStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        binding: node
+            .binding
+            .map(|(keyword, v, eq)| (keyword, v.fold(folder), eq)),
+        cond: node.cond.fold(folder),
+        body: node.body.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_loop_stmt_fold<F: crate::Fold>(folder: &mut F, node: LoopStmt) -> LoopStmt {
+    LoopStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        body: node.body.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_break_stmt_fold<F: crate::Fold>(folder: &mut F, node: BreakStmt) -> BreakStmt {
+    BreakStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        value: node.value.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_continue_stmt_fold<F: crate::Fold>(folder: &mut F, node: ContinueStmt) -> ContinueStmt {
+    ContinueStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_macro_stmt_fold<F: crate::Fold>(folder: &mut F, node: MacroStmt) -> MacroStmt {
+    MacroStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_macro_call_stmt_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: MacroCallStmt,
+) -> MacroCallStmt {
+    MacroCallStmt {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_impl_method_fold<F: crate::Fold>(folder: &mut F, node: ImplMethod) -> ImplMethod {
+    ImplMethod {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        self_param: node.self_param.map(|v| v.fold(folder)),
+        params: node.params.map(|v| v.fold(folder)),
+        return_ty: node.return_ty.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        where_clause: node.where_clause.map(|v| v.fold(folder)),
+        block: node.block.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_protocol_method_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: ProtocolMethod,
+) -> ProtocolMethod {
+    ProtocolMethod {
+        meta: node.meta.map(|v| v.fold(folder)),
+        generics: node.generics.map(|v| v.fold(folder)),
+        self_param: node.self_param.map(|v| v.fold(folder)),
+        params: node.params.map(|v| v.fold(folder)),
+        return_ty: node.return_ty.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        where_clause: node.where_clause.map(|v| v.fold(folder)),
+        block: node.block.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_protocol_const_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: ProtocolConst,
+) -> ProtocolConst {
+    ProtocolConst {
+        meta: node.meta.map(|v| v.fold(folder)),
+        ty: Suffixed {
+            suffix: node.ty.suffix.fold(folder),
+            ..node.ty
+        },
+        default: node.default.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_protocol_type_fold<F: crate::Fold>(folder: &mut F, node: ProtocolType) -> ProtocolType {
+    ProtocolType {
+        meta: node.meta.map(|v| v.fold(folder)),
+        bounds: node.bounds.map(|v| crate::walk_bounds_fold(folder, v)),
+        default: node.default.map(|v| Suffixed {
+            suffix: v.suffix.fold(folder),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_impl_syntax_fold<F: crate::Fold>(folder: &mut F, node: ImplSyntax) -> ImplSyntax {
+    match node {
+        ImplSyntax::Method(v) => ImplSyntax::Method(Box::new((*v).fold(folder))),
+        ImplSyntax::Const(v) => ImplSyntax::Const(Box::new((*v).fold(folder))),
+        ImplSyntax::Type(v) => ImplSyntax::Type(Box::new((*v).fold(folder))),
+    }
+}
+
+pub fn walk_protocol_syntax_fold<F: crate::Fold>(
+    folder: &mut F,
+    node: ProtocolSyntax,
+) -> ProtocolSyntax {
+    match node {
+        ProtocolSyntax::Method(v) => ProtocolSyntax::Method(Box::new((*v).fold(folder))),
+        ProtocolSyntax::Const(v) => ProtocolSyntax::Const(Box::new((*v).fold(folder))),
+        ProtocolSyntax::Type(v) => ProtocolSyntax::Type(Box::new((*v).fold(folder))),
+    }
+}
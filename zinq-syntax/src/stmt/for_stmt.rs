@@ -1,15 +1,18 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{For, In};
+use zinq_token::{Colon, For, In, Suffixed};
 
 use crate::{
-    Syntax,
-    expr::Expr,
+    Label, Syntax,
+    expr::{Expr, ExprParser},
+    meta::Meta,
     pat::Pattern,
     stmt::{BlockStmt, Stmt},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ForStmt {
+    pub meta: Option<Meta>,
+    pub label: Option<Suffixed<Label, Colon>>,
     pub keyword: For,
     pub pattern: Pattern,
     pub in_keyword: In,
@@ -28,11 +31,21 @@ impl Syntax for ForStmt {
         "Stmt::For"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_for_stmt(self);
-        self.pattern.accept(visitor);
-        self.expr.accept(visitor);
-        self.body.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_for_stmt(self)
+            .then(|| crate::stmt::walk_for_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_for_stmt_mut(self)
+            .then(|| crate::stmt::walk_for_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_for_stmt_fold(folder, self);
+        folder.fold_for_stmt(node)
     }
 }
 
@@ -47,7 +60,12 @@ impl Peek for ForStmt {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<For>(cursor).unwrap_or(false))
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Option<Suffixed<Label, Colon>>>(&mut fork)?;
+        Ok(fork_parser.peek::<For>(&fork).unwrap_or(false))
     }
 }
 
@@ -56,13 +74,17 @@ impl Parse for ForStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let label = parser.parse::<Option<Suffixed<Label, Colon>>>(cursor)?;
         let keyword = parser.parse::<For>(cursor)?;
         let pattern = parser.parse::<Pattern>(cursor)?;
         let in_keyword = parser.parse::<In>(cursor)?;
-        let expr = parser.parse::<Expr>(cursor)?;
+        let expr = parser.parse_cond_expr(cursor)?;
         let body = parser.parse::<BlockStmt>(cursor)?;
 
         Ok(Self {
+            meta,
+            label,
             keyword,
             pattern,
             in_keyword,
@@ -74,7 +96,17 @@ impl Parse for ForStmt {
 
 impl Spanned for ForStmt {
     fn span(&self) -> Span {
-        Span::join(self.keyword.span(), self.body.span())
+        let mut start = self.keyword.span();
+
+        if let Some(label) = &self.label {
+            start = label.span();
+        }
+
+        if let Some(meta) = &self.meta {
+            start = meta.span();
+        }
+
+        Span::join(start, self.body.span())
     }
 }
 
@@ -130,4 +162,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_labeled() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"rows: for row in rows { continue rows; }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert_eq!(
+            stmt.as_for().label.as_ref().unwrap().name.to_string(),
+            "rows"
+        );
+        debug_assert_eq!(stmt.to_string(), "rows: for row in rows { continue rows; }");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_parse_struct_in_head() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"for p in points { p }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.as_for().expr.is_path());
+        debug_assert_eq!(stmt.as_for().body.to_string(), "{ p }");
+
+        Ok(())
+    }
 }
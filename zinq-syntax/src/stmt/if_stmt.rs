@@ -1,20 +1,38 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Else, If};
+use zinq_token::{Else, Eq, If, Let};
 
 use crate::{
     Syntax,
-    expr::Expr,
+    expr::{Expr, ExprParser},
+    meta::Meta,
+    pat::Pattern,
     stmt::{BlockStmt, Stmt, StmtParser},
 };
 
+///
+/// ## If Statement
+/// ```
+/// if i < 10 { ... } else { ... }
+/// if let Some(item) = find(id) { ... }
+/// if value is Some(item) { ... }
+/// ```
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IfStmt {
+    pub meta: Option<Meta>,
     pub keyword: If,
+    pub binding: Option<(Let, Pattern, Eq)>,
     pub cond: Expr,
     pub then_stmt: BlockStmt,
     pub else_stmt: Option<(Else, Box<Stmt>)>,
 }
 
+impl IfStmt {
+    pub fn is_let(&self) -> bool {
+        self.binding.is_some()
+    }
+}
+
 impl From<IfStmt> for Stmt {
     fn from(value: IfStmt) -> Self {
         Self::If(value)
@@ -26,14 +44,21 @@ impl Syntax for IfStmt {
         "Stmt::If"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_if_stmt(self);
-        self.cond.accept(visitor);
-        self.then_stmt.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_if_stmt(self)
+            .then(|| crate::stmt::walk_if_stmt(visitor, self))
+    }
 
-        if let Some(else_stmt) = &self.else_stmt {
-            else_stmt.1.accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_if_stmt_mut(self)
+            .then(|| crate::stmt::walk_if_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_if_stmt_fold(folder, self);
+        folder.fold_if_stmt(node)
     }
 }
 
@@ -48,7 +73,11 @@ impl Peek for IfStmt {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<If>(cursor).unwrap_or(false))
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<If>(&fork).unwrap_or(false))
     }
 }
 
@@ -57,8 +86,18 @@ impl Parse for IfStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let keyword = parser.parse::<If>(cursor)?;
-        let cond = parser.parse::<Expr>(cursor)?;
+        let mut binding = None;
+
+        if parser.peek::<Let>(cursor).unwrap_or(false) {
+            let let_keyword = parser.parse::<Let>(cursor)?;
+            let pattern = parser.parse::<Pattern>(cursor)?;
+            let eq = parser.parse::<Eq>(cursor)?;
+            binding = Some((let_keyword, pattern, eq));
+        }
+
+        let cond = parser.parse_cond_expr(cursor)?;
         let then_stmt = parser.parse::<BlockStmt>(cursor)?;
 
         if parser.peek::<Else>(cursor).unwrap_or(false) {
@@ -66,7 +105,9 @@ impl Parse for IfStmt {
             let else_stmt = parser.parse_stmt(cursor)?;
 
             return Ok(Self {
+                meta,
                 keyword,
+                binding,
                 cond,
                 then_stmt,
                 else_stmt: Some((else_token, Box::new(else_stmt))),
@@ -74,7 +115,9 @@ impl Parse for IfStmt {
         }
 
         Ok(Self {
+            meta,
             keyword,
+            binding,
             cond,
             then_stmt,
             else_stmt: None,
@@ -84,11 +127,15 @@ impl Parse for IfStmt {
 
 impl Spanned for IfStmt {
     fn span(&self) -> Span {
-        if let Some((_, stmt)) = &self.else_stmt {
-            return Span::join(self.keyword.span(), stmt.span());
-        }
+        let start = match &self.meta {
+            None => self.keyword.span(),
+            Some(meta) => meta.span(),
+        };
 
-        Span::join(self.keyword.span(), self.then_stmt.span())
+        match &self.else_stmt {
+            None => Span::join(start, self.then_stmt.span()),
+            Some((_, stmt)) => Span::join(start, stmt.span()),
+        }
     }
 }
 
@@ -167,4 +214,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_let() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor =
+            Span::from_bytes(b"if let Some(v) = find(id) { return v; } else { return 0; }")
+                .cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+        let (_, pattern, _) = stmt.as_if().binding.as_ref().unwrap();
+
+        debug_assert!(stmt.as_if().is_let());
+        debug_assert!(pattern.is_tuple_struct());
+        debug_assert_eq!(stmt.as_if().cond.to_string(), "find(id)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_is_binding() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"if value is Some(v) { return v; }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+        let is = stmt.as_if().cond.as_is();
+
+        debug_assert!(is.target.is_pattern());
+        debug_assert_eq!(is.target.to_string(), "Some(v)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_parse_struct_in_head() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"if ok { x } else { y }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.as_if().cond.is_path());
+        debug_assert!(stmt.as_if().then_stmt.has_tail());
+
+        let mut cursor =
+            Span::from_bytes(b"if p == (Point { x }) && valid(Point { x }) { x }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.as_if().cond.is_logical());
+        debug_assert_eq!(
+            stmt.as_if().cond.to_string(),
+            "p == (Point { x }) && valid(Point { x })"
+        );
+
+        let mut cursor = Span::from_bytes(b"if xs.any(|p| p == Point { x }) { x }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.as_if().cond.is_call());
+        debug_assert!(stmt.as_if().then_stmt.has_tail());
+
+        Ok(())
+    }
 }
@@ -2,20 +2,33 @@ mod syntax;
 
 pub use syntax::*;
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Impl, LBrace, RBrace};
+use zinq_token::{For, Impl, LBrace, RBrace, Suffixed};
 
-use crate::{Generics, Syntax, stmt::Stmt, ty::Type};
+use crate::{Generics, Path, Syntax, WhereClause, meta::Meta, stmt::Stmt, ty::Type};
 
+///
+/// ## Impl Statement
+/// `impl <type> { ... }` or `impl <protocol> for <type> { ... }`
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ImplStmt {
+    pub meta: Option<Meta>,
     pub keyword: Impl,
     pub generics: Option<Generics>,
+    pub protocol: Option<Suffixed<Path, For>>,
     pub for_ty: Type,
+    pub where_clause: Option<WhereClause>,
     pub left_brace: LBrace,
     pub stmts: Vec<ImplSyntax>,
     pub right_brace: RBrace,
 }
 
+impl ImplStmt {
+    pub fn is_protocol(&self) -> bool {
+        self.protocol.is_some()
+    }
+}
+
 impl From<ImplStmt> for Stmt {
     fn from(value: ImplStmt) -> Self {
         Self::Impl(value)
@@ -27,9 +40,21 @@ impl Syntax for ImplStmt {
         "Stmt::Impl"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_impl_stmt(self);
-        self.for_ty.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_impl_stmt(self)
+            .then(|| crate::stmt::walk_impl_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_impl_stmt_mut(self)
+            .then(|| crate::stmt::walk_impl_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_impl_stmt_fold(folder, self);
+        folder.fold_impl_stmt(node)
     }
 }
 
@@ -44,7 +69,11 @@ impl Peek for ImplStmt {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<Impl>(cursor).unwrap_or(false))
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Impl>(&fork).unwrap_or(false))
     }
 }
 
@@ -53,9 +82,12 @@ impl Parse for ImplStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let keyword = parser.parse::<Impl>(cursor)?;
         let generics = parser.parse::<Option<Generics>>(cursor)?;
+        let protocol = parser.parse::<Option<Suffixed<Path, For>>>(cursor)?;
         let for_ty = parser.parse::<Type>(cursor)?;
+        let where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
         let left_brace = parser.parse::<LBrace>(cursor)?;
         let mut stmts = vec![];
 
@@ -66,9 +98,12 @@ impl Parse for ImplStmt {
         let right_brace = parser.parse::<RBrace>(cursor)?;
 
         Ok(Self {
+            meta,
             keyword,
             generics,
+            protocol,
             for_ty,
+            where_clause,
             left_brace,
             stmts,
             right_brace,
@@ -78,9 +113,13 @@ impl Parse for ImplStmt {
 
 impl Spanned for ImplStmt {
     fn span(&self) -> Span {
-        Span::join(self.keyword.span(), self.right_brace.span())
+        match &self.meta {
+            None => Span::join(self.keyword.span(), self.right_brace.span()),
+            Some(meta) => Span::join(meta.span(), self.right_brace.span()),
+        }
     }
 }
+
 #[cfg(test)]
 mod tests {
     use zinq_error::Result;
@@ -117,4 +156,63 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_protocol_impl() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"impl<T> fmt::Display for Wrapper::<T> {
+                fn fmt(&self, f: &mut Formatter) -> Result { }
+            }",
+        )
+        .cursor();
+
+        let stmt = parser.parse::<ImplStmt>(&mut cursor)?;
+
+        debug_assert!(stmt.is_protocol());
+        debug_assert!(stmt.generics.is_some());
+        debug_assert_eq!(
+            stmt.protocol.as_ref().unwrap().inner.to_string(),
+            "fmt::Display"
+        );
+        debug_assert_eq!(stmt.for_ty.to_string(), "Wrapper::<T>");
+        debug_assert_eq!(stmt.stmts.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_inherent() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"impl Formatter {}").cursor();
+        let stmt = parser.parse::<ImplStmt>(&mut cursor)?;
+
+        debug_assert!(!stmt.is_protocol());
+        debug_assert_eq!(stmt.for_ty.to_string(), "Formatter");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_associated_items() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"impl Iterator for Range {
+                type Item = u32;
+                const STEP: u32 = 1;
+
+                fn next(&mut self) -> u32 { self.start }
+            }",
+        )
+        .cursor();
+
+        let stmt = parser.parse::<ImplStmt>(&mut cursor)?;
+
+        debug_assert_eq!(stmt.stmts.len(), 3);
+        debug_assert!(stmt.stmts[0].is_type());
+        debug_assert!(stmt.stmts[1].is_const());
+        debug_assert!(stmt.stmts[2].is_method());
+
+        Ok(())
+    }
 }
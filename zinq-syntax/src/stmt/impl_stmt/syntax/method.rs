@@ -2,7 +2,7 @@ use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Comma, Fn, Ident, LParen, Punctuated, RArrow, RParen, Suffixed};
 
 use crate::{
-    Generics, Syntax, Visibility,
+    Generics, Syntax, Visibility, WhereClause,
     meta::Meta,
     param::{FnParam, SelfParam},
     stmt::{BlockStmt, ImplSyntax},
@@ -25,12 +25,13 @@ pub struct ImplMethod {
     pub params: Punctuated<FnParam, Comma>,
     pub right_paren: RParen,
     pub return_ty: Option<Suffixed<RArrow, Type>>,
+    pub where_clause: Option<WhereClause>,
     pub block: BlockStmt,
 }
 
 impl From<ImplMethod> for ImplSyntax {
     fn from(value: ImplMethod) -> Self {
-        Self::Method(value)
+        Self::Method(Box::new(value))
     }
 }
 
@@ -39,16 +40,21 @@ impl Syntax for ImplMethod {
         "Stmt::Impl::Method"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        for item in self.params.iter() {
-            item.value().ty.accept(visitor);
-        }
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_impl_method(self)
+            .then(|| crate::stmt::walk_impl_method(visitor, self))
+    }
 
-        if let Some(ty) = &self.return_ty {
-            ty.suffix.accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_impl_method_mut(self)
+            .then(|| crate::stmt::walk_impl_method_mut(visitor, self))
+    }
 
-        self.block.accept(visitor);
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_impl_method_fold(folder, self);
+        folder.fold_impl_method(node)
     }
 }
 
@@ -90,8 +96,11 @@ impl Parse for ImplMethod {
         }
 
         let params = parser.parse::<Punctuated<FnParam, Comma>>(cursor)?;
+        FnParam::expect_typed(&params)?;
+
         let right_paren = parser.parse::<RParen>(cursor)?;
         let return_ty = parser.parse::<Option<Suffixed<RArrow, Type>>>(cursor)?;
+        let where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
         let block = parser.parse::<BlockStmt>(cursor)?;
 
         Ok(Self {
@@ -105,6 +114,7 @@ impl Parse for ImplMethod {
             params,
             right_paren,
             return_ty,
+            where_clause,
             block,
         })
     }
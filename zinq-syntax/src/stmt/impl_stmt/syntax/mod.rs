@@ -3,23 +3,72 @@ mod method;
 pub use method::*;
 use zinq_parse::{Parse, Peek, Spanned};
 
+use crate::{
+    Syntax,
+    stmt::{ConstStmt, TypeAliasStmt},
+};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ImplSyntax {
-    Method(ImplMethod),
+    Method(Box<ImplMethod>),
+    Const(Box<ConstStmt>),
+    Type(Box<TypeAliasStmt>),
 }
 
 impl ImplSyntax {
     pub fn is_method(&self) -> bool {
+        matches!(self, Self::Method(_))
+    }
+
+    pub fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+
+    pub fn is_type(&self) -> bool {
+        matches!(self, Self::Type(_))
+    }
+}
+
+impl From<ConstStmt> for ImplSyntax {
+    fn from(value: ConstStmt) -> Self {
+        Self::Const(Box::new(value))
+    }
+}
+
+impl From<TypeAliasStmt> for ImplSyntax {
+    fn from(value: TypeAliasStmt) -> Self {
+        Self::Type(Box::new(value))
+    }
+}
+
+impl Syntax for ImplSyntax {
+    fn name(&self) -> &str {
         match self {
-            Self::Method(_) => true,
+            Self::Method(v) => v.name(),
+            Self::Const(v) => v.name(),
+            Self::Type(v) => v.name(),
         }
     }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        crate::stmt::walk_impl_syntax(visitor, self)
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        crate::stmt::walk_impl_syntax_mut(visitor, self)
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        crate::stmt::walk_impl_syntax_fold(folder, self)
+    }
 }
 
 impl std::fmt::Display for ImplSyntax {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Method(v) => write!(f, "{}", v),
+            Self::Const(v) => write!(f, "{}", v),
+            Self::Type(v) => write!(f, "{}", v),
         }
     }
 }
@@ -48,6 +97,14 @@ impl Parse for ImplSyntax {
             return Ok(parser.parse::<ImplMethod>(cursor)?.into());
         }
 
+        if parser.peek::<ConstStmt>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<ConstStmt>(cursor)?.into());
+        }
+
+        if parser.peek::<TypeAliasStmt>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<TypeAliasStmt>(cursor)?.into());
+        }
+
         Err(cursor.error(
             zinq_error::NOT_FOUND,
             &format!("unexpected token '{}'", *cursor.peek()? as char),
@@ -59,6 +116,8 @@ impl Spanned for ImplSyntax {
     fn span(&self) -> zinq_parse::Span {
         match self {
             Self::Method(v) => v.span(),
+            Self::Const(v) => v.span(),
+            Self::Type(v) => v.span(),
         }
     }
 }
@@ -1,21 +1,52 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Colon, Eq, Ident, Let, SemiColon, Suffixed};
+use zinq_token::{Colon, Else, Eq, Let, SemiColon, Suffixed};
 
-use crate::{Syntax, expr::Expr, stmt::Stmt, ty::Type};
+use crate::{
+    Syntax,
+    expr::Expr,
+    meta::Meta,
+    pat::Pattern,
+    stmt::{BlockStmt, Stmt},
+    ty::Type,
+};
 
 ///
 /// ## Let Statement
-/// `let <var>: <type> = <init>;`
+/// `let <pattern>: <type> = <init>;`
+///
+/// ```
+/// let (a, b) = pair;
+/// let Point { x, y } = p;
+/// let Some(v) = opt else { return; };
+/// ```
+///
+/// a pattern that is refutable by its syntax alone,
+/// see `Pattern::is_refutable`, needs an `else` block,
+/// and an initializer before `else` can't end in `}`
+///
+/// ```text
+/// let 0 = n;                              // error
+/// let x = if a { 1 } else { 2 } else {};  // error
+/// let x = (if a { 1 } else { 2 }) else {};
+/// ```
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LetStmt {
+    pub meta: Option<Meta>,
     pub keyword: Let,
-    pub name: Ident,
+    pub pattern: Pattern,
     pub ty: Option<Suffixed<Colon, Type>>,
     pub init: Option<Suffixed<Eq, Expr>>,
+    pub else_block: Option<Suffixed<Else, BlockStmt>>,
     pub semi: SemiColon,
 }
 
+impl LetStmt {
+    pub fn is_else(&self) -> bool {
+        self.else_block.is_some()
+    }
+}
+
 impl From<LetStmt> for Stmt {
     fn from(value: LetStmt) -> Self {
         Self::Let(value)
@@ -27,16 +58,21 @@ impl Syntax for LetStmt {
         "Stmt::Let"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_let_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_let_stmt(self)
+            .then(|| crate::stmt::walk_let_stmt(visitor, self))
+    }
 
-        if let Some(ty) = &self.ty {
-            ty.suffix.accept(visitor);
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_let_stmt_mut(self)
+            .then(|| crate::stmt::walk_let_stmt_mut(visitor, self))
+    }
 
-        if let Some(init) = &self.init {
-            init.suffix.accept(visitor);
-        }
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_let_stmt_fold(folder, self);
+        folder.fold_let_stmt(node)
     }
 }
 
@@ -51,7 +87,11 @@ impl Peek for LetStmt {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<Let>(cursor).unwrap_or(false))
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Let>(&fork).unwrap_or(false))
     }
 }
 
@@ -60,17 +100,41 @@ impl Parse for LetStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let keyword = parser.parse::<Let>(cursor)?;
-        let name = parser.parse::<Ident>(cursor)?;
+        let pattern = parser.parse::<Pattern>(cursor)?;
         let ty = parser.parse::<Option<Suffixed<Colon, Type>>>(cursor)?;
         let init = parser.parse::<Option<Suffixed<Eq, Expr>>>(cursor)?;
+        let else_block = match init.is_some() {
+            true => parser.parse::<Option<Suffixed<Else, BlockStmt>>>(cursor)?,
+            false => None,
+        };
+
+        if let (Some(init), Some(_)) = (&init, &else_block)
+            && init.suffix.span().bytes().last() == Some(&b'}')
+        {
+            return Err(cursor.error(
+                zinq_error::BAD_ARGUMENTS,
+                "an initializer can't end in '}' before 'else', wrap it in '(...)'",
+            ));
+        }
+
+        if else_block.is_none() && pattern.is_refutable() {
+            return Err(cursor.error(
+                zinq_error::BAD_ARGUMENTS,
+                &format!("refutable pattern '{}' needs an 'else' block", pattern),
+            ));
+        }
+
         let semi = parser.parse::<SemiColon>(cursor)?;
 
         Ok(Self {
+            meta,
             keyword,
-            name,
+            pattern,
             ty,
             init,
+            else_block,
             semi,
         })
     }
@@ -78,7 +142,10 @@ impl Parse for LetStmt {
 
 impl Spanned for LetStmt {
     fn span(&self) -> Span {
-        Span::join(self.keyword.span(), self.semi.span())
+        match &self.meta {
+            None => Span::join(self.keyword.span(), self.semi.span()),
+            Some(meta) => Span::join(meta.span(), self.semi.span()),
+        }
     }
 }
 
@@ -121,4 +188,91 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_mut() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"let mut a = 1;").cursor();
+        let stmt = parser.parse::<LetStmt>(&mut cursor)?;
+
+        debug_assert!(stmt.pattern.is_ident());
+        debug_assert_eq!(stmt.pattern.to_string(), "mut a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_tuple_pattern() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"let (a, b) = pair;").cursor();
+        let stmt = parser.parse::<LetStmt>(&mut cursor)?;
+
+        debug_assert_eq!(stmt.pattern.as_tuple().items.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_struct_pattern() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"let Point { x, y } = p;").cursor();
+        let stmt = parser.parse::<LetStmt>(&mut cursor)?;
+
+        debug_assert_eq!(stmt.pattern.as_struct().fields.len(), 2);
+        debug_assert!(!stmt.is_else());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_else() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"let Some(v) = opt else { return; };").cursor();
+        let stmt = parser.parse::<LetStmt>(&mut cursor)?;
+
+        debug_assert!(stmt.pattern.is_tuple_struct());
+        debug_assert!(stmt.is_else());
+        debug_assert_eq!(stmt.to_string(), "let Some(v) = opt else { return; };");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_parse_brace_before_else() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor =
+            Span::from_bytes(b"let x = if a { 1 } else { 2 } else { return; };").cursor();
+
+        debug_assert!(parser.parse::<LetStmt>(&mut cursor).is_err());
+
+        let mut cursor = Span::from_bytes(b"let Some(v) = Point { x } else { return; };").cursor();
+
+        debug_assert!(parser.parse::<LetStmt>(&mut cursor).is_err());
+
+        let mut cursor =
+            Span::from_bytes(b"let Some(v) = (if a { b } else { c }) else { return; };").cursor();
+
+        debug_assert!(parser.parse::<LetStmt>(&mut cursor)?.is_else());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_parse_refutable_without_else() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+
+        for src in ["let 0 = n;", "let (a, 1..=5) = t;", "let &-1 = r;"] {
+            let mut cursor = Span::from_str(src).cursor();
+
+            debug_assert!(parser.parse::<LetStmt>(&mut cursor).is_err(), "{}", src);
+        }
+
+        for src in ["let 0 = n else { return; };", "let 0 | _ = n;"] {
+            let mut cursor = Span::from_str(src).cursor();
+
+            debug_assert!(parser.parse::<LetStmt>(&mut cursor).is_ok(), "{}", src);
+        }
+
+        Ok(())
+    }
 }
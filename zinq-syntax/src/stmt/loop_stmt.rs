@@ -0,0 +1,140 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Colon, Loop, Suffixed};
+
+use crate::{
+    Label, Syntax,
+    meta::Meta,
+    stmt::{BlockStmt, Stmt},
+};
+
+///
+/// ## Loop Statement
+/// ```
+/// 'outer: loop {
+///     ...
+/// }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LoopStmt {
+    pub meta: Option<Meta>,
+    pub label: Option<Suffixed<Label, Colon>>,
+    pub keyword: Loop,
+    pub body: BlockStmt,
+}
+
+impl From<LoopStmt> for Stmt {
+    fn from(value: LoopStmt) -> Self {
+        Self::Loop(value)
+    }
+}
+
+impl Syntax for LoopStmt {
+    fn name(&self) -> &str {
+        "Stmt::Loop"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_loop_stmt(self)
+            .then(|| crate::stmt::walk_loop_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_loop_stmt_mut(self)
+            .then(|| crate::stmt::walk_loop_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_loop_stmt_fold(folder, self);
+        folder.fold_loop_stmt(node)
+    }
+}
+
+impl std::fmt::Display for LoopStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for LoopStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Option<Suffixed<Label, Colon>>>(&mut fork)?;
+        Ok(fork_parser.peek::<Loop>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for LoopStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let label = parser.parse::<Option<Suffixed<Label, Colon>>>(cursor)?;
+        let keyword = parser.parse::<Loop>(cursor)?;
+        let body = parser.parse::<BlockStmt>(cursor)?;
+
+        Ok(Self {
+            meta,
+            label,
+            keyword,
+            body,
+        })
+    }
+}
+
+impl Spanned for LoopStmt {
+    fn span(&self) -> Span {
+        let mut start = self.keyword.span();
+
+        if let Some(label) = &self.label {
+            start = label.span();
+        }
+
+        if let Some(meta) = &self.meta {
+            start = meta.span();
+        }
+
+        Span::join(start, self.body.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::StmtParser;
+
+    #[test]
+    fn should_parse_labeled() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"'outer: loop {
+                loop {
+                    continue 'outer;
+                }
+            }",
+        )
+        .cursor();
+
+        let stmt = parser.parse_stmt(&mut cursor)?;
+        let label = stmt.as_loop().label.as_ref().unwrap();
+        let inner = &stmt.as_loop().body.stmts[0];
+
+        debug_assert!(label.is_ticked());
+        debug_assert_eq!(label.to_string(), "'outer:");
+        debug_assert!(inner.as_loop().label.is_none());
+        debug_assert!(inner.as_loop().body.stmts[0].is_continue());
+
+        Ok(())
+    }
+}
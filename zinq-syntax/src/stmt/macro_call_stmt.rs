@@ -0,0 +1,167 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{RBrace, SemiColon};
+
+use crate::{Syntax, macros::MacroCall, meta::Meta, stmt::Stmt};
+
+///
+/// ## Macro Call Statement
+/// a macro invoked in item or statement position,
+/// it expands to any number of statements
+///
+/// ```
+/// log!("started");
+/// routes! { get "/" => index }
+/// ```
+///
+/// a call followed by anything but `;` or the end
+/// of the block is an expression like `m!(a) + 1`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroCallStmt {
+    pub meta: Option<Meta>,
+    pub call: MacroCall,
+    pub semi: Option<SemiColon>,
+}
+
+impl From<MacroCallStmt> for Stmt {
+    fn from(value: MacroCallStmt) -> Self {
+        Self::MacroCall(value)
+    }
+}
+
+impl Syntax for MacroCallStmt {
+    fn name(&self) -> &str {
+        "Stmt::MacroCall"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_call_stmt(self)
+            .then(|| crate::stmt::walk_macro_call_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_call_stmt_mut(self)
+            .then(|| crate::stmt::walk_macro_call_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_macro_call_stmt_fold(folder, self);
+        folder.fold_macro_call_stmt(node)
+    }
+}
+
+impl std::fmt::Display for MacroCallStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for MacroCallStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+
+        if !fork_parser.peek::<MacroCall>(&fork).unwrap_or(false) {
+            return Ok(false);
+        }
+
+        let call = fork_parser.parse::<MacroCall>(&mut fork)?;
+
+        Ok(call.is_braced()
+            || fork_parser.peek::<SemiColon>(&fork).unwrap_or(false)
+            || fork_parser.peek::<RBrace>(&fork).unwrap_or(false)
+            || fork.next_while(|b, _| b.is_ascii_whitespace())?.eof())
+    }
+}
+
+impl Parse for MacroCallStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let call = parser.parse::<MacroCall>(cursor)?;
+        let semi = parser.parse::<Option<SemiColon>>(cursor)?;
+
+        Ok(Self { meta, call, semi })
+    }
+}
+
+impl Spanned for MacroCallStmt {
+    fn span(&self) -> Span {
+        let start = match &self.meta {
+            None => self.call.span(),
+            Some(meta) => meta.span(),
+        };
+
+        match &self.semi {
+            None => Span::join(start, self.call.span()),
+            Some(semi) => Span::join(start, semi.span()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::{BlockStmt, Stmt};
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"log!(\"a\", 1);").cursor();
+        let stmt = parser.parse::<Stmt>(&mut cursor)?;
+
+        debug_assert!(stmt.is_macro_call());
+        debug_assert!(stmt.is_item());
+        debug_assert_eq!(stmt.as_macro_call().call.name(), "log");
+        debug_assert_eq!(stmt.to_string(), "log!(\"a\", 1);");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_braced() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"{ routes! { a => b } let x = 1; }").cursor();
+        let block = parser.parse::<BlockStmt>(&mut cursor)?;
+
+        debug_assert_eq!(block.stmts.len(), 2);
+        debug_assert!(block.stmts[0].is_macro_call());
+        debug_assert!(block.stmts[0].as_macro_call().call.is_braced());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_expr_in_stmt_position() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"{ m!(a) + 1; m![b] }").cursor();
+        let block = parser.parse::<BlockStmt>(&mut cursor)?;
+
+        debug_assert!(block.stmts[0].is_expr());
+        debug_assert!(block.stmts[0].as_expr().expr.is_arithmetic());
+        debug_assert!(block.tail.as_ref().is_some_and(|tail| tail.is_macro()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_parse_compare() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a != (b);").cursor();
+
+        debug_assert!(parser.parse::<Stmt>(&mut cursor)?.as_expr().expr.is_cmp());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{EqArrow, Group, Ident, LBrace, Macro, RBrace, SemiColon, Suffixed};
+
+use crate::{Syntax, Visibility, meta::Meta, stmt::Stmt};
+
+///
+/// ## Macro Statement
+/// a declarative macro, each rule matches the
+/// call arguments against its pattern and
+/// expands to its body
+///
+/// ```
+/// macro max {
+///     ($a:expr) => { $a };
+///     ($a:expr, $($rest:expr),+) => {
+///         { let b = max!($($rest),+); if $a > b { $a } else { b } }
+///     };
+/// }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroStmt {
+    pub meta: Option<Meta>,
+    pub vis: Visibility,
+    pub keyword: Macro,
+    pub name: Ident,
+    pub left_brace: LBrace,
+    pub rules: Vec<MacroRule>,
+    pub right_brace: RBrace,
+}
+
+impl From<MacroStmt> for Stmt {
+    fn from(value: MacroStmt) -> Self {
+        Self::Macro(value)
+    }
+}
+
+impl Syntax for MacroStmt {
+    fn name(&self) -> &str {
+        "Stmt::Macro"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_stmt(self)
+            .then(|| crate::stmt::walk_macro_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_stmt_mut(self)
+            .then(|| crate::stmt::walk_macro_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_macro_stmt_fold(folder, self);
+        folder.fold_macro_stmt(node)
+    }
+}
+
+impl std::fmt::Display for MacroStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for MacroStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Visibility>(&mut fork)?;
+
+        // `macro` is contextual, so it has to be followed by `name {`
+        Ok(fork_parser
+            .peek::<Suffixed<Macro, Suffixed<Ident, LBrace>>>(&fork)
+            .unwrap_or(false))
+    }
+}
+
+impl Parse for MacroStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let vis = parser.parse::<Visibility>(cursor)?;
+        let keyword = parser.parse::<Macro>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+        let left_brace = parser.parse::<LBrace>(cursor)?;
+        let mut rules = vec![];
+
+        while !parser.peek::<RBrace>(cursor).unwrap_or(false) {
+            rules.push(parser.parse::<MacroRule>(cursor)?);
+        }
+
+        let right_brace = parser.parse::<RBrace>(cursor)?;
+
+        Ok(Self {
+            meta,
+            vis,
+            keyword,
+            name,
+            left_brace,
+            rules,
+            right_brace,
+        })
+    }
+}
+
+impl Spanned for MacroStmt {
+    fn span(&self) -> Span {
+        match &self.meta {
+            None => Span::join(self.vis.span(), self.right_brace.span()),
+            Some(meta) => Span::join(meta.span(), self.right_brace.span()),
+        }
+    }
+}
+
+///
+/// ## Macro Rule
+/// `(pattern) => { expansion }`, rules are
+/// separated by an optional `;`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroRule {
+    pub pattern: Group,
+    pub arrow: EqArrow,
+    pub body: Group,
+    pub semi: Option<SemiColon>,
+}
+
+impl std::fmt::Display for MacroRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for MacroRule {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Group>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for MacroRule {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let pattern = parser.parse::<Group>(cursor)?;
+        let arrow = parser.parse::<EqArrow>(cursor)?;
+        let body = parser.parse::<Group>(cursor)?;
+        let semi = parser.parse::<Option<SemiColon>>(cursor)?;
+
+        Ok(Self {
+            pattern,
+            arrow,
+            body,
+            semi,
+        })
+    }
+}
+
+impl Spanned for MacroRule {
+    fn span(&self) -> Span {
+        match &self.semi {
+            None => Span::join(self.pattern.span(), self.body.span()),
+            Some(semi) => Span::join(self.pattern.span(), semi.span()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::{MacroStmt, Stmt};
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"pub macro square {
+    ($x:expr) => { $x * $x };
+    () => { 0 }
+}",
+        )
+        .cursor();
+        let stmt = parser.parse::<Stmt>(&mut cursor)?;
+        let value = stmt.as_macro();
+
+        debug_assert!(stmt.is_item());
+        debug_assert_eq!(value.name.to_string(), "square");
+        debug_assert_eq!(value.rules.len(), 2);
+        debug_assert_eq!(value.rules[0].to_string(), "($x:expr) => { $x * $x };");
+        debug_assert_eq!(value.rules[1].body.to_string(), "{ 0 }");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_keep_macro_as_ident() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"macro = 1;").cursor();
+
+        debug_assert!(!parser.peek::<MacroStmt>(&cursor)?);
+        debug_assert!(parser.parse::<Stmt>(&mut cursor)?.is_expr());
+
+        Ok(())
+    }
+}
@@ -1,37 +1,59 @@
 mod block_stmt;
+mod break_stmt;
+mod const_stmt;
+mod continue_stmt;
 mod enum_stmt;
 mod expr_stmt;
 mod fn_stmt;
+mod fold;
 mod for_stmt;
 mod if_stmt;
 mod impl_stmt;
 mod let_stmt;
+mod loop_stmt;
+mod macro_call_stmt;
+mod macro_stmt;
 mod mod_stmt;
 mod parser;
+mod protocol_stmt;
 mod return_stmt;
 mod struct_stmt;
+mod type_alias_stmt;
 mod use_stmt;
 mod visitor;
+mod visitor_mut;
+mod while_stmt;
 
 pub use block_stmt::*;
+pub use break_stmt::*;
+pub use const_stmt::*;
+pub use continue_stmt::*;
 pub use enum_stmt::*;
 pub use expr_stmt::*;
 pub use fn_stmt::*;
+pub use fold::*;
 pub use for_stmt::*;
 pub use if_stmt::*;
 pub use impl_stmt::*;
 pub use let_stmt::*;
+pub use loop_stmt::*;
+pub use macro_call_stmt::*;
+pub use macro_stmt::*;
 pub use mod_stmt::*;
 pub use parser::*;
+pub use protocol_stmt::*;
 pub use return_stmt::*;
 pub use struct_stmt::*;
+pub use type_alias_stmt::*;
 pub use use_stmt::*;
 pub use visitor::*;
+pub use visitor_mut::*;
+pub use while_stmt::*;
 
 use zinq_error::Result;
 use zinq_parse::{Parse, Peek, Spanned};
 
-use crate::Syntax;
+use crate::{Syntax, meta::Meta};
 
 ///
 /// ## Statement
@@ -47,13 +69,22 @@ pub enum Stmt {
     Let(LetStmt),
     Mod(ModStmt),
     Struct(StructStmt),
-    Fn(FnStmt),
+    Const(ConstStmt),
+    TypeAlias(TypeAliasStmt),
+    Fn(Box<FnStmt>),
     Impl(ImplStmt),
+    Protocol(ProtocolStmt),
     Use(UseStmt),
     Return(ReturnStmt),
     If(IfStmt),
     Enum(EnumStmt),
     For(ForStmt),
+    While(WhileStmt),
+    Loop(LoopStmt),
+    Break(BreakStmt),
+    Continue(ContinueStmt),
+    Macro(MacroStmt),
+    MacroCall(MacroCallStmt),
 }
 
 impl Stmt {
@@ -92,6 +123,14 @@ impl Stmt {
         }
     }
 
+    pub fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+
+    pub fn is_type_alias(&self) -> bool {
+        matches!(self, Self::TypeAlias(_))
+    }
+
     pub fn is_fn(&self) -> bool {
         match self {
             Self::Fn(_) => true,
@@ -106,6 +145,10 @@ impl Stmt {
         }
     }
 
+    pub fn is_protocol(&self) -> bool {
+        matches!(self, Self::Protocol(_))
+    }
+
     pub fn is_use(&self) -> bool {
         match self {
             Self::Use(_) => true,
@@ -141,6 +184,113 @@ impl Stmt {
         }
     }
 
+    pub fn is_while(&self) -> bool {
+        matches!(self, Self::While(_))
+    }
+
+    pub fn is_loop(&self) -> bool {
+        matches!(self, Self::Loop(_))
+    }
+
+    pub fn is_break(&self) -> bool {
+        matches!(self, Self::Break(_))
+    }
+
+    pub fn is_continue(&self) -> bool {
+        matches!(self, Self::Continue(_))
+    }
+
+    pub fn is_macro(&self) -> bool {
+        matches!(self, Self::Macro(_))
+    }
+
+    pub fn is_macro_call(&self) -> bool {
+        matches!(self, Self::MacroCall(_))
+    }
+
+    ///
+    /// ## is_item
+    /// can the statement appear at the
+    /// top level of a file
+    ///
+    pub fn is_item(&self) -> bool {
+        matches!(
+            self,
+            Self::Mod(_)
+                | Self::Struct(_)
+                | Self::Const(_)
+                | Self::TypeAlias(_)
+                | Self::Fn(_)
+                | Self::Impl(_)
+                | Self::Protocol(_)
+                | Self::Use(_)
+                | Self::Enum(_)
+                | Self::Macro(_)
+                | Self::MacroCall(_)
+        )
+    }
+
+    ///
+    /// ## meta
+    /// the outer attributes of the statement
+    ///
+    pub fn meta(&self) -> Option<&Meta> {
+        match self {
+            Self::Block(_) => None,
+            Self::Expr(v) => v.meta.as_ref(),
+            Self::Let(v) => v.meta.as_ref(),
+            Self::Mod(v) => v.meta.as_ref(),
+            Self::Struct(v) => v.meta.as_ref(),
+            Self::Const(v) => v.meta.as_ref(),
+            Self::TypeAlias(v) => v.meta.as_ref(),
+            Self::Fn(v) => v.meta.as_ref(),
+            Self::Impl(v) => v.meta.as_ref(),
+            Self::Protocol(v) => v.meta.as_ref(),
+            Self::Use(v) => v.meta.as_ref(),
+            Self::Return(v) => v.meta.as_ref(),
+            Self::If(v) => v.meta.as_ref(),
+            Self::Enum(v) => v.meta.as_ref(),
+            Self::For(v) => v.meta.as_ref(),
+            Self::While(v) => v.meta.as_ref(),
+            Self::Loop(v) => v.meta.as_ref(),
+            Self::Break(v) => v.meta.as_ref(),
+            Self::Continue(v) => v.meta.as_ref(),
+            Self::Macro(v) => v.meta.as_ref(),
+            Self::MacroCall(v) => v.meta.as_ref(),
+        }
+    }
+
+    ///
+    /// ## meta_mut
+    /// `None` for a block, which only has
+    /// inner attributes
+    ///
+    pub fn meta_mut(&mut self) -> Option<&mut Option<Meta>> {
+        match self {
+            Self::Block(_) => None,
+            Self::Expr(v) => Some(&mut v.meta),
+            Self::Let(v) => Some(&mut v.meta),
+            Self::Mod(v) => Some(&mut v.meta),
+            Self::Struct(v) => Some(&mut v.meta),
+            Self::Const(v) => Some(&mut v.meta),
+            Self::TypeAlias(v) => Some(&mut v.meta),
+            Self::Fn(v) => Some(&mut v.meta),
+            Self::Impl(v) => Some(&mut v.meta),
+            Self::Protocol(v) => Some(&mut v.meta),
+            Self::Use(v) => Some(&mut v.meta),
+            Self::Return(v) => Some(&mut v.meta),
+            Self::If(v) => Some(&mut v.meta),
+            Self::Enum(v) => Some(&mut v.meta),
+            Self::For(v) => Some(&mut v.meta),
+            Self::While(v) => Some(&mut v.meta),
+            Self::Loop(v) => Some(&mut v.meta),
+            Self::Break(v) => Some(&mut v.meta),
+            Self::Continue(v) => Some(&mut v.meta),
+            Self::Macro(v) => Some(&mut v.meta),
+            Self::MacroCall(v) => Some(&mut v.meta),
+        }
+    }
+
     pub fn as_block(&self) -> &BlockStmt {
         match self {
             Self::Block(v) => v,
@@ -183,6 +333,20 @@ impl Stmt {
         }
     }
 
+    pub fn as_const(&self) -> &ConstStmt {
+        match self {
+            Self::Const(v) => v,
+            v => panic!("expected ConstStmt, received {}", v.name()),
+        }
+    }
+
+    pub fn as_type_alias(&self) -> &TypeAliasStmt {
+        match self {
+            Self::TypeAlias(v) => v,
+            v => panic!("expected TypeAliasStmt, received {}", v.name()),
+        }
+    }
+
     pub fn as_impl(&self) -> &ImplStmt {
         match self {
             Self::Impl(v) => v,
@@ -190,6 +354,13 @@ impl Stmt {
         }
     }
 
+    pub fn as_protocol(&self) -> &ProtocolStmt {
+        match self {
+            Self::Protocol(v) => v,
+            v => panic!("expected ProtocolStmt, received {}", v.name()),
+        }
+    }
+
     pub fn as_use(&self) -> &UseStmt {
         match self {
             Self::Use(v) => v,
@@ -224,6 +395,48 @@ impl Stmt {
             v => panic!("expected ForStmt, received {}", v.name()),
         }
     }
+
+    pub fn as_while(&self) -> &WhileStmt {
+        match self {
+            Self::While(v) => v,
+            v => panic!("expected WhileStmt, received {}", v.name()),
+        }
+    }
+
+    pub fn as_loop(&self) -> &LoopStmt {
+        match self {
+            Self::Loop(v) => v,
+            v => panic!("expected LoopStmt, received {}", v.name()),
+        }
+    }
+
+    pub fn as_break(&self) -> &BreakStmt {
+        match self {
+            Self::Break(v) => v,
+            v => panic!("expected BreakStmt, received {}", v.name()),
+        }
+    }
+
+    pub fn as_continue(&self) -> &ContinueStmt {
+        match self {
+            Self::Continue(v) => v,
+            v => panic!("expected ContinueStmt, received {}", v.name()),
+        }
+    }
+
+    pub fn as_macro(&self) -> &MacroStmt {
+        match self {
+            Self::Macro(v) => v,
+            v => panic!("expected MacroStmt, received {}", v.name()),
+        }
+    }
+
+    pub fn as_macro_call(&self) -> &MacroCallStmt {
+        match self {
+            Self::MacroCall(v) => v,
+            v => panic!("expected MacroCallStmt, received {}", v.name()),
+        }
+    }
 }
 
 impl Syntax for Stmt {
@@ -234,33 +447,40 @@ impl Syntax for Stmt {
             Self::Let(v) => v.name(),
             Self::Mod(v) => v.name(),
             Self::Struct(v) => v.name(),
+            Self::Const(v) => v.name(),
+            Self::TypeAlias(v) => v.name(),
             Self::Fn(v) => v.name(),
             Self::Impl(v) => v.name(),
+            Self::Protocol(v) => v.name(),
             Self::Use(v) => v.name(),
             Self::Return(v) => v.name(),
             Self::If(v) => v.name(),
             Self::Enum(v) => v.name(),
             Self::For(v) => v.name(),
+            Self::While(v) => v.name(),
+            Self::Loop(v) => v.name(),
+            Self::Break(v) => v.name(),
+            Self::Continue(v) => v.name(),
+            Self::Macro(v) => v.name(),
+            Self::MacroCall(v) => v.name(),
         }
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_stmt(self)
+            .then(|| crate::stmt::walk_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_stmt_mut(self)
+            .then(|| crate::stmt::walk_stmt_mut(visitor, self))
+    }
 
-        match self {
-            Self::Block(v) => v.accept(visitor),
-            Self::Enum(v) => v.accept(visitor),
-            Self::Expr(v) => v.accept(visitor),
-            Self::Fn(v) => v.accept(visitor),
-            Self::For(v) => v.accept(visitor),
-            Self::If(v) => v.accept(visitor),
-            Self::Impl(v) => v.accept(visitor),
-            Self::Let(v) => v.accept(visitor),
-            Self::Mod(v) => v.accept(visitor),
-            Self::Return(v) => v.accept(visitor),
-            Self::Struct(v) => v.accept(visitor),
-            Self::Use(v) => v.accept(visitor),
-        }
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_stmt_fold(folder, self);
+        folder.fold_stmt(node)
     }
 }
 
@@ -272,13 +492,22 @@ impl std::fmt::Display for Stmt {
             Self::Let(v) => write!(f, "{}", v),
             Self::Mod(v) => write!(f, "{}", v),
             Self::Struct(v) => write!(f, "{}", v),
+            Self::Const(v) => write!(f, "{}", v),
+            Self::TypeAlias(v) => write!(f, "{}", v),
             Self::Fn(v) => write!(f, "{}", v),
             Self::Impl(v) => write!(f, "{}", v),
+            Self::Protocol(v) => write!(f, "{}", v),
             Self::Use(v) => write!(f, "{}", v),
             Self::Return(v) => write!(f, "{}", v),
             Self::If(v) => write!(f, "{}", v),
             Self::Enum(v) => write!(f, "{}", v),
             Self::For(v) => write!(f, "{}", v),
+            Self::While(v) => write!(f, "{}", v),
+            Self::Loop(v) => write!(f, "{}", v),
+            Self::Break(v) => write!(f, "{}", v),
+            Self::Continue(v) => write!(f, "{}", v),
+            Self::Macro(v) => write!(f, "{}", v),
+            Self::MacroCall(v) => write!(f, "{}", v),
         }
     }
 }
@@ -306,13 +535,22 @@ impl Spanned for Stmt {
             Self::Expr(v) => v.span(),
             Self::Let(v) => v.span(),
             Self::Struct(v) => v.span(),
+            Self::Const(v) => v.span(),
+            Self::TypeAlias(v) => v.span(),
             Self::Mod(v) => v.span(),
             Self::Fn(v) => v.span(),
             Self::Impl(v) => v.span(),
+            Self::Protocol(v) => v.span(),
             Self::Return(v) => v.span(),
             Self::If(v) => v.span(),
             Self::Enum(v) => v.span(),
             Self::For(v) => v.span(),
+            Self::While(v) => v.span(),
+            Self::Loop(v) => v.span(),
+            Self::Break(v) => v.span(),
+            Self::Continue(v) => v.span(),
+            Self::Macro(v) => v.span(),
+            Self::MacroCall(v) => v.span(),
         }
     }
 }
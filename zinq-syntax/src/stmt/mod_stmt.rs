@@ -1,14 +1,35 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
-use zinq_token::{Ident, Mod, SemiColon};
-
-use crate::{Syntax, Visibility, stmt::Stmt};
-
+use zinq_token::{Ident, LBrace, Mod, RBrace, SemiColon};
+
+use crate::{Syntax, Visibility, meta::Meta, parse_item, stmt::Stmt};
+
+///
+/// ## ModStmt
+/// a module declaration, either loaded
+/// from `name.zq`/`name/mod.zq` or inline
+///
+/// ```
+/// pub mod tests;
+///
+/// mod utils {
+///     fn add(a: u8, b: u8) -> u8 { a + b }
+/// }
+/// ```
+///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ModStmt {
+    pub meta: Option<Meta>,
     pub vis: Visibility,
     pub keyword: Mod,
     pub name: Ident,
-    pub semi: SemiColon,
+    pub body: Option<ModBody>,
+    pub semi: Option<SemiColon>,
+}
+
+impl ModStmt {
+    pub fn is_inline(&self) -> bool {
+        self.body.is_some()
+    }
 }
 
 impl From<ModStmt> for Stmt {
@@ -22,8 +43,21 @@ impl Syntax for ModStmt {
         "Stmt::Mod"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_mod_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_mod_stmt(self)
+            .then(|| crate::stmt::walk_mod_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_mod_stmt_mut(self)
+            .then(|| crate::stmt::walk_mod_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_mod_stmt_fold(folder, self);
+        folder.fold_mod_stmt(node)
     }
 }
 
@@ -41,6 +75,7 @@ impl Peek for ModStmt {
         let mut fork = cursor.fork();
         let mut fork_parser = parser.clone();
 
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
         fork_parser.parse::<Visibility>(&mut fork)?;
         Ok(fork_parser.peek::<Mod>(&fork).unwrap_or(false))
     }
@@ -51,15 +86,22 @@ impl Parse for ModStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let vis = parser.parse::<Visibility>(cursor)?;
         let keyword = parser.parse::<Mod>(cursor)?;
         let name = parser.parse::<Ident>(cursor)?;
-        let semi = parser.parse::<SemiColon>(cursor)?;
+        let body = parser.parse::<Option<ModBody>>(cursor)?;
+        let semi = match &body {
+            None => Some(parser.parse::<SemiColon>(cursor)?),
+            Some(_) => None,
+        };
 
         Ok(Self {
+            meta,
             vis,
             keyword,
             name,
+            body,
             semi,
         })
     }
@@ -67,7 +109,71 @@ impl Parse for ModStmt {
 
 impl Spanned for ModStmt {
     fn span(&self) -> Span {
-        Span::join(self.vis.span(), self.semi.span())
+        let start = match &self.meta {
+            None => self.vis.span(),
+            Some(meta) => meta.span(),
+        };
+
+        match (&self.body, &self.semi) {
+            (Some(body), _) => Span::join(start, body.span()),
+            (None, semi) => Span::join(start, semi.span()),
+        }
+    }
+}
+
+///
+/// ## ModBody
+/// the items of an inline module
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModBody {
+    pub left_brace: LBrace,
+    pub items: Vec<Stmt>,
+    pub right_brace: RBrace,
+}
+
+impl std::fmt::Display for ModBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ModBody {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<LBrace>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for ModBody {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let left_brace = parser.parse::<LBrace>(cursor)?;
+        let mut items = vec![];
+
+        while !cursor.eof() && !parser.peek::<RBrace>(cursor).unwrap_or(false) {
+            if let Some(item) = parse_item(cursor, parser)? {
+                items.push(item);
+            }
+        }
+
+        let right_brace = parser.parse::<RBrace>(cursor)?;
+
+        Ok(Self {
+            left_brace,
+            items,
+            right_brace,
+        })
+    }
+}
+
+impl Spanned for ModBody {
+    fn span(&self) -> Span {
+        Span::join(self.left_brace.span(), self.right_brace.span())
     }
 }
 
@@ -103,4 +209,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_inline() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor =
+            Span::from_bytes(b"mod utils { pub struct A {} mod nested {} return 1; }").cursor();
+
+        let ty = parser.parse::<ModStmt>(&mut cursor)?;
+        let body = ty.body.as_ref().unwrap();
+
+        debug_assert!(ty.is_inline());
+        debug_assert!(ty.semi.is_none());
+        debug_assert_eq!(body.items.len(), 2);
+        debug_assert!(body.items[1].as_mod().is_inline());
+        debug_assert!(cursor.build(()).diagnostic.is_some());
+        debug_assert_eq!(
+            ty.to_string(),
+            "mod utils { pub struct A {} mod nested {} return 1; }"
+        );
+
+        Ok(())
+    }
 }
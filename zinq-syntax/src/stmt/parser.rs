@@ -2,8 +2,9 @@ use zinq_error::Result;
 use zinq_parse::{Cursor, ZinqParser};
 
 use crate::stmt::{
-    BlockStmt, EnumStmt, ExprStmt, FnStmt, ForStmt, IfStmt, ImplStmt, LetStmt, ReturnStmt, Stmt,
-    StructStmt, UseStmt,
+    BlockStmt, BreakStmt, ConstStmt, ContinueStmt, EnumStmt, ExprStmt, FnStmt, ForStmt, IfStmt,
+    ImplStmt, LetStmt, LoopStmt, MacroCallStmt, MacroStmt, ModStmt, ProtocolStmt, ReturnStmt, Stmt,
+    StructStmt, TypeAliasStmt, UseStmt, WhileStmt,
 };
 
 pub trait StmtParser {
@@ -13,36 +14,66 @@ pub trait StmtParser {
     fn parse_var_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_struct_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_enum_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_const_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_type_alias_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_impl_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_protocol_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_for_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_expr_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_fn_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_block_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
     fn parse_use_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_mod_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_while_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_loop_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_break_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_continue_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_macro_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
+    fn parse_macro_call_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt>;
 }
 
 impl StmtParser for ZinqParser {
     fn parse_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
-        if self.peek::<StructStmt>(cursor).unwrap_or(false) {
+        if self.peek::<MacroStmt>(cursor).unwrap_or(false) {
+            return self.parse_macro_stmt(cursor);
+        } else if self.peek::<StructStmt>(cursor).unwrap_or(false) {
             return self.parse_struct_stmt(cursor);
         } else if self.peek::<EnumStmt>(cursor).unwrap_or(false) {
             return self.parse_enum_stmt(cursor);
+        } else if self.peek::<ConstStmt>(cursor).unwrap_or(false) {
+            return self.parse_const_stmt(cursor);
+        } else if self.peek::<TypeAliasStmt>(cursor).unwrap_or(false) {
+            return self.parse_type_alias_stmt(cursor);
         } else if self.peek::<FnStmt>(cursor).unwrap_or(false) {
             return self.parse_fn_stmt(cursor);
         } else if self.peek::<LetStmt>(cursor).unwrap_or(false) {
             return self.parse_var_stmt(cursor);
+        } else if self.peek::<ModStmt>(cursor).unwrap_or(false) {
+            return self.parse_mod_stmt(cursor);
         } else if self.peek::<UseStmt>(cursor).unwrap_or(false) {
             return self.parse_use_stmt(cursor);
         } else if self.peek::<ImplStmt>(cursor).unwrap_or(false) {
             return self.parse_impl_stmt(cursor);
+        } else if self.peek::<ProtocolStmt>(cursor).unwrap_or(false) {
+            return self.parse_protocol_stmt(cursor);
         } else if self.peek::<ForStmt>(cursor).unwrap_or(false) {
             return self.parse_for_stmt(cursor);
+        } else if self.peek::<WhileStmt>(cursor).unwrap_or(false) {
+            return self.parse_while_stmt(cursor);
+        } else if self.peek::<LoopStmt>(cursor).unwrap_or(false) {
+            return self.parse_loop_stmt(cursor);
+        } else if self.peek::<BreakStmt>(cursor).unwrap_or(false) {
+            return self.parse_break_stmt(cursor);
+        } else if self.peek::<ContinueStmt>(cursor).unwrap_or(false) {
+            return self.parse_continue_stmt(cursor);
         } else if self.peek::<IfStmt>(cursor).unwrap_or(false) {
             return self.parse_if_stmt(cursor);
         } else if self.peek::<ReturnStmt>(cursor).unwrap_or(false) {
             return self.parse_return_stmt(cursor);
         } else if self.peek::<BlockStmt>(cursor).unwrap_or(false) {
             return self.parse_block_stmt(cursor);
+        } else if self.peek::<MacroCallStmt>(cursor).unwrap_or(false) {
+            return self.parse_macro_call_stmt(cursor);
         }
 
         self.parse_expr_stmt(cursor)
@@ -68,10 +99,22 @@ impl StmtParser for ZinqParser {
         Ok(self.parse::<EnumStmt>(cursor)?.into())
     }
 
+    fn parse_const_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<ConstStmt>(cursor)?.into())
+    }
+
+    fn parse_type_alias_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<TypeAliasStmt>(cursor)?.into())
+    }
+
     fn parse_impl_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
         Ok(self.parse::<ImplStmt>(cursor)?.into())
     }
 
+    fn parse_protocol_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<ProtocolStmt>(cursor)?.into())
+    }
+
     fn parse_for_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
         Ok(self.parse::<ForStmt>(cursor)?.into())
     }
@@ -91,4 +134,32 @@ impl StmtParser for ZinqParser {
     fn parse_use_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
         Ok(self.parse::<UseStmt>(cursor)?.into())
     }
+
+    fn parse_mod_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<ModStmt>(cursor)?.into())
+    }
+
+    fn parse_while_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<WhileStmt>(cursor)?.into())
+    }
+
+    fn parse_loop_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<LoopStmt>(cursor)?.into())
+    }
+
+    fn parse_break_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<BreakStmt>(cursor)?.into())
+    }
+
+    fn parse_continue_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<ContinueStmt>(cursor)?.into())
+    }
+
+    fn parse_macro_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<MacroStmt>(cursor)?.into())
+    }
+
+    fn parse_macro_call_stmt(&mut self, cursor: &mut Cursor) -> Result<Stmt> {
+        Ok(self.parse::<MacroCallStmt>(cursor)?.into())
+    }
 }
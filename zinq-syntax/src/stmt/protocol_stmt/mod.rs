@@ -0,0 +1,208 @@
+mod syntax;
+
+pub use syntax::*;
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Ident, LBrace, Protocol, RBrace};
+
+use crate::{Bounds, Generics, Syntax, Visibility, WhereClause, meta::Meta, stmt::Stmt};
+
+///
+/// ## Protocol Statement
+/// `protocol <name>: <super1> + <super2> { ... }`
+///
+/// ```
+/// pub protocol Shape: Display {
+///     const SIDES: u32;
+///
+///     fn area(&self) -> f64;
+///     fn describe(&self) -> string { "shape" }
+/// }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProtocolStmt {
+    pub meta: Option<Meta>,
+    pub vis: Visibility,
+    pub keyword: Protocol,
+    pub name: Ident,
+    pub generics: Option<Generics>,
+    pub bounds: Option<Bounds>,
+    pub where_clause: Option<WhereClause>,
+    pub left_brace: LBrace,
+    pub items: Vec<ProtocolSyntax>,
+    pub right_brace: RBrace,
+}
+
+impl ProtocolStmt {
+    pub fn methods(&self) -> impl Iterator<Item = &ProtocolMethod> {
+        self.items.iter().filter_map(|item| match item {
+            ProtocolSyntax::Method(v) => Some(v.as_ref()),
+            _ => None,
+        })
+    }
+
+    pub fn consts(&self) -> impl Iterator<Item = &ProtocolConst> {
+        self.items.iter().filter_map(|item| match item {
+            ProtocolSyntax::Const(v) => Some(v.as_ref()),
+            _ => None,
+        })
+    }
+
+    pub fn types(&self) -> impl Iterator<Item = &ProtocolType> {
+        self.items.iter().filter_map(|item| match item {
+            ProtocolSyntax::Type(v) => Some(v.as_ref()),
+            _ => None,
+        })
+    }
+}
+
+impl From<ProtocolStmt> for Stmt {
+    fn from(value: ProtocolStmt) -> Self {
+        Self::Protocol(value)
+    }
+}
+
+impl Syntax for ProtocolStmt {
+    fn name(&self) -> &str {
+        "Stmt::Protocol"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_stmt(self)
+            .then(|| crate::stmt::walk_protocol_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_stmt_mut(self)
+            .then(|| crate::stmt::walk_protocol_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_protocol_stmt_fold(folder, self);
+        folder.fold_protocol_stmt(node)
+    }
+}
+
+impl std::fmt::Display for ProtocolStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ProtocolStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Visibility>(&mut fork)?;
+        Ok(fork_parser.peek::<Protocol>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for ProtocolStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let vis = parser.parse::<Visibility>(cursor)?;
+        let keyword = parser.parse::<Protocol>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+        let generics = parser.parse::<Option<Generics>>(cursor)?;
+        let bounds = parser.parse::<Option<Bounds>>(cursor)?;
+        let where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
+        let left_brace = parser.parse::<LBrace>(cursor)?;
+        let mut items = vec![];
+
+        while !cursor.eof() && !parser.peek::<RBrace>(cursor).unwrap_or(false) {
+            items.push(parser.parse::<ProtocolSyntax>(cursor)?);
+        }
+
+        let right_brace = parser.parse::<RBrace>(cursor)?;
+
+        Ok(Self {
+            meta,
+            vis,
+            keyword,
+            name,
+            generics,
+            bounds,
+            where_clause,
+            left_brace,
+            items,
+            right_brace,
+        })
+    }
+}
+
+impl Spanned for ProtocolStmt {
+    fn span(&self) -> Span {
+        if let Some(meta) = &self.meta {
+            return Span::join(meta.span(), self.right_brace.span());
+        }
+
+        Span::join(self.vis.span(), self.right_brace.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::{ProtocolStmt, StmtParser};
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"pub protocol Shape: Display + Debug {
+                type Unit: Display = f64;
+                const SIDES: u32;
+                const NAME: string = \"shape\";
+
+                fn area(&self) -> f64;
+
+                fn describe(&self) -> string {
+                    return self.name();
+                }
+            }",
+        )
+        .cursor();
+
+        let stmt = parser.parse_stmt(&mut cursor)?;
+        let protocol = stmt.as_protocol();
+
+        debug_assert!(stmt.is_item());
+        debug_assert!(protocol.vis.is_pub());
+        debug_assert_eq!(protocol.name.to_string(), "Shape");
+        debug_assert_eq!(protocol.bounds.as_ref().unwrap().items.len(), 2);
+        debug_assert_eq!(protocol.types().count(), 1);
+        debug_assert_eq!(protocol.consts().count(), 2);
+        debug_assert_eq!(protocol.methods().count(), 2);
+        debug_assert!(!protocol.methods().next().unwrap().is_default());
+        debug_assert!(protocol.methods().last().unwrap().is_default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_empty_generic() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"protocol Into<T> {}").cursor();
+        let protocol = parser.parse::<ProtocolStmt>(&mut cursor)?;
+
+        debug_assert!(protocol.vis.is_priv());
+        debug_assert!(protocol.generics.is_some());
+        debug_assert!(protocol.items.is_empty());
+        debug_assert_eq!(protocol.to_string(), "protocol Into<T> {}");
+
+        Ok(())
+    }
+}
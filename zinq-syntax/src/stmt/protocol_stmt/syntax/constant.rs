@@ -0,0 +1,126 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Colon, Const, Eq, Ident, SemiColon, Suffixed};
+
+use crate::{Syntax, expr::Expr, meta::Meta, stmt::ProtocolSyntax, ty::Type};
+
+///
+/// ## Protocol Const
+/// `const <name>: <type>;` or `const <name>: <type> = <default>;`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProtocolConst {
+    pub meta: Option<Meta>,
+    pub keyword: Const,
+    pub name: Ident,
+    pub ty: Suffixed<Colon, Type>,
+    pub default: Option<Suffixed<Eq, Expr>>,
+    pub semi: SemiColon,
+}
+
+impl ProtocolConst {
+    pub fn is_default(&self) -> bool {
+        self.default.is_some()
+    }
+}
+
+impl From<ProtocolConst> for ProtocolSyntax {
+    fn from(value: ProtocolConst) -> Self {
+        Self::Const(Box::new(value))
+    }
+}
+
+impl Syntax for ProtocolConst {
+    fn name(&self) -> &str {
+        "Stmt::Protocol::Const"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_const(self)
+            .then(|| crate::stmt::walk_protocol_const(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_const_mut(self)
+            .then(|| crate::stmt::walk_protocol_const_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_protocol_const_fold(folder, self);
+        folder.fold_protocol_const(node)
+    }
+}
+
+impl std::fmt::Display for ProtocolConst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ProtocolConst {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Const>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for ProtocolConst {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let keyword = parser.parse::<Const>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+        let ty = parser.parse::<Suffixed<Colon, Type>>(cursor)?;
+        let default = parser.parse::<Option<Suffixed<Eq, Expr>>>(cursor)?;
+        let semi = parser.parse::<SemiColon>(cursor)?;
+
+        Ok(Self {
+            meta,
+            keyword,
+            name,
+            ty,
+            default,
+            semi,
+        })
+    }
+}
+
+impl Spanned for ProtocolConst {
+    fn span(&self) -> Span {
+        if let Some(meta) = &self.meta {
+            return Span::join(meta.span(), self.semi.span());
+        }
+
+        Span::join(self.keyword.span(), self.semi.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::ProtocolConst;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"const MAX: u32 = 10;").cursor();
+        let value = parser.parse::<ProtocolConst>(&mut cursor)?;
+
+        debug_assert!(value.is_default());
+        debug_assert_eq!(value.ty.suffix.to_string(), "u32");
+        debug_assert_eq!(value.to_string(), "const MAX: u32 = 10;");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Comma, Fn, Ident, LParen, Punctuated, RArrow, RParen, SemiColon, Suffixed};
+
+use crate::{
+    Generics, Syntax, Visibility, WhereClause,
+    meta::Meta,
+    param::{FnParam, SelfParam},
+    stmt::{BlockStmt, ProtocolSyntax},
+    ty::Type,
+};
+
+///
+/// ## Protocol Method
+/// `fn <name>(<self_param>, <arg1>, ...) -> <return_type>;`,
+/// methods with a body are the protocol's default
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProtocolMethod {
+    pub meta: Option<Meta>,
+    pub vis: Visibility,
+    pub keyword: Fn,
+    pub name: Ident,
+    pub generics: Option<Generics>,
+    pub left_paren: LParen,
+    pub self_param: Option<SelfParam>,
+    pub params: Punctuated<FnParam, Comma>,
+    pub right_paren: RParen,
+    pub return_ty: Option<Suffixed<RArrow, Type>>,
+    pub where_clause: Option<WhereClause>,
+    pub block: Option<BlockStmt>,
+    pub semi: Option<SemiColon>,
+}
+
+impl ProtocolMethod {
+    pub fn is_default(&self) -> bool {
+        self.block.is_some()
+    }
+}
+
+impl From<ProtocolMethod> for ProtocolSyntax {
+    fn from(value: ProtocolMethod) -> Self {
+        Self::Method(Box::new(value))
+    }
+}
+
+impl Syntax for ProtocolMethod {
+    fn name(&self) -> &str {
+        "Stmt::Protocol::Method"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_method(self)
+            .then(|| crate::stmt::walk_protocol_method(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_method_mut(self)
+            .then(|| crate::stmt::walk_protocol_method_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_protocol_method_fold(folder, self);
+        folder.fold_protocol_method(node)
+    }
+}
+
+impl std::fmt::Display for ProtocolMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ProtocolMethod {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Visibility>(&mut fork)?;
+        Ok(fork_parser.peek::<Fn>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for ProtocolMethod {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let vis = parser.parse::<Visibility>(cursor)?;
+        let keyword = parser.parse::<Fn>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+        let generics = parser.parse::<Option<Generics>>(cursor)?;
+        let left_paren = parser.parse::<LParen>(cursor)?;
+        let self_param = parser.parse::<Option<SelfParam>>(cursor)?;
+
+        if self_param.is_some() && parser.peek::<Comma>(cursor).unwrap_or(false) {
+            let _ = parser.parse::<Comma>(cursor)?;
+        }
+
+        let params = parser.parse::<Punctuated<FnParam, Comma>>(cursor)?;
+        FnParam::expect_typed(&params)?;
+
+        let right_paren = parser.parse::<RParen>(cursor)?;
+        let return_ty = parser.parse::<Option<Suffixed<RArrow, Type>>>(cursor)?;
+        let where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
+        let block = parser.parse::<Option<BlockStmt>>(cursor)?;
+        let semi = match &block {
+            None => Some(parser.parse::<SemiColon>(cursor)?),
+            Some(_) => None,
+        };
+
+        Ok(Self {
+            meta,
+            vis,
+            keyword,
+            name,
+            generics,
+            left_paren,
+            self_param,
+            params,
+            right_paren,
+            return_ty,
+            where_clause,
+            block,
+            semi,
+        })
+    }
+}
+
+impl Spanned for ProtocolMethod {
+    fn span(&self) -> Span {
+        let start = match &self.meta {
+            None => self.vis.span(),
+            Some(meta) => meta.span(),
+        };
+
+        match (&self.block, &self.semi) {
+            (Some(block), _) => Span::join(start, block.span()),
+            (None, Some(semi)) => Span::join(start, semi.span()),
+            (None, None) => Span::join(start, self.right_paren.span()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::ProtocolMethod;
+
+    #[test]
+    fn should_parse_signature() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"fn area(&self, scale: f64) -> f64;").cursor();
+        let value = parser.parse::<ProtocolMethod>(&mut cursor)?;
+
+        debug_assert!(!value.is_default());
+        debug_assert!(value.self_param.is_some());
+        debug_assert_eq!(value.to_string(), "fn area(&self, scale: f64) -> f64;");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_default() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"fn name(self) -> string { \"shape\" }").cursor();
+        let value = parser.parse::<ProtocolMethod>(&mut cursor)?;
+
+        debug_assert!(value.is_default());
+        debug_assert!(value.semi.is_none());
+
+        Ok(())
+    }
+}
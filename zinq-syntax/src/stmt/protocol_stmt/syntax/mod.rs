@@ -0,0 +1,112 @@
+mod constant;
+mod method;
+mod ty;
+
+pub use constant::*;
+pub use method::*;
+pub use ty::*;
+use zinq_parse::{Parse, Peek, Spanned};
+
+use crate::Syntax;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProtocolSyntax {
+    Method(Box<ProtocolMethod>),
+    Const(Box<ProtocolConst>),
+    Type(Box<ProtocolType>),
+}
+
+impl ProtocolSyntax {
+    pub fn is_method(&self) -> bool {
+        matches!(self, Self::Method(_))
+    }
+
+    pub fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+
+    pub fn is_type(&self) -> bool {
+        matches!(self, Self::Type(_))
+    }
+}
+
+impl Syntax for ProtocolSyntax {
+    fn name(&self) -> &str {
+        match self {
+            Self::Method(v) => v.name(),
+            Self::Const(v) => v.name(),
+            Self::Type(v) => v.name(),
+        }
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        crate::stmt::walk_protocol_syntax(visitor, self)
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        crate::stmt::walk_protocol_syntax_mut(visitor, self)
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        crate::stmt::walk_protocol_syntax_fold(folder, self)
+    }
+}
+
+impl std::fmt::Display for ProtocolSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Method(v) => write!(f, "{}", v),
+            Self::Const(v) => write!(f, "{}", v),
+            Self::Type(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Peek for ProtocolSyntax {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        match fork_parser.parse::<Self>(&mut fork) {
+            Err(_) => Ok(false),
+            Ok(_) => Ok(true),
+        }
+    }
+}
+
+impl Parse for ProtocolSyntax {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        if parser.peek::<ProtocolMethod>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<ProtocolMethod>(cursor)?.into());
+        }
+
+        if parser.peek::<ProtocolConst>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<ProtocolConst>(cursor)?.into());
+        }
+
+        if parser.peek::<ProtocolType>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<ProtocolType>(cursor)?.into());
+        }
+
+        Err(cursor.error(
+            zinq_error::NOT_FOUND,
+            &format!("unexpected token '{}'", *cursor.peek()? as char),
+        ))
+    }
+}
+
+impl Spanned for ProtocolSyntax {
+    fn span(&self) -> zinq_parse::Span {
+        match self {
+            Self::Method(v) => v.span(),
+            Self::Const(v) => v.span(),
+            Self::Type(v) => v.span(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Eq, Ident, SemiColon, Suffixed};
+
+use crate::{Bounds, Syntax, meta::Meta, stmt::ProtocolSyntax, ty::Type};
+
+///
+/// ## Protocol Type
+/// an associated type, `type <name>: <bounds>;`
+/// or `type <name> = <default>;`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProtocolType {
+    pub meta: Option<Meta>,
+    pub keyword: zinq_token::Type,
+    pub name: Ident,
+    pub bounds: Option<Bounds>,
+    pub default: Option<Suffixed<Eq, Type>>,
+    pub semi: SemiColon,
+}
+
+impl ProtocolType {
+    pub fn is_default(&self) -> bool {
+        self.default.is_some()
+    }
+}
+
+impl From<ProtocolType> for ProtocolSyntax {
+    fn from(value: ProtocolType) -> Self {
+        Self::Type(Box::new(value))
+    }
+}
+
+impl Syntax for ProtocolType {
+    fn name(&self) -> &str {
+        "Stmt::Protocol::Type"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_type(self)
+            .then(|| crate::stmt::walk_protocol_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_protocol_type_mut(self)
+            .then(|| crate::stmt::walk_protocol_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_protocol_type_fold(folder, self);
+        folder.fold_protocol_type(node)
+    }
+}
+
+impl std::fmt::Display for ProtocolType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ProtocolType {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<zinq_token::Type>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for ProtocolType {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let keyword = parser.parse::<zinq_token::Type>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+        let bounds = parser.parse::<Option<Bounds>>(cursor)?;
+        let default = parser.parse::<Option<Suffixed<Eq, Type>>>(cursor)?;
+        let semi = parser.parse::<SemiColon>(cursor)?;
+
+        Ok(Self {
+            meta,
+            keyword,
+            name,
+            bounds,
+            default,
+            semi,
+        })
+    }
+}
+
+impl Spanned for ProtocolType {
+    fn span(&self) -> Span {
+        if let Some(meta) = &self.meta {
+            return Span::join(meta.span(), self.semi.span());
+        }
+
+        Span::join(self.keyword.span(), self.semi.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::ProtocolType;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"type Item: Display + Clone = string;").cursor();
+        let value = parser.parse::<ProtocolType>(&mut cursor)?;
+
+        debug_assert!(value.is_default());
+        debug_assert_eq!(value.name.to_string(), "Item");
+        debug_assert_eq!(value.bounds.as_ref().unwrap().items.len(), 2);
+
+        Ok(())
+    }
+}
@@ -1,12 +1,13 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Return, SemiColon};
 
-use crate::{Syntax, expr::Expr, stmt::Stmt};
+use crate::{Syntax, expr::Expr, meta::Meta, stmt::Stmt};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ReturnStmt {
+    pub meta: Option<Meta>,
     pub keyword: Return,
-    pub right: Expr,
+    pub right: Option<Expr>,
     pub semi: Option<SemiColon>,
 }
 
@@ -21,9 +22,21 @@ impl Syntax for ReturnStmt {
         "Stmt::Return"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_return_stmt(self);
-        self.right.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_return_stmt(self)
+            .then(|| crate::stmt::walk_return_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_return_stmt_mut(self)
+            .then(|| crate::stmt::walk_return_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_return_stmt_fold(folder, self);
+        folder.fold_return_stmt(node)
     }
 }
 
@@ -38,7 +51,11 @@ impl Peek for ReturnStmt {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<Return>(cursor).unwrap_or(false))
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Return>(&fork).unwrap_or(false))
     }
 }
 
@@ -47,11 +64,16 @@ impl Parse for ReturnStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let keyword = parser.parse::<Return>(cursor)?;
-        let right = parser.parse::<Expr>(cursor)?;
+        let right = match parser.peek::<SemiColon>(cursor).unwrap_or(false) {
+            true => None,
+            false => Some(parser.parse::<Expr>(cursor)?),
+        };
         let semi = parser.parse::<Option<SemiColon>>(cursor)?;
 
         Ok(Self {
+            meta,
             keyword,
             right,
             semi,
@@ -61,11 +83,19 @@ impl Parse for ReturnStmt {
 
 impl Spanned for ReturnStmt {
     fn span(&self) -> Span {
+        let start = match &self.meta {
+            None => self.keyword.span(),
+            Some(meta) => meta.span(),
+        };
+
         if let Some(semi) = &self.semi {
-            return Span::join(self.keyword.span(), semi.span());
+            return Span::join(start, semi.span());
         }
 
-        Span::join(self.keyword.span(), self.right.span())
+        match &self.right {
+            None => Span::join(start, self.keyword.span()),
+            Some(right) => Span::join(start, right.span()),
+        }
     }
 }
 
@@ -99,4 +129,15 @@ mod tests {
         debug_assert_eq!(stmt.to_string(), "return (1 + 2);");
         Ok(())
     }
+
+    #[test]
+    fn should_parse_without_value() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"return;").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.as_return().right.is_none());
+        debug_assert_eq!(stmt.to_string(), "return;");
+        Ok(())
+    }
 }
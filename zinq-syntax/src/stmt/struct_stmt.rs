@@ -1,7 +1,7 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Ident, SemiColon, Struct};
 
-use crate::{Generics, Syntax, Visibility, fields::Fields, meta::Meta, stmt::Stmt};
+use crate::{Generics, Syntax, Visibility, WhereClause, fields::Fields, meta::Meta, stmt::Stmt};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StructStmt {
@@ -10,6 +10,7 @@ pub struct StructStmt {
     pub keyword: Struct,
     pub name: Ident,
     pub generics: Option<Generics>,
+    pub where_clause: Option<WhereClause>,
     pub fields: Fields,
     pub semi: Option<SemiColon>,
 }
@@ -25,8 +26,21 @@ impl Syntax for StructStmt {
         "Stmt::Struct"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_struct_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_struct_stmt(self)
+            .then(|| crate::stmt::walk_struct_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_struct_stmt_mut(self)
+            .then(|| crate::stmt::walk_struct_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_struct_stmt_fold(folder, self);
+        folder.fold_struct_stmt(node)
     }
 }
 
@@ -60,9 +74,15 @@ impl Parse for StructStmt {
         let keyword = parser.parse::<Struct>(cursor)?;
         let name = parser.parse::<Ident>(cursor)?;
         let generics = parser.parse::<Option<Generics>>(cursor)?;
+        let mut where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
         let fields = parser.parse::<Fields>(cursor)?;
         let mut semi = None;
 
+        // tuple structs declare their bounds after the fields
+        if where_clause.is_none() && fields.is_indexed() {
+            where_clause = parser.parse::<Option<WhereClause>>(cursor)?;
+        }
+
         if fields.is_none() || fields.is_indexed() {
             semi = Some(parser.parse::<SemiColon>(cursor)?);
         }
@@ -73,6 +93,7 @@ impl Parse for StructStmt {
             keyword,
             name,
             generics,
+            where_clause,
             fields,
             semi,
         })
@@ -276,4 +297,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_with_where() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(
+            b"struct Buffer<T, const N: usize> where T: Clone {
+            items: [T],
+        }
+        struct Pair<T>(T, T) where T: Cmp;",
+        )
+        .cursor();
+
+        let named = parser.parse_stmt(&mut cursor)?;
+        let indexed = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(named.as_struct().where_clause.is_some());
+        debug_assert!(named.as_struct().fields.is_named());
+        debug_assert_eq!(named.as_struct().generics.as_ref().unwrap().params.len(), 2);
+        debug_assert!(indexed.as_struct().where_clause.is_some());
+        debug_assert_eq!(indexed.to_string(), "struct Pair<T>(T, T) where T: Cmp;");
+
+        Ok(())
+    }
 }
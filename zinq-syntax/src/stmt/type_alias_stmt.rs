@@ -0,0 +1,145 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Eq, Ident, SemiColon};
+
+use crate::{Generics, Syntax, Visibility, meta::Meta, stmt::Stmt, ty::Type};
+
+///
+/// ## Type Alias Statement
+/// `type <name><generics> = <type>;`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeAliasStmt {
+    pub meta: Option<Meta>,
+    pub vis: Visibility,
+    pub keyword: zinq_token::Type,
+    pub name: Ident,
+    pub generics: Option<Generics>,
+    pub eq: Eq,
+    pub ty: Type,
+    pub semi: SemiColon,
+}
+
+impl From<TypeAliasStmt> for Stmt {
+    fn from(value: TypeAliasStmt) -> Self {
+        Self::TypeAlias(value)
+    }
+}
+
+impl Syntax for TypeAliasStmt {
+    fn name(&self) -> &str {
+        "Stmt::TypeAlias"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_type_alias_stmt(self)
+            .then(|| crate::stmt::walk_type_alias_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_type_alias_stmt_mut(self)
+            .then(|| crate::stmt::walk_type_alias_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_type_alias_stmt_fold(folder, self);
+        folder.fold_type_alias_stmt(node)
+    }
+}
+
+impl std::fmt::Display for TypeAliasStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for TypeAliasStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Visibility>(&mut fork)?;
+
+        // `type` is contextual, so it must be followed by a name
+        if fork_parser.parse::<zinq_token::Type>(&mut fork).is_err() {
+            return Ok(false);
+        }
+
+        Ok(fork_parser.peek::<Ident>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for TypeAliasStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let vis = parser.parse::<Visibility>(cursor)?;
+        let keyword = parser.parse::<zinq_token::Type>(cursor)?;
+        let name = parser.parse::<Ident>(cursor)?;
+        let generics = parser.parse::<Option<Generics>>(cursor)?;
+        let eq = parser.parse::<Eq>(cursor)?;
+        let ty = parser.parse::<Type>(cursor)?;
+        let semi = parser.parse::<SemiColon>(cursor)?;
+
+        Ok(Self {
+            meta,
+            vis,
+            keyword,
+            name,
+            generics,
+            eq,
+            ty,
+            semi,
+        })
+    }
+}
+
+impl Spanned for TypeAliasStmt {
+    fn span(&self) -> Span {
+        if let Some(meta) = &self.meta {
+            return Span::join(meta.span(), self.semi.span());
+        }
+
+        Span::join(self.vis.span(), self.semi.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::StmtParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"pub type Pair<T> = (T, T);").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.is_type_alias());
+        debug_assert!(stmt.is_item());
+        debug_assert!(stmt.as_type_alias().generics.is_some());
+        debug_assert_eq!(stmt.as_type_alias().ty.to_string(), "(T, T)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_type_as_ident() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"type = 1;").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.is_expr());
+
+        Ok(())
+    }
+}
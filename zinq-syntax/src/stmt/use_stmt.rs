@@ -1,10 +1,11 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{SemiColon, Use};
 
-use crate::{Syntax, UsePath, Visibility, stmt::Stmt};
+use crate::{Syntax, UsePath, Visibility, meta::Meta, stmt::Stmt};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UseStmt {
+    pub meta: Option<Meta>,
     pub vis: Visibility,
     pub keyword: Use,
     pub path: UsePath,
@@ -22,8 +23,21 @@ impl Syntax for UseStmt {
         "Stmt::Use"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_use_stmt(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_use_stmt(self)
+            .then(|| crate::stmt::walk_use_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_use_stmt_mut(self)
+            .then(|| crate::stmt::walk_use_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_use_stmt_fold(folder, self);
+        folder.fold_use_stmt(node)
     }
 }
 
@@ -41,6 +55,7 @@ impl Peek for UseStmt {
         let mut fork = cursor.fork();
         let mut fork_parser = parser.clone();
 
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
         fork_parser.parse::<Visibility>(&mut fork)?;
         Ok(fork_parser.peek::<Use>(&fork).unwrap_or(false))
     }
@@ -51,12 +66,14 @@ impl Parse for UseStmt {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let vis = parser.parse::<Visibility>(cursor)?;
         let keyword = parser.parse::<Use>(cursor)?;
         let path = parser.parse::<UsePath>(cursor)?;
         let semi = parser.parse::<SemiColon>(cursor)?;
 
         Ok(Self {
+            meta,
             vis,
             keyword,
             path,
@@ -67,7 +84,10 @@ impl Parse for UseStmt {
 
 impl Spanned for UseStmt {
     fn span(&self) -> Span {
-        Span::join(self.vis.span(), self.semi.span())
+        match &self.meta {
+            None => Span::join(self.vis.span(), self.semi.span()),
+            Some(meta) => Span::join(meta.span(), self.semi.span()),
+        }
     }
 }
 
@@ -1,19 +1,558 @@
 #![allow(unused)]
 
+use crate::{Syntax, Visit, visitor::try_visit};
+
 use super::*;
 
 pub trait StmtVisitor {
-    fn visit_stmt(&mut self, node: &Stmt) {}
-    fn visit_impl_stmt(&mut self, node: &ImplStmt) {}
-    fn visit_block_stmt(&mut self, node: &BlockStmt) {}
-    fn visit_enum_stmt(&mut self, node: &EnumStmt) {}
-    fn visit_expr_stmt(&mut self, node: &ExprStmt) {}
-    fn visit_fn_stmt(&mut self, node: &FnStmt) {}
-    fn visit_for_stmt(&mut self, node: &ForStmt) {}
-    fn visit_if_stmt(&mut self, node: &IfStmt) {}
-    fn visit_let_stmt(&mut self, node: &LetStmt) {}
-    fn visit_mod_stmt(&mut self, node: &ModStmt) {}
-    fn visit_return_stmt(&mut self, node: &ReturnStmt) {}
-    fn visit_struct_stmt(&mut self, node: &StructStmt) {}
-    fn visit_use_stmt(&mut self, node: &UseStmt) {}
+    fn visit_stmt(&mut self, node: &Stmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_impl_stmt(&mut self, node: &ImplStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_block_stmt(&mut self, node: &BlockStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_const_stmt(&mut self, node: &ConstStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_enum_stmt(&mut self, node: &EnumStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_expr_stmt(&mut self, node: &ExprStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_fn_stmt(&mut self, node: &FnStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_for_stmt(&mut self, node: &ForStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_if_stmt(&mut self, node: &IfStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_let_stmt(&mut self, node: &LetStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_mod_stmt(&mut self, node: &ModStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_stmt(&mut self, node: &ProtocolStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_return_stmt(&mut self, node: &ReturnStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_struct_stmt(&mut self, node: &StructStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_type_alias_stmt(&mut self, node: &TypeAliasStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_use_stmt(&mut self, node: &UseStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_while_stmt(&mut self, node: &WhileStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_loop_stmt(&mut self, node: &LoopStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_break_stmt(&mut self, node: &BreakStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_continue_stmt(&mut self, node: &ContinueStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_stmt(&mut self, node: &MacroStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_call_stmt(&mut self, node: &MacroCallStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_impl_method(&mut self, node: &ImplMethod) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_method(&mut self, node: &ProtocolMethod) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_const(&mut self, node: &ProtocolConst) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_type(&mut self, node: &ProtocolType) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_stmt<V: crate::Visitor>(visitor: &mut V, node: &Stmt) -> Visit {
+    match node {
+        Stmt::Block(v) => v.accept(visitor),
+        Stmt::Expr(v) => v.accept(visitor),
+        Stmt::Let(v) => v.accept(visitor),
+        Stmt::Mod(v) => v.accept(visitor),
+        Stmt::Struct(v) => v.accept(visitor),
+        Stmt::Const(v) => v.accept(visitor),
+        Stmt::TypeAlias(v) => v.accept(visitor),
+        Stmt::Fn(v) => v.accept(visitor),
+        Stmt::Impl(v) => v.accept(visitor),
+        Stmt::Protocol(v) => v.accept(visitor),
+        Stmt::Use(v) => v.accept(visitor),
+        Stmt::Return(v) => v.accept(visitor),
+        Stmt::If(v) => v.accept(visitor),
+        Stmt::Enum(v) => v.accept(visitor),
+        Stmt::For(v) => v.accept(visitor),
+        Stmt::While(v) => v.accept(visitor),
+        Stmt::Loop(v) => v.accept(visitor),
+        Stmt::Break(v) => v.accept(visitor),
+        Stmt::Continue(v) => v.accept(visitor),
+        Stmt::Macro(v) => v.accept(visitor),
+        Stmt::MacroCall(v) => v.accept(visitor),
+    }
+}
+
+pub fn walk_impl_stmt<V: crate::Visitor>(visitor: &mut V, node: &ImplStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.protocol {
+        try_visit!(crate::walk_path(visitor, &v.inner));
+    }
+
+    try_visit!(node.for_ty.accept(visitor));
+
+    if let Some(v) = &node.where_clause {
+        try_visit!(v.accept(visitor));
+    }
+
+    for v in &node.stmts {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_block_stmt<V: crate::Visitor>(visitor: &mut V, node: &BlockStmt) -> Visit {
+    for v in &node.attrs {
+        try_visit!(v.accept(visitor));
+    }
+
+    for v in &node.stmts {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.tail {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_const_stmt<V: crate::Visitor>(visitor: &mut V, node: &ConstStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.ty.suffix.accept(visitor));
+    try_visit!(node.init.suffix.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_enum_stmt<V: crate::Visitor>(visitor: &mut V, node: &EnumStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.where_clause {
+        try_visit!(v.accept(visitor));
+    }
+
+    for item in node.variants.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_expr_stmt<V: crate::Visitor>(visitor: &mut V, node: &ExprStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.expr.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_fn_stmt<V: crate::Visitor>(visitor: &mut V, node: &FnStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    for item in node.params.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    if let Some(v) = &node.return_ty {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    if let Some(v) = &node.where_clause {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.block.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_for_stmt<V: crate::Visitor>(visitor: &mut V, node: &ForStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.pattern.accept(visitor));
+    try_visit!(node.expr.accept(visitor));
+    try_visit!(node.body.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_if_stmt<V: crate::Visitor>(visitor: &mut V, node: &IfStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some((_, v, _)) = &node.binding {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.cond.accept(visitor));
+    try_visit!(node.then_stmt.accept(visitor));
+
+    if let Some((_, v)) = &node.else_stmt {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_let_stmt<V: crate::Visitor>(visitor: &mut V, node: &LetStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.pattern.accept(visitor));
+
+    if let Some(v) = &node.ty {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    if let Some(v) = &node.init {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    if let Some(v) = &node.else_block {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_mod_stmt<V: crate::Visitor>(visitor: &mut V, node: &ModStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(body) = &node.body {
+        for v in &body.items {
+            try_visit!(v.accept(visitor));
+        }
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_stmt<V: crate::Visitor>(visitor: &mut V, node: &ProtocolStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.bounds {
+        try_visit!(crate::walk_bounds(visitor, v));
+    }
+
+    if let Some(v) = &node.where_clause {
+        try_visit!(v.accept(visitor));
+    }
+
+    for v in &node.items {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_return_stmt<V: crate::Visitor>(visitor: &mut V, node: &ReturnStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.right {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_struct_stmt<V: crate::Visitor>(visitor: &mut V, node: &StructStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.where_clause {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.fields.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_type_alias_stmt<V: crate::Visitor>(visitor: &mut V, node: &TypeAliasStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.ty.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_use_stmt<V: crate::Visitor>(visitor: &mut V, node: &UseStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_while_stmt<V: crate::Visitor>(visitor: &mut V, node: &WhileStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some((_, v, _)) = &node.binding {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.cond.accept(visitor));
+    try_visit!(node.body.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_loop_stmt<V: crate::Visitor>(visitor: &mut V, node: &LoopStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.body.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_break_stmt<V: crate::Visitor>(visitor: &mut V, node: &BreakStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.value {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_continue_stmt<V: crate::Visitor>(visitor: &mut V, node: &ContinueStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_macro_stmt<V: crate::Visitor>(visitor: &mut V, node: &MacroStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_macro_call_stmt<V: crate::Visitor>(visitor: &mut V, node: &MacroCallStmt) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_impl_method<V: crate::Visitor>(visitor: &mut V, node: &ImplMethod) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.self_param {
+        try_visit!(v.accept(visitor));
+    }
+
+    for item in node.params.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    if let Some(v) = &node.return_ty {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    if let Some(v) = &node.where_clause {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.block.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_method<V: crate::Visitor>(visitor: &mut V, node: &ProtocolMethod) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.generics {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.self_param {
+        try_visit!(v.accept(visitor));
+    }
+
+    for item in node.params.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    if let Some(v) = &node.return_ty {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    if let Some(v) = &node.where_clause {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.block {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_const<V: crate::Visitor>(visitor: &mut V, node: &ProtocolConst) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.ty.suffix.accept(visitor));
+
+    if let Some(v) = &node.default {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_type<V: crate::Visitor>(visitor: &mut V, node: &ProtocolType) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    if let Some(v) = &node.bounds {
+        try_visit!(crate::walk_bounds(visitor, v));
+    }
+
+    if let Some(v) = &node.default {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_impl_syntax<V: crate::Visitor>(visitor: &mut V, node: &ImplSyntax) -> Visit {
+    match node {
+        ImplSyntax::Method(v) => v.accept(visitor),
+        ImplSyntax::Const(v) => v.accept(visitor),
+        ImplSyntax::Type(v) => v.accept(visitor),
+    }
+}
+
+pub fn walk_protocol_syntax<V: crate::Visitor>(visitor: &mut V, node: &ProtocolSyntax) -> Visit {
+    match node {
+        ProtocolSyntax::Method(v) => v.accept(visitor),
+        ProtocolSyntax::Const(v) => v.accept(visitor),
+        ProtocolSyntax::Type(v) => v.accept(visitor),
+    }
 }
@@ -0,0 +1,582 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait StmtVisitorMut {
+    fn visit_stmt_mut(&mut self, node: &mut Stmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_impl_stmt_mut(&mut self, node: &mut ImplStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_block_stmt_mut(&mut self, node: &mut BlockStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_const_stmt_mut(&mut self, node: &mut ConstStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_enum_stmt_mut(&mut self, node: &mut EnumStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_expr_stmt_mut(&mut self, node: &mut ExprStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_fn_stmt_mut(&mut self, node: &mut FnStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_for_stmt_mut(&mut self, node: &mut ForStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_if_stmt_mut(&mut self, node: &mut IfStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_let_stmt_mut(&mut self, node: &mut LetStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_mod_stmt_mut(&mut self, node: &mut ModStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_stmt_mut(&mut self, node: &mut ProtocolStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_return_stmt_mut(&mut self, node: &mut ReturnStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_struct_stmt_mut(&mut self, node: &mut StructStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_type_alias_stmt_mut(&mut self, node: &mut TypeAliasStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_use_stmt_mut(&mut self, node: &mut UseStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_while_stmt_mut(&mut self, node: &mut WhileStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_loop_stmt_mut(&mut self, node: &mut LoopStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_break_stmt_mut(&mut self, node: &mut BreakStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_continue_stmt_mut(&mut self, node: &mut ContinueStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_stmt_mut(&mut self, node: &mut MacroStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_call_stmt_mut(&mut self, node: &mut MacroCallStmt) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_impl_method_mut(&mut self, node: &mut ImplMethod) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_method_mut(&mut self, node: &mut ProtocolMethod) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_const_mut(&mut self, node: &mut ProtocolConst) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_protocol_type_mut(&mut self, node: &mut ProtocolType) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Stmt) -> Visit {
+    match node {
+        Stmt::Block(v) => v.accept_mut(visitor),
+        Stmt::Expr(v) => v.accept_mut(visitor),
+        Stmt::Let(v) => v.accept_mut(visitor),
+        Stmt::Mod(v) => v.accept_mut(visitor),
+        Stmt::Struct(v) => v.accept_mut(visitor),
+        Stmt::Const(v) => v.accept_mut(visitor),
+        Stmt::TypeAlias(v) => v.accept_mut(visitor),
+        Stmt::Fn(v) => v.accept_mut(visitor),
+        Stmt::Impl(v) => v.accept_mut(visitor),
+        Stmt::Protocol(v) => v.accept_mut(visitor),
+        Stmt::Use(v) => v.accept_mut(visitor),
+        Stmt::Return(v) => v.accept_mut(visitor),
+        Stmt::If(v) => v.accept_mut(visitor),
+        Stmt::Enum(v) => v.accept_mut(visitor),
+        Stmt::For(v) => v.accept_mut(visitor),
+        Stmt::While(v) => v.accept_mut(visitor),
+        Stmt::Loop(v) => v.accept_mut(visitor),
+        Stmt::Break(v) => v.accept_mut(visitor),
+        Stmt::Continue(v) => v.accept_mut(visitor),
+        Stmt::Macro(v) => v.accept_mut(visitor),
+        Stmt::MacroCall(v) => v.accept_mut(visitor),
+    }
+}
+
+pub fn walk_impl_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ImplStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.protocol {
+        try_visit!(crate::walk_path_mut(visitor, &mut v.inner));
+    }
+
+    try_visit!(node.for_ty.accept_mut(visitor));
+
+    if let Some(v) = &mut node.where_clause {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for v in &mut node.stmts {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_block_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut BlockStmt) -> Visit {
+    for v in &mut node.attrs {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for v in &mut node.stmts {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.tail {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_const_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ConstStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.ty.suffix.accept_mut(visitor));
+    try_visit!(node.init.suffix.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_enum_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut EnumStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.where_clause {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for item in node.variants.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_expr_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ExprStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.expr.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_fn_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut FnStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for item in node.params.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.return_ty {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.where_clause {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.block.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_for_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ForStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.pattern.accept_mut(visitor));
+    try_visit!(node.expr.accept_mut(visitor));
+    try_visit!(node.body.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_if_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut IfStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some((_, v, _)) = &mut node.binding {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.cond.accept_mut(visitor));
+    try_visit!(node.then_stmt.accept_mut(visitor));
+
+    if let Some((_, v)) = &mut node.else_stmt {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_let_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut LetStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.pattern.accept_mut(visitor));
+
+    if let Some(v) = &mut node.ty {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.init {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.else_block {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_mod_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ModStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(body) = &mut node.body {
+        for v in &mut body.items {
+            try_visit!(v.accept_mut(visitor));
+        }
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_stmt_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ProtocolStmt,
+) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.bounds {
+        try_visit!(crate::walk_bounds_mut(visitor, v));
+    }
+
+    if let Some(v) = &mut node.where_clause {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for v in &mut node.items {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_return_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ReturnStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.right {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_struct_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut StructStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.where_clause {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.fields.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_type_alias_stmt_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut TypeAliasStmt,
+) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.ty.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_use_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut UseStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_while_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut WhileStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some((_, v, _)) = &mut node.binding {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.cond.accept_mut(visitor));
+    try_visit!(node.body.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_loop_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut LoopStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.body.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_break_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut BreakStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.value {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_continue_stmt_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ContinueStmt,
+) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_macro_stmt_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut MacroStmt) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_macro_call_stmt_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut MacroCallStmt,
+) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_impl_method_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ImplMethod) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.self_param {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for item in node.params.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.return_ty {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.where_clause {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.block.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_method_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ProtocolMethod,
+) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.generics {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.self_param {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for item in node.params.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.return_ty {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.where_clause {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.block {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_const_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ProtocolConst,
+) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.ty.suffix.accept_mut(visitor));
+
+    if let Some(v) = &mut node.default {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_protocol_type_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ProtocolType,
+) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.bounds {
+        try_visit!(crate::walk_bounds_mut(visitor, v));
+    }
+
+    if let Some(v) = &mut node.default {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_impl_syntax_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ImplSyntax) -> Visit {
+    match node {
+        ImplSyntax::Method(v) => v.accept_mut(visitor),
+        ImplSyntax::Const(v) => v.accept_mut(visitor),
+        ImplSyntax::Type(v) => v.accept_mut(visitor),
+    }
+}
+
+pub fn walk_protocol_syntax_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut ProtocolSyntax,
+) -> Visit {
+    match node {
+        ProtocolSyntax::Method(v) => v.accept_mut(visitor),
+        ProtocolSyntax::Const(v) => v.accept_mut(visitor),
+        ProtocolSyntax::Type(v) => v.accept_mut(visitor),
+    }
+}
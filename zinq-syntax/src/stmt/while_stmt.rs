@@ -0,0 +1,183 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Colon, Eq, Let, Suffixed, While};
+
+use crate::{
+    Label, Syntax,
+    expr::{Expr, ExprParser},
+    meta::Meta,
+    pat::Pattern,
+    stmt::{BlockStmt, Stmt},
+};
+
+///
+/// ## While Statement
+/// ```
+/// while i < 10 { ... }
+/// while let Some(item) = iter.next() { ... }
+/// 'outer: while value is u8 { ... }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhileStmt {
+    pub meta: Option<Meta>,
+    pub label: Option<Suffixed<Label, Colon>>,
+    pub keyword: While,
+    pub binding: Option<(Let, Pattern, Eq)>,
+    pub cond: Expr,
+    pub body: BlockStmt,
+}
+
+impl WhileStmt {
+    pub fn is_let(&self) -> bool {
+        self.binding.is_some()
+    }
+}
+
+impl From<WhileStmt> for Stmt {
+    fn from(value: WhileStmt) -> Self {
+        Self::While(value)
+    }
+}
+
+impl Syntax for WhileStmt {
+    fn name(&self) -> &str {
+        "Stmt::While"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_while_stmt(self)
+            .then(|| crate::stmt::walk_while_stmt(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_while_stmt_mut(self)
+            .then(|| crate::stmt::walk_while_stmt_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::stmt::walk_while_stmt_fold(folder, self);
+        folder.fold_while_stmt(node)
+    }
+}
+
+impl std::fmt::Display for WhileStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for WhileStmt {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        fork_parser.parse::<Option<Suffixed<Label, Colon>>>(&mut fork)?;
+        Ok(fork_parser.peek::<While>(&fork).unwrap_or(false))
+    }
+}
+
+impl Parse for WhileStmt {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
+        let label = parser.parse::<Option<Suffixed<Label, Colon>>>(cursor)?;
+        let keyword = parser.parse::<While>(cursor)?;
+        let mut binding = None;
+
+        if parser.peek::<Let>(cursor).unwrap_or(false) {
+            let let_keyword = parser.parse::<Let>(cursor)?;
+            let pattern = parser.parse::<Pattern>(cursor)?;
+            let eq = parser.parse::<Eq>(cursor)?;
+            binding = Some((let_keyword, pattern, eq));
+        }
+
+        let cond = parser.parse_cond_expr(cursor)?;
+        let body = parser.parse::<BlockStmt>(cursor)?;
+
+        Ok(Self {
+            meta,
+            label,
+            keyword,
+            binding,
+            cond,
+            body,
+        })
+    }
+}
+
+impl Spanned for WhileStmt {
+    fn span(&self) -> Span {
+        let mut start = self.keyword.span();
+
+        if let Some(label) = &self.label {
+            start = label.span();
+        }
+
+        if let Some(meta) = &self.meta {
+            start = meta.span();
+        }
+
+        Span::join(start, self.body.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::stmt::StmtParser;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"while i < 10 { i = i + 1; }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.is_while());
+        debug_assert!(!stmt.as_while().is_let());
+        debug_assert!(stmt.as_while().cond.is_cmp());
+        debug_assert_eq!(stmt.to_string(), "while i < 10 { i = i + 1; }");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_let() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor =
+            Span::from_bytes(b"while let (key, value) = next() { print(key); }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+        let (_, pattern, _) = stmt.as_while().binding.as_ref().unwrap();
+
+        debug_assert!(stmt.as_while().is_let());
+        debug_assert_eq!(pattern.to_string(), "(key, value)");
+        debug_assert_eq!(stmt.as_while().cond.to_string(), "next()");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_is() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"outer: while value is u8 { break; }").cursor();
+        let stmt = parser.parse_stmt(&mut cursor)?;
+
+        debug_assert!(stmt.as_while().cond.is_is());
+        debug_assert_eq!(
+            stmt.as_while().label.as_ref().unwrap().name.to_string(),
+            "outer"
+        );
+        debug_assert_eq!(stmt.to_string(), "outer: while value is u8 { break; }");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{LBracket, RBracket, SemiColon, Suffixed};
+
+use crate::{Syntax, expr::ConstExpr, ty::Type};
+
+///
+/// ## Array Type
+/// `[T; N]`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType {
+    pub left_bracket: LBracket,
+    pub item_ty: Box<Type>,
+    pub semi: SemiColon,
+    pub len: ConstExpr,
+    pub right_bracket: RBracket,
+}
+
+impl From<ArrayType> for Type {
+    fn from(value: ArrayType) -> Self {
+        Self::Array(value)
+    }
+}
+
+impl Syntax for ArrayType {
+    fn name(&self) -> &str {
+        "Type::Array"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_array_type(self)
+            .then(|| crate::ty::walk_array_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_array_type_mut(self)
+            .then(|| crate::ty::walk_array_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_array_type_fold(folder, self);
+        folder.fold_array_type(node)
+    }
+}
+
+impl std::fmt::Display for ArrayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for ArrayType {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser
+            .peek::<Suffixed<LBracket, Suffixed<Type, SemiColon>>>(cursor)
+            .unwrap_or(false))
+    }
+}
+
+impl Parse for ArrayType {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let left_bracket = parser.parse::<LBracket>(cursor)?;
+        let item_ty = parser.parse::<Box<Type>>(cursor)?;
+        let semi = parser.parse::<SemiColon>(cursor)?;
+        let len = parser.parse::<ConstExpr>(cursor)?;
+        let right_bracket = parser.parse::<RBracket>(cursor)?;
+
+        Ok(Self {
+            left_bracket,
+            item_ty,
+            semi,
+            len,
+            right_bracket,
+        })
+    }
+}
+
+impl Spanned for ArrayType {
+    fn span(&self) -> Span {
+        Span::join(self.left_bracket.span(), self.right_bracket.span())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::ty::{ArrayType, Type};
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"[u32; 4]").cursor();
+        let value = parser.parse::<ArrayType>(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "[u32; 4]");
+        debug_assert_eq!(value.item_ty.to_string(), "u32");
+        debug_assert!(value.len.is_literal());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_const_len() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"[[u8; N]; N * 2]").cursor();
+        let value = parser.parse::<Type>(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "[[u8; N]; N * 2]");
+        debug_assert!(value.is_array());
+        debug_assert!(value.as_array().item_ty.is_array());
+        debug_assert!(value.as_array().len.is_arithmetic());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_slice() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"[u32]").cursor();
+        let value = parser.parse::<Type>(&mut cursor)?;
+
+        debug_assert!(value.is_slice());
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Comma, Fn, LParen, Punctuated, RArrow, RParen, Suffixed};
+
+use crate::{Syntax, ty::Type};
+
+///
+/// ## Fn Type
+/// `fn(i32, string) -> bool`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FnType {
+    pub keyword: Fn,
+    pub left_paren: LParen,
+    pub params: Punctuated<Type, Comma>,
+    pub right_paren: RParen,
+    pub return_ty: Option<Suffixed<RArrow, Box<Type>>>,
+}
+
+impl From<FnType> for Type {
+    fn from(value: FnType) -> Self {
+        Self::Fn(Box::new(value))
+    }
+}
+
+impl Syntax for FnType {
+    fn name(&self) -> &str {
+        "Type::Fn"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_fn_type(self)
+            .then(|| crate::ty::walk_fn_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_fn_type_mut(self)
+            .then(|| crate::ty::walk_fn_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_fn_type_fold(folder, self);
+        folder.fold_fn_type(node)
+    }
+}
+
+impl std::fmt::Display for FnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for FnType {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Fn>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for FnType {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let keyword = parser.parse::<Fn>(cursor)?;
+        let left_paren = parser.parse::<LParen>(cursor)?;
+        let params = parser.parse::<Punctuated<Type, Comma>>(cursor)?;
+        let right_paren = parser.parse::<RParen>(cursor)?;
+        let return_ty = parser.parse::<Option<Suffixed<RArrow, Box<Type>>>>(cursor)?;
+
+        Ok(Self {
+            keyword,
+            left_paren,
+            params,
+            right_paren,
+            return_ty,
+        })
+    }
+}
+
+impl Spanned for FnType {
+    fn span(&self) -> Span {
+        match &self.return_ty {
+            None => Span::join(self.keyword.span(), self.right_paren.span()),
+            Some(ty) => Span::join(self.keyword.span(), ty.span()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::ty::{FnType, Type};
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"fn(i32, string) -> bool").cursor();
+        let value = parser.parse::<FnType>(&mut cursor)?;
+
+        debug_assert_eq!(value.to_string(), "fn(i32, string) -> bool");
+        debug_assert_eq!(value.params.len(), 2);
+        debug_assert_eq!(value.return_ty.unwrap().suffix.to_string(), "bool");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_nested() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"(fn(), &fn(u8) -> fn() -> u8)").cursor();
+        let value = parser.parse::<Type>(&mut cursor)?;
+
+        debug_assert!(matches!(value, Type::Tuple(_)));
+        debug_assert_eq!(value.to_string(), "(fn(), &fn(u8) -> fn() -> u8)");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use zinq_token::Suffixed;
+
+use crate::Syntax;
+
+use super::*;
+
+pub trait TypeFold {
+    fn fold_type(&mut self, node: Type) -> Type {
+        node
+    }
+
+    fn fold_path_type(&mut self, node: PathType) -> PathType {
+        node
+    }
+
+    fn fold_fn_type(&mut self, node: FnType) -> FnType {
+        node
+    }
+
+    fn fold_mut_type(&mut self, node: MutType) -> MutType {
+        node
+    }
+
+    fn fold_ref_type(&mut self, node: RefType) -> RefType {
+        node
+    }
+
+    fn fold_slice_type(&mut self, node: SliceType) -> SliceType {
+        node
+    }
+
+    fn fold_array_type(&mut self, node: ArrayType) -> ArrayType {
+        node
+    }
+
+    fn fold_tuple_type(&mut self, node: TupleType) -> TupleType {
+        node
+    }
+
+    fn fold_macro_type(&mut self, node: MacroType) -> MacroType {
+        node
+    }
+}
+
+pub fn walk_type_fold<F: crate::Fold>(folder: &mut F, node: Type) -> Type {
+    match node {
+        Type::Path(v) => Type::Path(v.fold(folder)),
+        Type::Fn(v) => Type::Fn(Box::new((*v).fold(folder))),
+        Type::Mut(v) => Type::Mut(v.fold(folder)),
+        Type::Ref(v) => Type::Ref(v.fold(folder)),
+        Type::Slice(v) => Type::Slice(v.fold(folder)),
+        Type::Array(v) => Type::Array(v.fold(folder)),
+        Type::Tuple(v) => Type::Tuple(v.fold(folder)),
+        Type::Macro(v) => Type::Macro(Box::new((*v).fold(folder))),
+    }
+}
+
+pub fn walk_path_type_fold<F: crate::Fold>(folder: &mut F, node: PathType) -> PathType {
+    PathType {
+        inner: crate::walk_path_fold(folder, node.inner),
+    }
+}
+
+pub fn walk_fn_type_fold<F: crate::Fold>(folder: &mut F, node: FnType) -> FnType {
+    FnType {
+        params: node.params.map(|v| v.fold(folder)),
+        return_ty: node.return_ty.map(|v| Suffixed {
+            suffix: Box::new((*v.suffix).fold(folder)),
+            ..v
+        }),
+        ..node
+    }
+}
+
+pub fn walk_mut_type_fold<F: crate::Fold>(folder: &mut F, node: MutType) -> MutType {
+    MutType {
+        ty: Box::new((*node.ty).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_ref_type_fold<F: crate::Fold>(folder: &mut F, node: RefType) -> RefType {
+    RefType {
+        to: Box::new((*node.to).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_slice_type_fold<F: crate::Fold>(folder: &mut F, node: SliceType) -> SliceType {
+    SliceType {
+        item_ty: Box::new((*node.item_ty).fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_array_type_fold<F: crate::Fold>(folder: &mut F, node: ArrayType) -> ArrayType {
+    ArrayType {
+        item_ty: Box::new((*node.item_ty).fold(folder)),
+        len: node.len.fold(folder),
+        ..node
+    }
+}
+
+pub fn walk_tuple_type_fold<F: crate::Fold>(folder: &mut F, node: TupleType) -> TupleType {
+    TupleType {
+        items: node.items.map(|v| v.fold(folder)),
+        ..node
+    }
+}
+
+pub fn walk_macro_type_fold<F: crate::Fold>(_folder: &mut F, node: MacroType) -> MacroType {
+    node
+}
@@ -0,0 +1,93 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+
+use crate::{Syntax, macros::MacroCall, ty::Type};
+
+///
+/// ## Macro Type
+/// `pair!(u8)`, expanded before lowering
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroType {
+    pub call: MacroCall,
+}
+
+impl From<MacroType> for Type {
+    fn from(value: MacroType) -> Self {
+        Self::Macro(Box::new(value))
+    }
+}
+
+impl Syntax for MacroType {
+    fn name(&self) -> &str {
+        "Type::Macro"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_type(self)
+            .then(|| crate::ty::walk_macro_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_macro_type_mut(self)
+            .then(|| crate::ty::walk_macro_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_macro_type_fold(folder, self);
+        folder.fold_macro_type(node)
+    }
+}
+
+impl std::fmt::Display for MacroType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for MacroType {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<MacroCall>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for MacroType {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let call = parser.parse::<MacroCall>(cursor)?;
+        Ok(Self { call })
+    }
+}
+
+impl Spanned for MacroType {
+    fn span(&self) -> Span {
+        self.call.span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::ty::Type;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"pair![u8]").cursor();
+        let ty = parser.parse::<Type>(&mut cursor)?;
+
+        debug_assert!(ty.is_macro());
+        debug_assert_eq!(ty.to_string(), "pair![u8]");
+        debug_assert_eq!(ty.as_macro().call.name(), "pair");
+
+        Ok(())
+    }
+}
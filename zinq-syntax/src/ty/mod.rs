@@ -1,16 +1,26 @@
+mod array_type;
+mod fn_type;
+mod fold;
+mod macro_type;
 mod mut_type;
 mod path_type;
 mod ref_type;
 mod slice_type;
 mod tuple_type;
 mod visitor;
+mod visitor_mut;
 
+pub use array_type::*;
+pub use fn_type::*;
+pub use fold::*;
+pub use macro_type::*;
 pub use mut_type::*;
 pub use path_type::*;
 pub use ref_type::*;
 pub use slice_type::*;
 pub use tuple_type::*;
 pub use visitor::*;
+pub use visitor_mut::*;
 
 use zinq_error::Result;
 use zinq_parse::{Parse, Peek, Spanned};
@@ -24,33 +34,127 @@ use crate::Syntax;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Type {
     Path(PathType),
+    Fn(Box<FnType>),
     Mut(MutType),
     Ref(RefType),
     Slice(SliceType),
+    Array(ArrayType),
     Tuple(TupleType),
+    Macro(Box<MacroType>),
+}
+
+impl Type {
+    pub fn is_path(&self) -> bool {
+        matches!(self, Self::Path(_))
+    }
+
+    pub fn is_fn(&self) -> bool {
+        matches!(self, Self::Fn(_))
+    }
+
+    pub fn is_mut(&self) -> bool {
+        matches!(self, Self::Mut(_))
+    }
+
+    pub fn is_ref(&self) -> bool {
+        matches!(self, Self::Ref(_))
+    }
+
+    pub fn is_slice(&self) -> bool {
+        matches!(self, Self::Slice(_))
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::Array(_))
+    }
+
+    pub fn is_tuple(&self) -> bool {
+        matches!(self, Self::Tuple(_))
+    }
+
+    pub fn is_macro(&self) -> bool {
+        matches!(self, Self::Macro(_))
+    }
+
+    pub fn as_path(&self) -> &PathType {
+        match self {
+            Self::Path(v) => v,
+            v => panic!("expected PathType, received {}", v.name()),
+        }
+    }
+
+    pub fn as_fn(&self) -> &FnType {
+        match self {
+            Self::Fn(v) => v,
+            v => panic!("expected FnType, received {}", v.name()),
+        }
+    }
+
+    pub fn as_mut(&self) -> &MutType {
+        match self {
+            Self::Mut(v) => v,
+            v => panic!("expected MutType, received {}", v.name()),
+        }
+    }
+
+    pub fn as_slice(&self) -> &SliceType {
+        match self {
+            Self::Slice(v) => v,
+            v => panic!("expected SliceType, received {}", v.name()),
+        }
+    }
+
+    pub fn as_array(&self) -> &ArrayType {
+        match self {
+            Self::Array(v) => v,
+            v => panic!("expected ArrayType, received {}", v.name()),
+        }
+    }
+
+    pub fn as_tuple(&self) -> &TupleType {
+        match self {
+            Self::Tuple(v) => v,
+            v => panic!("expected TupleType, received {}", v.name()),
+        }
+    }
+
+    pub fn as_macro(&self) -> &MacroType {
+        match self {
+            Self::Macro(v) => v,
+            v => panic!("expected MacroType, received {}", v.name()),
+        }
+    }
 }
 
 impl Syntax for Type {
     fn name(&self) -> &str {
         match self {
             Self::Path(v) => v.name(),
+            Self::Fn(v) => v.name(),
             Self::Mut(v) => v.name(),
             Self::Ref(v) => v.name(),
             Self::Slice(v) => v.name(),
+            Self::Array(v) => v.name(),
             Self::Tuple(v) => v.name(),
+            Self::Macro(v) => v.name(),
         }
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_type(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_type(self)
+            .then(|| crate::ty::walk_type(visitor, self))
+    }
 
-        match self {
-            Self::Mut(v) => v.accept(visitor),
-            Self::Path(v) => v.accept(visitor),
-            Self::Ref(v) => v.accept(visitor),
-            Self::Slice(v) => v.accept(visitor),
-            Self::Tuple(v) => v.accept(visitor),
-        }
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_type_mut(self)
+            .then(|| crate::ty::walk_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_type_fold(folder, self);
+        folder.fold_type(node)
     }
 }
 
@@ -58,10 +162,13 @@ impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Path(v) => write!(f, "{}", v),
+            Self::Fn(v) => write!(f, "{}", v),
             Self::Mut(v) => write!(f, "{}", v),
             Self::Ref(v) => write!(f, "{}", v),
             Self::Slice(v) => write!(f, "{}", v),
+            Self::Array(v) => write!(f, "{}", v),
             Self::Tuple(v) => write!(f, "{}", v),
+            Self::Macro(v) => write!(f, "{}", v),
         }
     }
 }
@@ -91,10 +198,22 @@ impl Parse for Type {
             return Ok(parser.parse::<MutType>(cursor)?.into());
         }
 
+        if parser.peek::<FnType>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<FnType>(cursor)?.into());
+        }
+
+        if parser.peek::<MacroType>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<MacroType>(cursor)?.into());
+        }
+
         if parser.peek::<PathType>(cursor).unwrap_or(false) {
             return Ok(parser.parse::<PathType>(cursor)?.into());
         }
 
+        if parser.peek::<ArrayType>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<ArrayType>(cursor)?.into());
+        }
+
         if parser.peek::<SliceType>(cursor).unwrap_or(false) {
             return Ok(parser.parse::<SliceType>(cursor)?.into());
         }
@@ -114,10 +233,13 @@ impl Spanned for Type {
     fn span(&self) -> zinq_parse::Span {
         match self {
             Self::Path(v) => v.span(),
+            Self::Fn(v) => v.span(),
             Self::Mut(v) => v.span(),
             Self::Ref(v) => v.span(),
             Self::Slice(v) => v.span(),
+            Self::Array(v) => v.span(),
             Self::Tuple(v) => v.span(),
+            Self::Macro(v) => v.span(),
         }
     }
 }
@@ -24,9 +24,21 @@ impl Syntax for MutType {
         "Type::Mut"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_mut_type(self);
-        self.ty.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_mut_type(self)
+            .then(|| crate::ty::walk_mut_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_mut_type_mut(self)
+            .then(|| crate::ty::walk_mut_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_mut_type_fold(folder, self);
+        folder.fold_mut_type(node)
     }
 }
 
@@ -22,8 +22,21 @@ impl Syntax for PathType {
         "Type::Path"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_path_type(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_path_type(self)
+            .then(|| crate::ty::walk_path_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_path_type_mut(self)
+            .then(|| crate::ty::walk_path_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_path_type_fold(folder, self);
+        folder.fold_path_type(node)
     }
 }
 
@@ -75,4 +88,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_parse_const_args() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"std::Array::<u8, 16>").cursor();
+        let value = parser.parse::<PathType>(&mut cursor)?;
+        let args = &value.inner.last().generics.as_ref().unwrap().suffix.args;
+
+        debug_assert_eq!(value.to_string(), "std::Array::<u8, 16>");
+        debug_assert!(args[0].value().is_type());
+        debug_assert!(args[1].value().is_const());
+
+        Ok(())
+    }
 }
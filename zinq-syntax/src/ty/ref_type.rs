@@ -24,9 +24,21 @@ impl Syntax for RefType {
         "Type::Ref"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_ref_type(self);
-        self.to.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_ref_type(self)
+            .then(|| crate::ty::walk_ref_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_ref_type_mut(self)
+            .then(|| crate::ty::walk_ref_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_ref_type_fold(folder, self);
+        folder.fold_ref_type(node)
     }
 }
 
@@ -25,9 +25,21 @@ impl Syntax for SliceType {
         "Type::Slice"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_slice_type(self);
-        self.item_ty.accept(visitor);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_slice_type(self)
+            .then(|| crate::ty::walk_slice_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_slice_type_mut(self)
+            .then(|| crate::ty::walk_slice_type_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_slice_type_fold(folder, self);
+        folder.fold_slice_type(node)
     }
 }
 
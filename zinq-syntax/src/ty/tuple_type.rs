@@ -25,12 +25,21 @@ impl Syntax for TupleType {
         "Type::Tuple"
     }
 
-    fn accept<V: crate::Visitor>(&self, visitor: &mut V) {
-        visitor.visit_tuple_type(self);
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_type(self)
+            .then(|| crate::ty::walk_tuple_type(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_tuple_type_mut(self)
+            .then(|| crate::ty::walk_tuple_type_mut(visitor, self))
+    }
 
-        for item in self.items.iter() {
-            item.value().accept(visitor);
-        }
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::ty::walk_tuple_type_fold(folder, self);
+        folder.fold_tuple_type(node)
     }
 }
 
@@ -1,12 +1,111 @@
 #![allow(unused)]
 
-use crate::ty::*;
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
 
 pub trait TypeVisitor {
-    fn visit_type(&mut self, node: &Type) {}
-    fn visit_path_type(&mut self, node: &PathType) {}
-    fn visit_mut_type(&mut self, node: &MutType) {}
-    fn visit_ref_type(&mut self, node: &RefType) {}
-    fn visit_slice_type(&mut self, node: &SliceType) {}
-    fn visit_tuple_type(&mut self, node: &TupleType) {}
+    fn visit_type(&mut self, node: &Type) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_path_type(&mut self, node: &PathType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_fn_type(&mut self, node: &FnType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_mut_type(&mut self, node: &MutType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ref_type(&mut self, node: &RefType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_slice_type(&mut self, node: &SliceType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_array_type(&mut self, node: &ArrayType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_type(&mut self, node: &TupleType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_type(&mut self, node: &MacroType) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_type<V: crate::Visitor>(visitor: &mut V, node: &Type) -> Visit {
+    match node {
+        Type::Path(v) => v.accept(visitor),
+        Type::Fn(v) => v.accept(visitor),
+        Type::Mut(v) => v.accept(visitor),
+        Type::Ref(v) => v.accept(visitor),
+        Type::Slice(v) => v.accept(visitor),
+        Type::Array(v) => v.accept(visitor),
+        Type::Tuple(v) => v.accept(visitor),
+        Type::Macro(v) => v.accept(visitor),
+    }
+}
+
+pub fn walk_path_type<V: crate::Visitor>(visitor: &mut V, node: &PathType) -> Visit {
+    try_visit!(crate::walk_path(visitor, &node.inner));
+
+    Visit::Continue
+}
+
+pub fn walk_fn_type<V: crate::Visitor>(visitor: &mut V, node: &FnType) -> Visit {
+    for item in node.params.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    if let Some(v) = &node.return_ty {
+        try_visit!(v.suffix.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_mut_type<V: crate::Visitor>(visitor: &mut V, node: &MutType) -> Visit {
+    try_visit!(node.ty.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_ref_type<V: crate::Visitor>(visitor: &mut V, node: &RefType) -> Visit {
+    try_visit!(node.to.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_slice_type<V: crate::Visitor>(visitor: &mut V, node: &SliceType) -> Visit {
+    try_visit!(node.item_ty.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_array_type<V: crate::Visitor>(visitor: &mut V, node: &ArrayType) -> Visit {
+    try_visit!(node.item_ty.accept(visitor));
+    try_visit!(node.len.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_type<V: crate::Visitor>(visitor: &mut V, node: &TupleType) -> Visit {
+    for item in node.items.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_macro_type<V: crate::Visitor>(_visitor: &mut V, _node: &MacroType) -> Visit {
+    Visit::Continue
 }
@@ -0,0 +1,111 @@
+#![allow(unused)]
+
+use crate::{Syntax, Visit, visitor::try_visit};
+
+use super::*;
+
+pub trait TypeVisitorMut {
+    fn visit_type_mut(&mut self, node: &mut Type) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_path_type_mut(&mut self, node: &mut PathType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_fn_type_mut(&mut self, node: &mut FnType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_mut_type_mut(&mut self, node: &mut MutType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_ref_type_mut(&mut self, node: &mut RefType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_slice_type_mut(&mut self, node: &mut SliceType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_array_type_mut(&mut self, node: &mut ArrayType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_tuple_type_mut(&mut self, node: &mut TupleType) -> Visit {
+        Visit::Continue
+    }
+
+    fn visit_macro_type_mut(&mut self, node: &mut MacroType) -> Visit {
+        Visit::Continue
+    }
+}
+
+pub fn walk_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Type) -> Visit {
+    match node {
+        Type::Path(v) => v.accept_mut(visitor),
+        Type::Fn(v) => v.accept_mut(visitor),
+        Type::Mut(v) => v.accept_mut(visitor),
+        Type::Ref(v) => v.accept_mut(visitor),
+        Type::Slice(v) => v.accept_mut(visitor),
+        Type::Array(v) => v.accept_mut(visitor),
+        Type::Tuple(v) => v.accept_mut(visitor),
+        Type::Macro(v) => v.accept_mut(visitor),
+    }
+}
+
+pub fn walk_path_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut PathType) -> Visit {
+    try_visit!(crate::walk_path_mut(visitor, &mut node.inner));
+
+    Visit::Continue
+}
+
+pub fn walk_fn_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut FnType) -> Visit {
+    for item in node.params.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    if let Some(v) = &mut node.return_ty {
+        try_visit!(v.suffix.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_mut_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut MutType) -> Visit {
+    try_visit!(node.ty.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_ref_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut RefType) -> Visit {
+    try_visit!(node.to.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_slice_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut SliceType) -> Visit {
+    try_visit!(node.item_ty.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_array_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ArrayType) -> Visit {
+    try_visit!(node.item_ty.accept_mut(visitor));
+    try_visit!(node.len.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_tuple_type_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut TupleType) -> Visit {
+    for item in node.items.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_macro_type_mut<V: crate::VisitorMut>(_visitor: &mut V, _node: &mut MacroType) -> Visit {
+    Visit::Continue
+}
@@ -1,15 +1,39 @@
 use zinq_parse::{Parse, Peek, Span, Spanned};
 use zinq_token::{Eq, Ident};
 
-use crate::{expr::Expr, fields::Fields};
+use crate::{Syntax, expr::Expr, fields::Fields, meta::Meta};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Variant {
+    pub meta: Option<Meta>,
     pub name: Ident,
     pub fields: Fields,
     pub discriminant: Option<(Eq, Expr)>,
 }
 
+impl Syntax for Variant {
+    fn name(&self) -> &str {
+        "Variant"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_variant(self)
+            .then(|| crate::walk_variant(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_variant_mut(self)
+            .then(|| crate::walk_variant_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::walk_variant_fold(folder, self);
+        folder.fold_variant(node)
+    }
+}
+
 impl std::fmt::Display for Variant {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.span())
@@ -21,7 +45,11 @@ impl Peek for Variant {
         cursor: &zinq_parse::Cursor,
         parser: &zinq_parse::ZinqParser,
     ) -> zinq_error::Result<bool> {
-        Ok(parser.peek::<Ident>(cursor).unwrap_or(false))
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        fork_parser.parse::<Option<Meta>>(&mut fork)?;
+        Ok(fork_parser.peek::<Ident>(&fork).unwrap_or(false))
     }
 }
 
@@ -30,6 +58,7 @@ impl Parse for Variant {
         cursor: &mut zinq_parse::Cursor,
         parser: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
+        let meta = parser.parse::<Option<Meta>>(cursor)?;
         let name = parser.parse::<Ident>(cursor)?;
         let fields = parser.parse::<Fields>(cursor)?;
         let mut discriminant = None;
@@ -41,6 +70,7 @@ impl Parse for Variant {
         }
 
         Ok(Self {
+            meta,
             name,
             fields,
             discriminant,
@@ -50,10 +80,14 @@ impl Parse for Variant {
 
 impl Spanned for Variant {
     fn span(&self) -> zinq_parse::Span {
-        if let Some(v) = &self.discriminant {
-            return Span::join(self.name.span(), v.1.span());
-        }
+        let start = match &self.meta {
+            None => self.name.span(),
+            Some(meta) => meta.span(),
+        };
 
-        Span::join(self.name.span(), self.fields.span())
+        match &self.discriminant {
+            None => Span::join(start, self.fields.span()),
+            Some(v) => Span::join(start, v.1.span()),
+        }
     }
 }
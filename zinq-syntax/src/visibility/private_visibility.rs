@@ -35,7 +35,7 @@ impl Parse for PrivateVisibility {
         _: &mut zinq_parse::ZinqParser,
     ) -> zinq_error::Result<Self> {
         Ok(Self {
-            span: cursor.span().clone(),
+            span: cursor.source_span(),
         })
     }
 }
@@ -0,0 +1,322 @@
+use crate::{
+    Bounds, GenericArg, GenericArgs, Generics, Path, SourceFile, Syntax, Variant, WhereClause,
+    WherePredicate,
+    members::MemberValue,
+    spread::{ExprSpread, TypeSpread},
+};
+
+///
+/// ## Visit
+/// returned by visitor hooks to steer the walk
+/// - `Continue` walk the children of the node
+/// - `Skip` leave the children out
+/// - `Stop` end the walk
+///
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Visit {
+    #[default]
+    Continue,
+    Skip,
+    Stop,
+}
+
+impl Visit {
+    pub fn is_continue(&self) -> bool {
+        matches!(self, Self::Continue)
+    }
+
+    pub fn is_skip(&self) -> bool {
+        matches!(self, Self::Skip)
+    }
+
+    pub fn is_stop(&self) -> bool {
+        matches!(self, Self::Stop)
+    }
+
+    ///
+    /// ## then
+    /// walk the children with `f` if the hook
+    /// returned `Continue`, a skipped node
+    /// counts as walked
+    ///
+    pub fn then<F: FnOnce() -> Self>(self, f: F) -> Self {
+        match self {
+            Self::Continue => f(),
+            Self::Skip => Self::Continue,
+            Self::Stop => Self::Stop,
+        }
+    }
+}
+
+// return from the walk once a child stopped it
+macro_rules! try_visit {
+    ($visit:expr) => {
+        if $visit.is_stop() {
+            return crate::Visit::Stop;
+        }
+    };
+}
+
+pub(crate) use try_visit;
+
+pub fn walk_source_file<V: crate::Visitor>(visitor: &mut V, node: &SourceFile) -> Visit {
+    for v in &node.attrs {
+        try_visit!(v.accept(visitor));
+    }
+
+    for v in &node.items {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_generics<V: crate::Visitor>(visitor: &mut V, node: &Generics) -> Visit {
+    for item in node.params.iter() {
+        try_visit!(item.value().accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_where_clause<V: crate::Visitor>(visitor: &mut V, node: &WhereClause) -> Visit {
+    for item in node.predicates.iter() {
+        try_visit!(walk_where_predicate(visitor, item.value()));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_variant<V: crate::Visitor>(visitor: &mut V, node: &Variant) -> Visit {
+    if let Some(v) = &node.meta {
+        try_visit!(v.accept(visitor));
+    }
+
+    try_visit!(node.fields.accept(visitor));
+
+    if let Some((_, v)) = &node.discriminant {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_path<V: crate::Visitor>(visitor: &mut V, node: &Path) -> Visit {
+    for item in node.iter() {
+        if let Some(v) = &item.value().generics {
+            try_visit!(walk_generic_args(visitor, &v.suffix));
+        }
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_generic_args<V: crate::Visitor>(visitor: &mut V, node: &GenericArgs) -> Visit {
+    for item in node.args.iter() {
+        match item.value() {
+            GenericArg::Type(v) => try_visit!(v.accept(visitor)),
+            GenericArg::Const(v) => try_visit!(v.accept(visitor)),
+        }
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_bounds<V: crate::Visitor>(visitor: &mut V, node: &Bounds) -> Visit {
+    for item in node.items.iter() {
+        try_visit!(walk_path(visitor, item.value()));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_where_predicate<V: crate::Visitor>(visitor: &mut V, node: &WherePredicate) -> Visit {
+    try_visit!(node.ty.accept(visitor));
+    try_visit!(walk_bounds(visitor, &node.bounds));
+
+    Visit::Continue
+}
+
+pub fn walk_member_value<V: crate::Visitor>(visitor: &mut V, node: &MemberValue) -> Visit {
+    if let Some(v) = &node.expr {
+        try_visit!(v.accept(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_expr_spread<V: crate::Visitor>(visitor: &mut V, node: &ExprSpread) -> Visit {
+    try_visit!(node.expr.accept(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_type_spread<V: crate::Visitor>(visitor: &mut V, node: &TypeSpread) -> Visit {
+    try_visit!(node.ty.accept(visitor));
+
+    Visit::Continue
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::{
+        SourceFile, Syntax, Variant, Visit, Visitor,
+        expr::{ExprVisitor, LiteralExpr, MatchVisitor},
+        fields::{FieldsVisitor, NameField},
+        meta::{Attribute, MetaVisitor},
+        param::{ParamVisitor, TypeParam},
+        pat::PatternVisitor,
+        stmt::{FnStmt, ImplMethod, StmtVisitor},
+        ty::{PathType, TypeVisitor},
+    };
+
+    #[derive(Default)]
+    struct Names {
+        items: Vec<String>,
+        skip: Option<String>,
+        stop: Option<String>,
+    }
+
+    impl Names {
+        fn push(&mut self, name: String) -> Visit {
+            let visit = if self.stop.as_ref() == Some(&name) {
+                Visit::Stop
+            } else if self.skip.as_ref() == Some(&name) {
+                Visit::Skip
+            } else {
+                Visit::Continue
+            };
+
+            self.items.push(name);
+            visit
+        }
+    }
+
+    impl TypeVisitor for Names {
+        fn visit_path_type(&mut self, node: &PathType) -> Visit {
+            self.push(format!("type {}", node))
+        }
+    }
+
+    impl StmtVisitor for Names {
+        fn visit_fn_stmt(&mut self, node: &FnStmt) -> Visit {
+            self.push(format!("fn {}", node.name))
+        }
+
+        fn visit_impl_method(&mut self, node: &ImplMethod) -> Visit {
+            self.push(format!("method {}", node.name))
+        }
+    }
+
+    impl ExprVisitor for Names {
+        fn visit_literal_expr(&mut self, node: &LiteralExpr) -> Visit {
+            self.push(format!("literal {}", node))
+        }
+    }
+
+    impl MatchVisitor for Names {}
+    impl PatternVisitor for Names {}
+
+    impl ParamVisitor for Names {
+        fn visit_type_param(&mut self, node: &TypeParam) -> Visit {
+            self.push(format!("param {}", node.ident))
+        }
+    }
+
+    impl FieldsVisitor for Names {
+        fn visit_name_field(&mut self, node: &NameField) -> Visit {
+            self.push(format!("field {}", node.name))
+        }
+    }
+
+    impl MetaVisitor for Names {
+        fn visit_attribute(&mut self, node: &Attribute) -> Visit {
+            self.push(format!("attr {}", node.path))
+        }
+    }
+
+    impl Visitor for Names {
+        fn visit_variant(&mut self, node: &Variant) -> Visit {
+            self.push(format!("variant {}", node.name))
+        }
+    }
+
+    fn parse_file(src: &str) -> Result<SourceFile> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_str(src).cursor();
+        parser.parse::<SourceFile>(&mut cursor)
+    }
+
+    #[test]
+    fn should_visit_every_child() -> Result<()> {
+        let file = parse_file(
+            "#[Clone]
+struct User<T> { #[Key] id: u8, name: T }
+
+enum Shape { Circle { r: f32 }, Unit = 2 }
+
+impl User::<u8> {
+    fn id(self) -> u8 { 1 }
+}",
+        )?;
+
+        let mut names = Names::default();
+
+        debug_assert_eq!(file.accept(&mut names), Visit::Continue);
+        debug_assert_eq!(
+            names.items,
+            vec![
+                "attr Clone",
+                "param T",
+                "field id",
+                "attr Key",
+                "type u8",
+                "field name",
+                "type T",
+                "variant Circle",
+                "field r",
+                "type f32",
+                "variant Unit",
+                "literal 2",
+                "type User::<u8>",
+                "type u8",
+                "method id",
+                "type u8",
+                "literal 1",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_skip_children() -> Result<()> {
+        let file = parse_file("fn a() { 1 }\nfn b() { 2 }")?;
+        let mut names = Names {
+            skip: Some("fn a".to_string()),
+            ..Names::default()
+        };
+
+        debug_assert_eq!(file.accept(&mut names), Visit::Continue);
+        debug_assert_eq!(names.items, vec!["fn a", "fn b", "literal 2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_stop() -> Result<()> {
+        let file = parse_file("fn a() { 1 + 2 }\nfn b() { 3 }")?;
+        let mut names = Names {
+            stop: Some("literal 1".to_string()),
+            ..Names::default()
+        };
+
+        debug_assert_eq!(file.accept(&mut names), Visit::Stop);
+        debug_assert_eq!(names.items, vec!["fn a", "literal 1"]);
+
+        Ok(())
+    }
+}
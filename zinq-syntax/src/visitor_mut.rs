@@ -0,0 +1,176 @@
+use crate::{
+    Bounds, GenericArg, GenericArgs, Generics, Path, SourceFile, Syntax, Variant, WhereClause,
+    WherePredicate,
+    members::MemberValue,
+    spread::{ExprSpread, TypeSpread},
+};
+
+use crate::{Visit, visitor::try_visit};
+
+pub fn walk_source_file_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut SourceFile) -> Visit {
+    for v in &mut node.attrs {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    for v in &mut node.items {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_generics_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Generics) -> Visit {
+    for item in node.params.iter_mut() {
+        try_visit!(item.value_mut().accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_where_clause_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut WhereClause,
+) -> Visit {
+    for item in node.predicates.iter_mut() {
+        try_visit!(walk_where_predicate_mut(visitor, item.value_mut()));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_variant_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Variant) -> Visit {
+    if let Some(v) = &mut node.meta {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    try_visit!(node.fields.accept_mut(visitor));
+
+    if let Some((_, v)) = &mut node.discriminant {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_path_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Path) -> Visit {
+    for item in node.iter_mut() {
+        if let Some(v) = &mut item.value_mut().generics {
+            try_visit!(walk_generic_args_mut(visitor, &mut v.suffix));
+        }
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_generic_args_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut GenericArgs,
+) -> Visit {
+    for item in node.args.iter_mut() {
+        match item.value_mut() {
+            GenericArg::Type(v) => try_visit!(v.accept_mut(visitor)),
+            GenericArg::Const(v) => try_visit!(v.accept_mut(visitor)),
+        }
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_bounds_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut Bounds) -> Visit {
+    for item in node.items.iter_mut() {
+        try_visit!(walk_path_mut(visitor, item.value_mut()));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_where_predicate_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut WherePredicate,
+) -> Visit {
+    try_visit!(node.ty.accept_mut(visitor));
+    try_visit!(walk_bounds_mut(visitor, &mut node.bounds));
+
+    Visit::Continue
+}
+
+pub fn walk_member_value_mut<V: crate::VisitorMut>(
+    visitor: &mut V,
+    node: &mut MemberValue,
+) -> Visit {
+    if let Some(v) = &mut node.expr {
+        try_visit!(v.accept_mut(visitor));
+    }
+
+    Visit::Continue
+}
+
+pub fn walk_expr_spread_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut ExprSpread) -> Visit {
+    try_visit!(node.expr.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+pub fn walk_type_spread_mut<V: crate::VisitorMut>(visitor: &mut V, node: &mut TypeSpread) -> Visit {
+    try_visit!(node.ty.accept_mut(visitor));
+
+    Visit::Continue
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::{
+        Syntax, Visit, VisitorMut,
+        expr::{Expr, ExprParser, ExprVisitorMut, MatchVisitorMut},
+        fields::FieldsVisitorMut,
+        meta::MetaVisitorMut,
+        param::ParamVisitorMut,
+        pat::PatternVisitorMut,
+        stmt::StmtVisitorMut,
+        ty::TypeVisitorMut,
+    };
+
+    struct Inline {
+        value: Expr,
+    }
+
+    impl ExprVisitorMut for Inline {
+        fn visit_expr_mut(&mut self, node: &mut Expr) -> Visit {
+            if node.is_path() {
+                *node = self.value.clone();
+            }
+
+            Visit::Continue
+        }
+    }
+
+    impl TypeVisitorMut for Inline {}
+    impl StmtVisitorMut for Inline {}
+    impl MatchVisitorMut for Inline {}
+    impl PatternVisitorMut for Inline {}
+    impl ParamVisitorMut for Inline {}
+    impl FieldsVisitorMut for Inline {}
+    impl MetaVisitorMut for Inline {}
+    impl VisitorMut for Inline {}
+
+    #[test]
+    fn should_edit_in_place() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let value = parser.parse_expr(&mut Span::from_bytes(b"1").cursor())?;
+        let mut expr = parser.parse_expr(&mut Span::from_bytes(b"a + (b * 2)").cursor())?;
+        let mut inline = Inline { value };
+
+        debug_assert_eq!(expr.accept_mut(&mut inline), Visit::Continue);
+        debug_assert!(expr.as_arithmetic().left.is_literal());
+        debug_assert!(expr.as_arithmetic().right.is_group());
+
+        let group = expr.as_arithmetic().right.as_group();
+
+        debug_assert!(group.inner.as_arithmetic().left.is_literal());
+        debug_assert!(group.inner.as_arithmetic().right.is_literal());
+        Ok(())
+    }
+}
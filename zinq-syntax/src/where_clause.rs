@@ -0,0 +1,147 @@
+use zinq_parse::{Parse, Peek, Span, Spanned};
+use zinq_token::{Comma, Punctuated, Where};
+
+use crate::{Bounds, Syntax, ty::Type};
+
+///
+/// ## Where Clause
+/// bounds declared after the signature
+///
+/// ```
+/// fn print<T>(value: T) where T: Display + Debug { ... }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhereClause {
+    pub keyword: Where,
+    pub predicates: Punctuated<WherePredicate, Comma>,
+}
+
+impl Syntax for WhereClause {
+    fn name(&self) -> &str {
+        "WhereClause"
+    }
+
+    fn accept<V: crate::Visitor>(&self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_where_clause(self)
+            .then(|| crate::walk_where_clause(visitor, self))
+    }
+
+    fn accept_mut<V: crate::VisitorMut>(&mut self, visitor: &mut V) -> crate::Visit {
+        visitor
+            .visit_where_clause_mut(self)
+            .then(|| crate::walk_where_clause_mut(visitor, self))
+    }
+
+    fn fold<F: crate::Fold>(self, folder: &mut F) -> Self {
+        let node = crate::walk_where_clause_fold(folder, self);
+        folder.fold_where_clause(node)
+    }
+}
+
+impl std::fmt::Display for WhereClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for WhereClause {
+    fn span(&self) -> zinq_parse::Span {
+        Span::join(self.keyword.span(), self.predicates.span())
+    }
+}
+
+impl Peek for WhereClause {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        Ok(parser.peek::<Where>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for WhereClause {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let keyword = parser.parse::<Where>(cursor)?;
+        let predicates = parser.parse::<Punctuated<WherePredicate, Comma>>(cursor)?;
+
+        Ok(Self {
+            keyword,
+            predicates,
+        })
+    }
+}
+
+///
+/// ## Where Predicate
+/// `<type>: <bound1> + <bound2>`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WherePredicate {
+    pub ty: Type,
+    pub bounds: Bounds,
+}
+
+impl std::fmt::Display for WherePredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Spanned for WherePredicate {
+    fn span(&self) -> zinq_parse::Span {
+        Span::join(self.ty.span(), self.bounds.span())
+    }
+}
+
+impl Peek for WherePredicate {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        match fork_parser.parse::<Self>(&mut fork) {
+            Err(_) => Ok(false),
+            Ok(_) => Ok(true),
+        }
+    }
+}
+
+impl Parse for WherePredicate {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let ty = parser.parse::<Type>(cursor)?;
+        let bounds = parser.parse::<Bounds>(cursor)?;
+
+        Ok(Self { ty, bounds })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::WhereClause;
+
+    #[test]
+    fn should_parse() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"where T: Display + Debug, &U: Cmp {").cursor();
+        let syntax = parser.parse::<WhereClause>(&mut cursor)?;
+
+        debug_assert_eq!(syntax.predicates.len(), 2);
+        debug_assert_eq!(syntax.predicates[0].value().bounds.items.len(), 2);
+        debug_assert_eq!(syntax.to_string(), "where T: Display + Debug, &U: Cmp");
+
+        Ok(())
+    }
+}
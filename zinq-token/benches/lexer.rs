@@ -0,0 +1,72 @@
+//!
+//! ## Lexer Benchmark
+//! compares the table driven `Lexer` with trial parsing
+//! a `TokenStream` on a large generated source file
+//!
+//! `cargo bench -p zinq-token --bench lexer`
+//!
+
+use std::time::{Duration, Instant};
+
+use zinq_parse::Span;
+use zinq_token::{Lexer, TokenStream};
+
+const CHUNK: &str = "#[Print(pretty)]
+pub struct User<T: ToString + Cmp> {
+    pub(mod) name: string,
+    age: u8,
+}
+
+impl User {
+    fn birthday(&mut self) -> bool {
+        let ok = self.age >= 18u8 && !false || 1.5f32 <= 2.0;
+        self.age += 1;
+        self.items[0..2] == b'a';
+        match ok { true => \"yes\", _ => \"no\" }
+    }
+}
+";
+
+const REPEAT: usize = 200;
+const SAMPLES: u32 = 5;
+
+fn measure<T, F: FnMut() -> T>(mut f: F) -> (T, Duration) {
+    let mut value = f();
+    let mut total = Duration::ZERO;
+
+    for _ in 0..SAMPLES {
+        let start = Instant::now();
+        value = f();
+        total += start.elapsed();
+    }
+
+    (value, total / SAMPLES)
+}
+
+fn main() {
+    // trial parsing rejects trailing whitespace
+    let src = CHUNK.repeat(REPEAT).trim_end().to_string();
+    let span = Span::from_str(&src);
+
+    let (trial, trial_time) = measure(|| {
+        let mut cursor = span.cursor();
+        let mut parser = zinq_parse::ZinqParser;
+
+        parser
+            .parse::<TokenStream>(&mut cursor)
+            .expect("trial parsing failed")
+            .to_vec()
+    });
+
+    let (lexed, lexer_time) = measure(|| Lexer::new(&span).lex().expect("lexing failed"));
+
+    assert_eq!(trial, lexed, "lexer output differs from trial parsing");
+
+    println!("source:  {} bytes, {} tokens", src.len(), lexed.len());
+    println!("trial:   {:?}", trial_time);
+    println!("lexer:   {:?}", lexer_time);
+    println!(
+        "speedup: {:.1}x",
+        trial_time.as_secs_f64() / lexer_time.as_secs_f64()
+    );
+}
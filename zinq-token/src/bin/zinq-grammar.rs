@@ -0,0 +1,28 @@
+//!
+//! ## zinq-grammar
+//! write the editor grammars generated from the
+//! token tables into a directory
+//!
+//! `cargo run -p zinq-token --bin zinq-grammar -- ./editors`
+//!
+
+use zinq_token::Grammar;
+
+fn main() -> std::io::Result<()> {
+    let dir = match std::env::args().nth(1) {
+        Some(v) => std::path::PathBuf::from(v),
+        None => {
+            eprintln!("usage: zinq-grammar <out-dir>");
+            std::process::exit(1);
+        }
+    };
+
+    let grammar = Grammar::new();
+
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("zinq.tmLanguage.json"), grammar.to_textmate())?;
+    std::fs::write(dir.join("grammar.js"), grammar.to_tree_sitter())?;
+
+    println!("wrote {}", dir.display());
+    Ok(())
+}
@@ -37,6 +37,22 @@ impl Delim {
     }
 }
 
+impl OpenDelim {
+    ///
+    /// ## is_closed_by
+    /// whether `close` is the matching
+    /// delimiter for this one
+    ///
+    pub fn is_closed_by(&self, close: &CloseDelim) -> bool {
+        matches!(
+            (self, close),
+            (Self::LParen(_), CloseDelim::RParen(_))
+                | (Self::LBracket(_), CloseDelim::RBracket(_))
+                | (Self::LBrace(_), CloseDelim::RBrace(_))
+        )
+    }
+}
+
 impl Token {
     pub fn is_open_delim(&self) -> bool {
         match self {
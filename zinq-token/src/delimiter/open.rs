@@ -1,5 +1,5 @@
 macro_rules! define_open_delimiters {
-    ($($token:literal, pub struct $name:ident, $is_method:ident),*) => {
+    ($($token:literal, pub struct $name:ident, $is_method:ident, $new_method:ident),*) => {
         #[derive(Debug, Clone, PartialEq, Eq, Hash)]
         pub enum OpenDelim {
             $($name($name),)*
@@ -24,6 +24,29 @@ macro_rules! define_open_delimiters {
             )*
         }
 
+        impl OpenDelim {
+            $(
+                #[doc = "a `"]
+                #[doc = $token]
+                #[doc = "` with a generated span"]
+                #[inline]
+                pub fn $new_method() -> Self {
+                    Self::$name($name::new())
+                }
+            )*
+        }
+
+        impl OpenDelim {
+            ///
+            /// ## TABLE
+            /// every open delimiter in declaration order, paired
+            /// with a constructor used by the `Lexer`
+            ///
+            pub(crate) const TABLE: &'static [(&'static str, fn(zinq_parse::Span) -> $crate::Token)] = &[
+                $(($token, |span| $crate::Token::from($name { span })),)*
+            ];
+        }
+
         impl zinq_parse::Peek for OpenDelim {
             #[inline]
             fn peek(cursor: &zinq_parse::Cursor, parser: &zinq_parse::ZinqParser) -> zinq_error::Result<bool> {
@@ -97,11 +120,26 @@ macro_rules! define_open_delimiters {
             }
 
             impl $name {
+                #[doc = "a `"]
+                #[doc = $token]
+                #[doc = "` with a generated span"]
+                pub fn new() -> Self {
+                    Self {
+                        span: zinq_parse::Span::generated($token),
+                    }
+                }
+
                 pub fn name(&self) -> &'static str {
                     stringify!($name)
                 }
             }
 
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
             impl zinq_parse::Peek for $name {
                 #[inline]
                 fn peek(cursor: &zinq_parse::Cursor, _: &zinq_parse::ZinqParser) -> zinq_error::Result<bool> {
@@ -117,7 +155,7 @@ macro_rules! define_open_delimiters {
                     }
 
                     Ok(Self {
-                        span: cursor.span().clone(),
+                        span: cursor.source_span(),
                     })
                 }
             }
@@ -205,7 +243,7 @@ macro_rules! define_open_delimiters {
 }
 
 define_open_delimiters! {
-    "{",    pub struct LBrace,      is_left_brace,
-    "[",    pub struct LBracket,    is_left_bracket,
-    "(",    pub struct LParen,      is_left_paren
+    "{",    pub struct LBrace,      is_left_brace,   left_brace,
+    "[",    pub struct LBracket,    is_left_bracket, left_bracket,
+    "(",    pub struct LParen,      is_left_paren,   left_paren
 }
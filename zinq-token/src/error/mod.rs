@@ -1,3 +1,5 @@
 mod token_mismatch_error;
+mod unbalanced_delim_error;
 
 pub use token_mismatch_error::*;
+pub use unbalanced_delim_error::*;
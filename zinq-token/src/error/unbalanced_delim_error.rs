@@ -0,0 +1,117 @@
+use std::rc::Rc;
+
+use zinq_error::{BAD_ARGUMENTS, Error, ZinqError};
+use zinq_parse::{EOF, ParseError, Span};
+
+///
+/// ## UnbalancedDelimError
+/// an open delimiter without its matching close,
+/// or the reverse, carrying the span of both
+/// sides when there are two
+///
+#[derive(Debug, Clone)]
+pub struct UnbalancedDelimError {
+    open: Option<Span>,
+    close: Option<Span>,
+    inner: ParseError,
+}
+
+impl UnbalancedDelimError {
+    #[inline]
+    pub fn mismatched(open: Span, close: Span) -> Self {
+        Self {
+            inner: ParseError::from_error(
+                Span::join(open.clone(), close.clone()),
+                Error::new()
+                    .code(BAD_ARGUMENTS)
+                    .message(format!(
+                        "mismatched delimiters, '{}' ({}) closed by '{}' ({})",
+                        open,
+                        open.start(),
+                        close,
+                        close.start()
+                    ))
+                    .build()
+                    .into(),
+            ),
+            open: Some(open),
+            close: Some(close),
+        }
+    }
+
+    #[inline]
+    pub fn unclosed(open: Span) -> Self {
+        Self {
+            inner: ParseError::from_error(
+                open.clone(),
+                Error::new()
+                    .code(EOF)
+                    .message(format!("unclosed delimiter '{}' ({})", open, open.start()))
+                    .build()
+                    .into(),
+            ),
+            open: Some(open),
+            close: None,
+        }
+    }
+
+    #[inline]
+    pub fn unexpected(close: Span) -> Self {
+        Self {
+            inner: ParseError::from_error(
+                close.clone(),
+                Error::new()
+                    .code(BAD_ARGUMENTS)
+                    .message(format!(
+                        "unexpected closing delimiter '{}' ({})",
+                        close,
+                        close.start()
+                    ))
+                    .build()
+                    .into(),
+            ),
+            open: None,
+            close: Some(close),
+        }
+    }
+
+    #[inline]
+    pub fn open(&self) -> Option<&Span> {
+        self.open.as_ref()
+    }
+
+    #[inline]
+    pub fn close(&self) -> Option<&Span> {
+        self.close.as_ref()
+    }
+
+    #[inline]
+    pub fn span(&self) -> &Span {
+        self.inner.span()
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &ParseError {
+        &self.inner
+    }
+}
+
+impl std::fmt::Display for UnbalancedDelimError {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner())
+    }
+}
+
+impl std::error::Error for UnbalancedDelimError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+impl From<UnbalancedDelimError> for ZinqError {
+    fn from(value: UnbalancedDelimError) -> Self {
+        ZinqError::Std(Rc::new(value))
+    }
+}
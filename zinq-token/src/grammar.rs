@@ -0,0 +1,361 @@
+use crate::{CloseDelim, Keyword, LBool, LFloat, LInt, OpenDelim, Punct, is_ident_byte};
+
+///
+/// ## literals
+/// the literal rules recognized by the `Lexer`,
+/// as `(name, scope, pattern, is_word)`, suffixes
+/// and words come from the literal tables
+///
+fn literals() -> Vec<(&'static str, &'static str, String, bool)> {
+    vec![
+        (
+            "string",
+            "string.quoted.double",
+            r#""[^"]*""#.to_string(),
+            false,
+        ),
+        ("byte", "constant.character", r"b'.'".to_string(), false),
+        ("char", "constant.character", r"'[^'\\]'".to_string(), false),
+        (
+            "float",
+            "constant.numeric.float",
+            format!(r"\d+\.\d+({})?", LFloat::SUFFIXES.join("|")),
+            true,
+        ),
+        (
+            "integer",
+            "constant.numeric.integer",
+            format!(r"\d+({})?", LInt::SUFFIXES.join("|")),
+            true,
+        ),
+        (
+            "boolean",
+            "constant.language.boolean",
+            LBool::TABLE.join("|"),
+            true,
+        ),
+    ]
+}
+
+const IDENT: &str = r"(r#)?[A-Za-z_][A-Za-z0-9_]*";
+
+///
+/// ## Grammar
+/// editor grammars generated from the same token
+/// tables the `Lexer` uses, so they never drift
+///
+/// ```
+/// let grammar = Grammar::new();
+/// std::fs::write("zinq.tmLanguage.json", grammar.to_textmate())?;
+/// std::fs::write("grammar.js", grammar.to_tree_sitter())?;
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Grammar {
+    keywords: Vec<&'static str>,
+    contextual: Vec<&'static str>,
+    puncts: Vec<&'static str>,
+    delimiters: Vec<&'static str>,
+}
+
+impl Grammar {
+    pub fn new() -> Self {
+        let (keywords, contextual) = Keyword::TABLE
+            .iter()
+            .map(|(text, _)| *text)
+            .partition(|text| Keyword::is_reserved(text.as_bytes()));
+
+        let mut puncts = Punct::TABLE
+            .iter()
+            .map(|(text, _)| *text)
+            .collect::<Vec<_>>();
+
+        // longest first so alternations prefer `::` over `:`
+        puncts.sort_by_key(|text| std::cmp::Reverse(text.len()));
+
+        let delimiters = OpenDelim::TABLE
+            .iter()
+            .chain(CloseDelim::TABLE)
+            .map(|(text, _)| *text)
+            .collect();
+
+        Self {
+            keywords,
+            contextual,
+            puncts,
+            delimiters,
+        }
+    }
+
+    pub fn keywords(&self) -> &[&'static str] {
+        &self.keywords
+    }
+
+    pub fn contextual(&self) -> &[&'static str] {
+        &self.contextual
+    }
+
+    pub fn puncts(&self) -> &[&'static str] {
+        &self.puncts
+    }
+
+    pub fn delimiters(&self) -> &[&'static str] {
+        &self.delimiters
+    }
+
+    ///
+    /// ## to_textmate
+    /// a TextMate grammar (`.tmLanguage.json`)
+    /// for `source.zinq`
+    ///
+    pub fn to_textmate(&self) -> String {
+        let mut rules = vec![];
+
+        for (name, scope, pattern, is_word) in literals() {
+            let pattern = match is_word {
+                true => format!(r"\b({})\b", pattern),
+                false => pattern.to_string(),
+            };
+
+            rules.push((name.to_string(), format!("{}.zinq", scope), pattern));
+        }
+
+        rules.push((
+            "keyword".to_string(),
+            "keyword.other.zinq".to_string(),
+            format!(r"\b({})\b", self.keywords.join("|")),
+        ));
+
+        rules.push((
+            "contextual".to_string(),
+            "keyword.other.contextual.zinq".to_string(),
+            format!(r"\b({})\b", self.contextual.join("|")),
+        ));
+
+        rules.push((
+            "operator".to_string(),
+            "keyword.operator.zinq".to_string(),
+            alternation(&self.puncts),
+        ));
+
+        rules.push((
+            "delimiter".to_string(),
+            "punctuation.section.zinq".to_string(),
+            alternation(&self.delimiters),
+        ));
+
+        rules.push((
+            "identifier".to_string(),
+            "variable.other.zinq".to_string(),
+            format!(r"\b{}\b", IDENT),
+        ));
+
+        let patterns = rules
+            .iter()
+            .map(|(name, _, _)| format!("    {{ \"include\": \"#{}\" }}", name))
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        let repository = rules
+            .iter()
+            .map(|(name, scope, pattern)| {
+                format!(
+                    "    \"{}\": {{\n      \"name\": \"{}\",\n      \"match\": {}\n    }}",
+                    name,
+                    scope,
+                    json_string(pattern)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        format!(
+            "{{\n  \"name\": \"Zinq\",\n  \"scopeName\": \"source.zinq\",\n  \"fileTypes\": [\"zq\"],\n  \"patterns\": [\n{}\n  ],\n  \"repository\": {{\n{}\n  }}\n}}\n",
+            patterns, repository
+        )
+    }
+
+    ///
+    /// ## to_tree_sitter
+    /// a tree-sitter `grammar.js` skeleton
+    /// with a rule per token kind
+    ///
+    pub fn to_tree_sitter(&self) -> String {
+        let mut rules = vec![
+            "    source_file: $ => repeat($._token),".to_string(),
+            "    _token: $ => choice($.keyword, $.operator, $.delimiter, $._literal, $.identifier),"
+                .to_string(),
+            format!("    keyword: $ => choice({}),", js_strings(&self.keywords)),
+            format!("    operator: $ => choice({}),", js_strings(&self.puncts)),
+            format!("    delimiter: $ => choice({}),", js_strings(&self.delimiters)),
+            format!(
+                "    _literal: $ => choice({}),",
+                literals()
+                    .iter()
+                    .map(|(name, _, _, _)| format!("$.{}", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ];
+
+        for (name, _, pattern, _) in literals() {
+            rules.push(format!(
+                "    {}: $ => /{}/,",
+                name,
+                pattern.replace('/', r"\/")
+            ));
+        }
+
+        rules.push(format!("    identifier: $ => /{}/,", IDENT));
+
+        format!(
+            "// generated from the zinq-token tables, do not edit\n\nmodule.exports = grammar({{\n  name: 'zinq',\n  word: $ => $.identifier,\n  extras: $ => [/\\s/],\n  rules: {{\n{}\n  }},\n}});\n",
+            rules.join("\n")
+        )
+    }
+}
+
+impl Default for Grammar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn alternation(items: &[&str]) -> String {
+    let escaped = items
+        .iter()
+        .map(|item| {
+            let word = item.bytes().all(|b| is_ident_byte(&b));
+            let escaped = item
+                .chars()
+                .map(|c| match c.is_ascii_alphanumeric() || c == '_' {
+                    true => c.to_string(),
+                    false => format!(r"\{}", c),
+                })
+                .collect::<String>();
+
+            // word like puncts (`_`) never split an identifier
+            match word {
+                true => format!(r"\b{}\b", escaped),
+                false => escaped,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    escaped.join("|")
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+fn js_strings(items: &[&str]) -> String {
+    items
+        .iter()
+        .map(|item| format!("'{}'", item.replace('\\', "\\\\").replace('\'', "\\'")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use zinq_error::Result;
+
+    use crate::{Grammar, Keyword, LFloat, LInt, Punct, Token, TokenStream};
+
+    use super::literals;
+
+    type IsKind = fn(&Token) -> bool;
+
+    #[test]
+    fn should_cover_token_tables() {
+        let grammar = Grammar::new();
+
+        debug_assert_eq!(
+            grammar.keywords().len() + grammar.contextual().len(),
+            Keyword::TABLE.len()
+        );
+        debug_assert_eq!(grammar.puncts().len(), Punct::TABLE.len());
+        debug_assert_eq!(grammar.delimiters().len(), 6);
+        debug_assert!(grammar.keywords().contains(&"match"));
+        debug_assert!(grammar.contextual().contains(&"protocol"));
+        debug_assert_eq!(grammar.puncts()[0].len(), 3);
+    }
+
+    #[test]
+    fn should_export_textmate() {
+        let json = Grammar::new().to_textmate();
+
+        debug_assert!(json.contains("\"scopeName\": \"source.zinq\""));
+        debug_assert!(json.contains(r#""match": "\\b(package|"#));
+        debug_assert!(json.contains(r#"\\:\\:|"#));
+        debug_assert!(json.contains(r#"|\\b_\\b|"#));
+        debug_assert!(json.contains(r#""match": "\"[^\"]*\"""#));
+        debug_assert_eq!(json.matches('{').count(), json.matches('}').count());
+    }
+
+    #[test]
+    fn should_export_tree_sitter() {
+        let js = Grammar::new().to_tree_sitter();
+
+        debug_assert!(js.contains("name: 'zinq',"));
+        debug_assert!(js.contains("'fn'"));
+        debug_assert!(!js.contains("'protocol'"));
+        debug_assert!(js.contains("'::', "));
+        debug_assert!(js.contains(r"integer: $ => /\d+(u8|i8|u16|i16|u32|i32|u64|i64)?/,"));
+    }
+
+    #[test]
+    fn should_match_lexer_literals() -> Result<()> {
+        let samples: &[(&str, &str, IsKind)] = &[
+            ("string", "\"a b\"", Token::is_string_literal),
+            ("byte", "b'a'", Token::is_byte_literal),
+            ("char", "'é'", Token::is_char_literal),
+            ("float", "1.5", Token::is_float_literal),
+            ("integer", "15", Token::is_int_literal),
+            ("boolean", "false", Token::is_bool_literal),
+        ];
+
+        let rules = literals();
+
+        debug_assert_eq!(
+            rules.iter().map(|(name, ..)| *name).collect::<Vec<_>>(),
+            samples.iter().map(|(name, ..)| *name).collect::<Vec<_>>()
+        );
+
+        for (name, src, is_kind) in samples {
+            let stream = TokenStream::from_str(src)?;
+
+            debug_assert_eq!(stream.len(), 1, "{}", name);
+            debug_assert!(is_kind(&stream[0]), "{}", name);
+        }
+
+        for (suffixes, digits, pattern) in [
+            (LInt::SUFFIXES, "1", &rules[4].2),
+            (LFloat::SUFFIXES, "1.5", &rules[3].2),
+        ] {
+            for suffix in suffixes {
+                let stream = TokenStream::from_str(&format!("{}{}", digits, suffix))?;
+
+                debug_assert_eq!(stream.len(), 1, "{}", suffix);
+                debug_assert!(pattern.contains(suffix), "{}", suffix);
+            }
+        }
+
+        Ok(())
+    }
+}
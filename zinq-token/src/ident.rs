@@ -1,15 +1,57 @@
-use zinq_error::Result;
+use zinq_error::{NOT_FOUND, Result};
 use zinq_parse::{Cursor, Parse, Peek, Span, Spanned};
 
-use crate::{ToTokens, Token, TokenStream};
+use crate::{Keyword, ToTokens, Token, TokenStream};
+
+///
+/// ## is_ident_byte
+/// can the byte appear inside an identifier
+///
+#[inline]
+pub(crate) fn is_ident_byte(byte: &u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == &b'_'
+}
+
+///
+/// ## ident_len
+/// the length of the identifier at the start of `bytes`,
+/// including the `r#` prefix of raw identifiers, or `0`
+/// when `bytes` does not start with one
+///
+#[inline]
+pub(crate) fn ident_len(bytes: &[u8]) -> usize {
+    let prefix = match bytes.starts_with(b"r#") {
+        true => 2,
+        false => 0,
+    };
+
+    match bytes.get(prefix) {
+        Some(b) if b.is_ascii_alphabetic() || b == &b'_' => {}
+        _ => return 0,
+    };
+
+    let len = prefix
+        + bytes[prefix..]
+            .iter()
+            .take_while(|b| is_ident_byte(b))
+            .count();
+
+    if &bytes[prefix..len] == b"_" {
+        return 0;
+    }
+
+    len
+}
 
 ///
 /// ## Ident
-/// an identifier
+/// an identifier, reserved keywords are only
+/// accepted in their raw form
 /// ## Examples
 /// - foo
 /// - my_var
 /// - MyType
+/// - r#match
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Ident {
@@ -17,9 +59,70 @@ pub struct Ident {
 }
 
 impl Ident {
+    ///
+    /// ## new
+    /// an identifier with a generated span,
+    /// panics if `name` is not a valid identifier
+    /// or is a reserved keyword
+    ///
+    pub fn new(name: &str) -> Self {
+        assert!(
+            ident_len(name.as_bytes()) == name.len() && !name.starts_with("r#"),
+            "'{}' is not a valid identifier",
+            name
+        );
+
+        assert!(
+            !Keyword::is_reserved(name.as_bytes()),
+            "'{}' is a reserved keyword, use `Ident::new_raw`",
+            name
+        );
+
+        Self {
+            span: Span::generated(name),
+        }
+    }
+
+    ///
+    /// ## new_raw
+    /// a raw identifier (`r#name`) with a generated span
+    ///
+    pub fn new_raw(name: &str) -> Self {
+        assert!(
+            ident_len(name.as_bytes()) == name.len() && !name.starts_with("r#"),
+            "'{}' is not a valid identifier",
+            name
+        );
+
+        Self {
+            span: Span::generated(&format!("r#{}", name)),
+        }
+    }
+
+    pub(crate) fn from_span(span: Span) -> Self {
+        Self { span }
+    }
+
     pub fn name(&self) -> &'static str {
         "Ident"
     }
+
+    pub fn is_raw(&self) -> bool {
+        self.span.bytes().starts_with(b"r#")
+    }
+
+    ///
+    /// ## unraw
+    /// the identifier without its `r#` prefix
+    ///
+    pub fn unraw(&self) -> &str {
+        let bytes = match self.is_raw() {
+            true => &self.span.bytes()[2..],
+            false => self.span.bytes(),
+        };
+
+        str::from_utf8(bytes).expect("identifiers are ascii")
+    }
 }
 
 impl From<Ident> for Token {
@@ -39,17 +142,37 @@ impl std::fmt::Display for Ident {
 impl Peek for Ident {
     #[inline]
     fn peek(cursor: &Cursor, _: &zinq_parse::ZinqParser) -> Result<bool> {
-        Ok(cursor.peek()?.is_ascii_alphabetic() || cursor.peek()? == &b'_')
+        let rest = &cursor.src()[cursor.end().index()..];
+        let len = ident_len(rest);
+
+        Ok(len > 0 && !Keyword::is_reserved(&rest[..len]))
     }
 }
 
 impl Parse for Ident {
     #[inline]
     fn parse(cursor: &mut Cursor, _: &mut zinq_parse::ZinqParser) -> Result<Self> {
-        let span = cursor
-            .next_while(|b, _| b.is_ascii_alphanumeric() || b == &b'_')?
-            .span();
-        Ok(Self { span: span.clone() })
+        let rest = &cursor.src()[cursor.end().index()..];
+        let len = ident_len(rest);
+
+        if len == 0 {
+            return Err(cursor.error(NOT_FOUND, "expected identifier"));
+        }
+
+        if Keyword::is_reserved(&rest[..len]) {
+            let message = format!(
+                "expected identifier, found keyword '{}'",
+                String::from_utf8_lossy(&rest[..len])
+            );
+
+            return Err(cursor.error(NOT_FOUND, &message));
+        }
+
+        cursor.next_n(len)?;
+
+        Ok(Self {
+            span: cursor.source_span(),
+        })
     }
 }
 
@@ -68,9 +191,9 @@ impl ToTokens for Ident {
 #[cfg(test)]
 mod tests {
     use zinq_error::Result;
-    use zinq_parse::Span;
+    use zinq_parse::{Span, Spanned};
 
-    use crate::Token;
+    use crate::{Ident, Token};
 
     #[test]
     fn should_parse() -> Result<()> {
@@ -82,6 +205,72 @@ mod tests {
         debug_assert!(token.is_ident());
         debug_assert_eq!(token.to_string(), "test");
 
+        Ok(())
+    }
+    #[test]
+    fn should_parse_leading_underscore() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"_test").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let token = parser.parse::<Token>(&mut cursor)?;
+
+        debug_assert!(token.is_ident());
+        debug_assert_eq!(token.to_string(), "_test");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_create_generated() -> Result<()> {
+        let ident = Ident::new("my_var");
+        let raw = Ident::new_raw("match");
+
+        debug_assert!(ident.span().is_generated());
+        debug_assert_eq!(ident.to_string(), "my_var");
+        debug_assert!(raw.is_raw());
+        debug_assert_eq!(raw.unraw(), "match");
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_create_reserved() {
+        Ident::new("match");
+    }
+
+    #[test]
+    fn should_parse_raw() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"r#match").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let ident = parser.parse::<Ident>(&mut cursor)?;
+
+        debug_assert!(ident.is_raw());
+        debug_assert_eq!(ident.to_string(), "r#match");
+        debug_assert_eq!(ident.unraw(), "match");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_reserved() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"match").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+
+        debug_assert!(!parser.peek::<Ident>(&cursor)?);
+        debug_assert!(parser.parse::<Ident>(&mut cursor).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_contextual() -> Result<()> {
+        let mut cursor = Span::from_bytes(b"protocol").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let ident = parser.parse::<Ident>(&mut cursor)?;
+
+        debug_assert!(!ident.is_raw());
+        debug_assert_eq!(ident.to_string(), "protocol");
+
         Ok(())
     }
 }
@@ -13,6 +13,13 @@ macro_rules! define_keywords {
                 }
             }
 
+            #[inline]
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$name(_) => $token,)*
+                }
+            }
+
             $(
                 #[inline]
                 pub fn $is_method(&self) -> bool {
@@ -24,6 +31,17 @@ macro_rules! define_keywords {
             )*
         }
 
+        impl Keyword {
+            ///
+            /// ## TABLE
+            /// every keyword in declaration order, paired
+            /// with a constructor used by the `Lexer`
+            ///
+            pub(crate) const TABLE: &'static [(&'static str, fn(zinq_parse::Span) -> $crate::Token)] = &[
+                $(($token, |span| $crate::Token::from($name { span })),)*
+            ];
+        }
+
         impl zinq_parse::Peek for Keyword {
             #[inline]
             fn peek(cursor: &zinq_parse::Cursor, parser: &zinq_parse::ZinqParser) -> zinq_error::Result<bool> {
@@ -90,29 +108,44 @@ macro_rules! define_keywords {
             }
 
             impl $name {
+                #[doc = "a `"]
+                #[doc = $token]
+                #[doc = "` with a generated span"]
+                pub fn new() -> Self {
+                    Self {
+                        span: zinq_parse::Span::generated($token),
+                    }
+                }
+
                 pub fn name(&self) -> &'static str {
                     stringify!($name)
                 }
             }
 
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
             impl zinq_parse::Peek for $name {
                 #[inline]
                 fn peek(cursor: &zinq_parse::Cursor, _: &zinq_parse::ZinqParser) -> zinq_error::Result<bool> {
-                    Ok(cursor.fork().next_while(|b, _| b.is_ascii_alphanumeric())?.span() == &$token.as_bytes())
+                    Ok(cursor.fork().next_while(|b, _| $crate::is_ident_byte(b))?.span() == &$token.as_bytes())
                 }
             }
 
             impl zinq_parse::Parse for $name {
                 #[inline]
                 fn parse(cursor: &mut zinq_parse::Cursor, _: &mut zinq_parse::ZinqParser) -> zinq_error::Result<Self> {
-                    let span = cursor.next_n($token.len())?.span();
+                    let span = cursor.next_n($token.len())?.span().clone();
 
-                    if span != &$token.as_bytes() {
+                    if span != $token.as_bytes() || cursor.peek().is_ok_and($crate::is_ident_byte) {
                         return Err(cursor.error(zinq_error::NOT_FOUND, &format!("expected '{}'", $token)));
                     }
 
                     Ok(Self {
-                        span: span.clone(),
+                        span: cursor.source_span(),
                     })
                 }
             }
@@ -165,6 +198,39 @@ macro_rules! define_keywords {
             use zinq_error::Result;
             use zinq_parse::Span;
 
+            #[test]
+            fn should_respect_identifier_boundaries() -> Result<()> {
+                for keyword in [$($token),*] {
+                    for affix in ["_", "x", "X", "1", "_x", "__", "_1"] {
+                        for src in [format!("{}{}", keyword, affix), format!("{}{}", affix, keyword)] {
+                            if src.starts_with(|c: char| c.is_ascii_digit()) {
+                                continue;
+                            }
+
+                            let mut cursor = Span::from_str(&src).cursor();
+                            let mut parser = zinq_parse::ZinqParser;
+                            let token = parser.parse::<$crate::Token>(&mut cursor)?;
+                            let tokens = $crate::Lexer::new(&Span::from_str(&src)).lex()?;
+
+                            debug_assert!(token.is_ident(), "{}", src);
+                            debug_assert_eq!(token.to_string(), src);
+                            debug_assert_eq!(tokens, vec![token]);
+                        }
+                    }
+
+                    let src = format!("r#{}", keyword);
+                    let mut cursor = Span::from_str(&src).cursor();
+                    let mut parser = zinq_parse::ZinqParser;
+                    let token = parser.parse::<$crate::Token>(&mut cursor)?;
+                    let tokens = $crate::Lexer::new(&Span::from_str(&src)).lex()?;
+
+                    debug_assert_eq!(token.try_to_ident()?.unraw(), keyword);
+                    debug_assert_eq!(tokens, vec![token]);
+                }
+
+                Ok(())
+            }
+
             $(
                 #[test]
                 fn $is_method() -> Result<()> {
@@ -175,6 +241,7 @@ macro_rules! define_keywords {
                     debug_assert!(token.is_keyword());
                     debug_assert!(token.$is_method());
                     debug_assert_eq!(token.to_string(), $token);
+                    debug_assert_eq!($crate::Lexer::new(&Span::from_str($token)).lex()?, vec![token]);
 
                     Ok(())
                 }
@@ -188,9 +255,14 @@ define_keywords! {
     "mod",       pub struct Mod,        is_mod,
     "mut",       pub struct Mut,        is_mut,
     "match",     pub struct Match,      is_match,
+    "macro",     pub struct Macro,      is_macro,
     "where",     pub struct Where,      is_where,
     "continue",  pub struct Continue,   is_continue,
+    "break",     pub struct Break,      is_break,
+    "while",     pub struct While,      is_while,
+    "loop",      pub struct Loop,       is_loop,
     "protocol",  pub struct Protocol,   is_protocol,
+    "type",      pub struct Type,       is_type,
     "if",        pub struct If,         is_if,
     "else",      pub struct Else,       is_else,
     "for",       pub struct For,        is_for,
@@ -210,3 +282,28 @@ define_keywords! {
     "pub",       pub struct Pub,        is_pub,
     "use",       pub struct Use,        is_use
 }
+
+impl Keyword {
+    ///
+    /// ## CONTEXTUAL
+    /// keywords that only carry meaning in specific positions,
+    /// everywhere else they are valid identifiers
+    ///
+    pub const CONTEXTUAL: &'static [&'static str] = &[
+        "package", "protocol", "type", "where", "self", "Self", "super", "macro",
+    ];
+
+    pub fn is_contextual(&self) -> bool {
+        Self::CONTEXTUAL.contains(&self.as_str())
+    }
+
+    ///
+    /// ## is_reserved
+    /// is `word` a keyword that can only be used
+    /// as an identifier in its raw `r#` form
+    ///
+    pub fn is_reserved(word: &[u8]) -> bool {
+        Self::TABLE.iter().any(|(text, _)| text.as_bytes() == word)
+            && !Self::CONTEXTUAL.iter().any(|text| text.as_bytes() == word)
+    }
+}
@@ -0,0 +1,347 @@
+use std::sync::OnceLock;
+
+use zinq_error::{Error, NOT_FOUND, Result, ZinqError, ZinqErrorCode};
+use zinq_parse::{EOF, Location, ParseError, Span};
+
+use crate::{
+    CloseDelim, Ident, Keyword, LBool, LByte, LChar, LFloat, LInt, LString, OpenDelim, Punct,
+    Token, TokenTree, TreeBuilder, char_len, ident_len, is_ident_byte,
+};
+
+type Entry = (&'static str, fn(Span) -> Token);
+
+///
+/// ## Dispatch
+/// the `define_puncts!`, `define_keywords!` and delimiter
+/// tables indexed by their leading byte, symbols are
+/// ordered longest first so the first hit is the longest match
+///
+struct Dispatch {
+    symbols: [Vec<Entry>; 256],
+    keywords: [Vec<Entry>; 256],
+}
+
+impl Dispatch {
+    fn get() -> &'static Self {
+        static DISPATCH: OnceLock<Dispatch> = OnceLock::new();
+
+        DISPATCH.get_or_init(|| {
+            let mut symbols: [Vec<Entry>; 256] = std::array::from_fn(|_| vec![]);
+            let mut keywords: [Vec<Entry>; 256] = std::array::from_fn(|_| vec![]);
+
+            for entry in Punct::TABLE
+                .iter()
+                .chain(OpenDelim::TABLE)
+                .chain(CloseDelim::TABLE)
+            {
+                symbols[entry.0.as_bytes()[0] as usize].push(*entry);
+            }
+
+            for entry in Keyword::TABLE {
+                keywords[entry.0.as_bytes()[0] as usize].push(*entry);
+            }
+
+            for candidates in symbols.iter_mut() {
+                candidates.sort_by_key(|entry| std::cmp::Reverse(entry.0.len()));
+            }
+
+            Self { symbols, keywords }
+        })
+    }
+}
+
+///
+/// ## Lexer
+/// a single pass tokenizer that dispatches on the
+/// leading byte of each token rather than trial
+/// parsing every token type in turn
+///
+/// ```
+/// let tokens = Lexer::new(&Span::from_str("let a = 1;")).lex()?;
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Lexer {
+    span: Span,
+    location: Location,
+}
+
+impl Lexer {
+    pub fn new(span: &Span) -> Self {
+        Self {
+            span: span.clone(),
+            location: *span.start(),
+        }
+    }
+
+    ///
+    /// ## lex
+    /// consume the rest of the span as a flat
+    /// list of tokens
+    ///
+    pub fn lex(&mut self) -> Result<Vec<Token>> {
+        let mut tokens = vec![];
+
+        while let Some(token) = self.next_token()? {
+            tokens.push(token);
+        }
+
+        Ok(tokens)
+    }
+
+    ///
+    /// ## lex_trees
+    /// consume the rest of the span as token trees,
+    /// nesting each group as its delimiters are lexed
+    ///
+    pub fn lex_trees(&mut self) -> Result<Vec<TokenTree>> {
+        let mut builder = TreeBuilder::default();
+
+        while let Some(token) = self.next_token()? {
+            builder.push(token)?;
+        }
+
+        builder.finish()
+    }
+
+    ///
+    /// ## next_token
+    /// skip whitespace and lex the next token,
+    /// returning `None` once the span is exhausted
+    ///
+    pub fn next_token(&mut self) -> Result<Option<Token>> {
+        while let Some(byte) = self.peek_at(0)
+            && byte.is_ascii_whitespace()
+        {
+            self.location.next(self.span.src());
+        }
+
+        let byte = match self.peek_at(0) {
+            None => return Ok(None),
+            Some(v) => v,
+        };
+
+        // before the symbols, which would read its `'` as a label
+        if let Some(len) = char_len(self.rest()) {
+            return Ok(Some(LChar::from_span(self.take(len)).into()));
+        }
+
+        let dispatch = Dispatch::get();
+        let word = ident_len(self.rest());
+
+        for (text, build) in dispatch.symbols[byte as usize].iter() {
+            // word like symbols (`_`) never split an identifier
+            if word > 0 && text.bytes().all(|b| is_ident_byte(&b)) {
+                continue;
+            }
+
+            if self.rest().starts_with(text.as_bytes()) {
+                return Ok(Some(build(self.take(text.len()))));
+            }
+        }
+
+        if byte.is_ascii_digit() {
+            return Ok(Some(self.number()));
+        }
+
+        if self.rest().starts_with(b"b'") {
+            if self.rest().len() < 4 {
+                return Err(self.error(EOF, self.rest().len(), "end of input stream"));
+            }
+
+            return Ok(Some(LByte::from_span(self.take(4)).into()));
+        }
+
+        if byte == b'"' {
+            let len = match self.rest()[1..].iter().position(|b| b == &b'"') {
+                None => return Err(self.error(EOF, self.rest().len(), "end of input stream")),
+                Some(v) => v + 2,
+            };
+
+            return Ok(Some(LString::from_span(self.take(len)).into()));
+        }
+
+        if LBool::TABLE
+            .iter()
+            .any(|text| &self.rest()[..word] == text.as_bytes())
+        {
+            return Ok(Some(LBool::from_span(self.take(word)).into()));
+        }
+
+        for (text, build) in dispatch.keywords[byte as usize].iter() {
+            if &self.rest()[..word] == text.as_bytes() {
+                return Ok(Some(build(self.take(word))));
+            }
+        }
+
+        if word == 0 {
+            return Err(self.error(
+                NOT_FOUND,
+                1,
+                &format!("unexpected token '{}'", byte as char),
+            ));
+        }
+
+        Ok(Some(Ident::from_span(self.take(word)).into()))
+    }
+
+    fn number(&mut self) -> Token {
+        let mut len = self.count(0, |b| b.is_ascii_digit());
+
+        if self.peek_at(len) == Some(b'.')
+            && self.peek_at(len + 1).is_some_and(|b| b.is_ascii_digit())
+        {
+            len = self.count(len + 1, |b| b.is_ascii_digit());
+
+            len += self.suffix(len, LFloat::SUFFIXES);
+
+            return LFloat::from_span(self.take(len)).into();
+        }
+
+        len += self.suffix(len, LInt::SUFFIXES);
+        LInt::from_span(self.take(len)).into()
+    }
+
+    fn rest(&self) -> &[u8] {
+        &self.span.src()[self.location.index()..self.span.end().index()]
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.rest().get(offset).copied()
+    }
+
+    fn count<P: Fn(&u8) -> bool>(&self, from: usize, predicate: P) -> usize {
+        from + self.rest()[from..]
+            .iter()
+            .take_while(|b| predicate(b))
+            .count()
+    }
+
+    // the length of the suffix at `offset`, or 0
+    fn suffix(&self, offset: usize, suffixes: &[&str]) -> usize {
+        suffixes
+            .iter()
+            .find(|suffix| self.rest()[offset..].starts_with(suffix.as_bytes()))
+            .map_or(0, |suffix| suffix.len())
+    }
+
+    fn take(&mut self, len: usize) -> Span {
+        let start = self.location;
+
+        for _ in 0..len {
+            self.location.next(self.span.src());
+        }
+
+        self.span.between(start, self.location)
+    }
+
+    fn error(&self, code: ZinqErrorCode, len: usize, message: &str) -> ZinqError {
+        let mut end = self.location;
+
+        for _ in 0..len {
+            end.next(self.span.src());
+        }
+
+        ParseError::from_error(
+            self.span.between(self.location, end),
+            Error::new().code(code).message(message).build().into(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::{Span, Spanned};
+
+    use crate::{Lexer, Token, TokenStream};
+
+    fn trial(src: &str) -> Result<Vec<Token>> {
+        let mut cursor = Span::from_str(src).cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let stream = parser.parse::<TokenStream>(&mut cursor)?;
+
+        Ok(stream.to_vec())
+    }
+
+    #[test]
+    fn should_lex() -> Result<()> {
+        let tokens = Lexer::new(&Span::from_str("let test: u16 = 500u16;")).lex()?;
+
+        debug_assert_eq!(tokens.len(), 7);
+        debug_assert!(tokens[0].is_let());
+        debug_assert!(tokens[1].is_ident());
+        debug_assert!(tokens[2].is_colon());
+        debug_assert!(tokens[3].is_ident());
+        debug_assert!(tokens[4].is_eq());
+        debug_assert!(tokens[5].is_u16_literal());
+        debug_assert!(tokens[6].is_semi_colon());
+        debug_assert_eq!(tokens[5].to_string(), "500u16");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_lex_longest_punct() -> Result<()> {
+        let tokens = Lexer::new(&Span::from_str("a::b ..= c -> d //= e")).lex()?;
+        let names = tokens.iter().map(|t| t.name()).collect::<Vec<_>>();
+
+        debug_assert_eq!(
+            names,
+            vec![
+                "Ident",
+                "ColonColon",
+                "Ident",
+                "DotDotEq",
+                "Ident",
+                "RArrow",
+                "Ident",
+                "SlashSlash",
+                "Eq",
+                "Ident"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_lex_trailing_whitespace() -> Result<()> {
+        let tokens = Lexer::new(&Span::from_str("  a b \n")).lex()?;
+
+        debug_assert_eq!(tokens.len(), 2);
+        debug_assert_eq!(tokens[1].to_string(), "b");
+        debug_assert_eq!(tokens[1].span().start().index(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_match_trial_parsing() -> Result<()> {
+        let src = "#[Print(pretty)]
+pub struct User<T: ToString + Cmp> {
+    pub(mod) name: string,
+    age: u8,
+}
+
+impl User {
+    fn birthday(&mut self) -> bool {
+        let ok = self.age >= 18u8 && !false || 1.5f32 <= 2.0;
+        self.age += 1;
+        self.items[0..2] == b'a';
+        match ok { true => \"yes\", _ => \"no\" }
+    }
+}";
+
+        debug_assert_eq!(Lexer::new(&Span::from_str(src)).lex()?, trial(src)?);
+        Ok(())
+    }
+
+    #[test]
+    fn should_error_on_unterminated_string() -> Result<()> {
+        debug_assert!(Lexer::new(&Span::from_str("let a = \"abc")).lex().is_err());
+        debug_assert!(Lexer::new(&Span::from_str("let a = `")).lex().is_err());
+        Ok(())
+    }
+}
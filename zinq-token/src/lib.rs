@@ -1,27 +1,37 @@
 mod delimiter;
 mod enclosed;
 mod error;
+mod grammar;
 mod ident;
 mod keyword;
+mod lexer;
 mod literal;
 pub mod macros;
 mod prefixed;
+mod printer;
 mod punct;
 mod punctuated;
 mod stream;
 mod suffixed;
+mod token_cursor;
+mod tree;
 
 pub use delimiter::*;
 pub use enclosed::*;
 pub use error::*;
+pub use grammar::*;
 pub use ident::*;
 pub use keyword::*;
+pub use lexer::*;
 pub use literal::*;
 pub use prefixed::*;
+pub use printer::*;
 pub use punct::*;
 pub use punctuated::*;
 pub use stream::*;
 pub use suffixed::*;
+pub use token_cursor::*;
+pub use tree::*;
 
 use zinq_error::Result;
 use zinq_parse::{Cursor, Parse, Peek, Span, Spanned};
@@ -163,6 +173,11 @@ impl Parse for Token {
             return parser.parse(cursor.shift_next()?);
         }
 
+        // before the puncts, which would read its `'` as a label
+        if parser.peek::<LChar>(cursor)? {
+            return Ok(parser.parse::<LChar>(cursor)?.into());
+        }
+
         if parser.peek::<Punct>(cursor)? {
             return Ok(parser.parse::<Punct>(cursor)?.into());
         }
@@ -195,6 +210,12 @@ impl Spanned for Token {
     }
 }
 
+impl<T: ToTokens + ?Sized> ToTokens for &T {
+    fn to_tokens(&self) -> Result<TokenStream> {
+        (**self).to_tokens()
+    }
+}
+
 impl ToTokens for Token {
     fn to_tokens(&self) -> Result<TokenStream> {
         Ok(self.clone().into())
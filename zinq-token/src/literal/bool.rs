@@ -1,7 +1,7 @@
-use zinq_error::Result;
+use zinq_error::{NOT_FOUND, Result};
 use zinq_parse::{Cursor, Parse, Peek, Span, Spanned};
 
-use crate::{Literal, ToTokens, Token, TokenStream};
+use crate::{Literal, ToTokens, Token, TokenStream, ident_len};
 
 ///
 /// ## LBool
@@ -15,6 +15,26 @@ pub struct LBool {
 }
 
 impl LBool {
+    ///
+    /// ## TABLE
+    /// the words the `Lexer` reads as booleans
+    ///
+    pub(crate) const TABLE: &'static [&'static str] = &["true", "false"];
+
+    ///
+    /// ## new
+    /// `true` or `false` with a generated span
+    ///
+    pub fn new(value: bool) -> Self {
+        Self {
+            span: Span::generated(&value.to_string()),
+        }
+    }
+
+    pub(crate) fn from_span(span: Span) -> Self {
+        Self { span }
+    }
+
     pub fn name(&self) -> &'static str {
         "LBool"
     }
@@ -42,24 +62,27 @@ impl std::fmt::Display for LBool {
 impl Peek for LBool {
     #[inline]
     fn peek(cursor: &Cursor, _: &zinq_parse::ZinqParser) -> Result<bool> {
-        Ok(cursor.peek_n(4).unwrap_or(&[]) == b"true"
-            || cursor.peek_n(5).unwrap_or(&[]) == b"false")
+        let rest = &cursor.src()[cursor.end().index()..];
+        let len = ident_len(rest);
+
+        Ok(&rest[..len] == b"true" || &rest[..len] == b"false")
     }
 }
 
 impl Parse for LBool {
     #[inline]
     fn parse(cursor: &mut Cursor, _: &mut zinq_parse::ZinqParser) -> Result<Self> {
-        if cursor.peek_n(4).unwrap_or(&[]) == b"true" {
-            cursor.next_n(4)?;
-        }
+        let rest = &cursor.src()[cursor.end().index()..];
+        let len = ident_len(rest);
 
-        if cursor.peek_n(5).unwrap_or(&[]) == b"false" {
-            cursor.next_n(5)?;
+        if &rest[..len] != b"true" && &rest[..len] != b"false" {
+            return Err(cursor.error(NOT_FOUND, "expected 'true' or 'false'"));
         }
 
+        cursor.next_n(len)?;
+
         Ok(Self {
-            span: cursor.span().clone(),
+            span: cursor.source_span(),
         }
         .into())
     }
@@ -107,6 +130,18 @@ mod tests {
         debug_assert_eq!(token.to_string(), "false");
         debug_assert_eq!(token.try_to_literal()?.try_to_bool()?.to_bool()?, false);
 
+        Ok(())
+    }
+    #[test]
+    fn is_ident_with_bool_prefix() -> Result<()> {
+        let span = Span::from_bytes(b"trueish");
+        let mut cursor = span.cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let token = parser.parse::<Token>(&mut cursor)?;
+
+        debug_assert!(token.is_ident());
+        debug_assert_eq!(token.to_string(), "trueish");
+
         Ok(())
     }
 }
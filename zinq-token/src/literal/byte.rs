@@ -15,6 +15,26 @@ pub struct LByte {
 }
 
 impl LByte {
+    ///
+    /// ## new
+    /// a byte literal with a generated span,
+    /// panics if `value` is not printable ascii
+    ///
+    pub fn new(value: u8) -> Self {
+        assert!(
+            value.is_ascii_graphic() || value == b' ',
+            "byte literals must be printable ascii"
+        );
+
+        Self {
+            span: Span::generated(&format!("b'{}'", value as char)),
+        }
+    }
+
+    pub(crate) fn from_span(span: Span) -> Self {
+        Self { span }
+    }
+
     pub fn name(&self) -> &'static str {
         "LByte"
     }
@@ -34,7 +54,7 @@ impl std::fmt::Display for LByte {
 impl Peek for LByte {
     #[inline]
     fn peek(cursor: &Cursor, _: &zinq_parse::ZinqParser) -> Result<bool> {
-        Ok(cursor.peek_n(2)? == b"b'")
+        Ok(cursor.peek_n(2).unwrap_or(&[]) == b"b'")
     }
 }
 
@@ -44,7 +64,7 @@ impl Parse for LByte {
         cursor.next_while(|b, _| b != &b'\'')?.next_n(3)?;
 
         Ok(Self {
-            span: cursor.span().clone(),
+            span: cursor.source_span(),
         }
         .into())
     }
@@ -0,0 +1,167 @@
+use zinq_error::{NOT_FOUND, Result};
+use zinq_parse::{Cursor, Parse, Peek, Span, Spanned};
+
+use crate::{Literal, ToTokens, Token, TokenStream};
+
+///
+/// ## LChar
+/// a literal character
+/// ### Example
+/// `'a'`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LChar {
+    span: Span,
+}
+
+impl LChar {
+    ///
+    /// ## new
+    /// a char literal with a generated span,
+    /// panics if `value` is `'`, `\` or a control
+    ///
+    pub fn new(value: char) -> Self {
+        assert!(
+            !matches!(value, '\'' | '\\') && !value.is_control(),
+            "char literals cannot be quotes, escapes or controls"
+        );
+
+        Self {
+            span: Span::generated(&format!("'{}'", value)),
+        }
+    }
+
+    pub(crate) fn from_span(span: Span) -> Self {
+        Self { span }
+    }
+
+    pub fn name(&self) -> &'static str {
+        "LChar"
+    }
+
+    pub fn to_char(&self) -> char {
+        let bytes = self.span.bytes();
+
+        std::str::from_utf8(&bytes[1..bytes.len() - 1])
+            .ok()
+            .and_then(|v| v.chars().next())
+            .expect("expected a single char")
+    }
+}
+
+///
+/// ## char_len
+/// the length in bytes of the char literal
+/// `bytes` starts with, a `'` that is not
+/// closed right after one char is a label
+///
+pub(crate) fn char_len(bytes: &[u8]) -> Option<usize> {
+    let rest = bytes.strip_prefix(b"'")?;
+    let width = match *rest.first()? {
+        b'\'' | b'\\' => return None,
+        v if v.is_ascii_control() => return None,
+        v if v < 0x80 => 1,
+        v if v >= 0xF0 => 4,
+        v if v >= 0xE0 => 3,
+        _ => 2,
+    };
+
+    match rest.get(width) {
+        Some(b'\'') => Some(width + 2),
+        _ => None,
+    }
+}
+
+impl std::fmt::Display for LChar {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &self.span)
+    }
+}
+
+impl Peek for LChar {
+    #[inline]
+    fn peek(cursor: &Cursor, _: &zinq_parse::ZinqParser) -> Result<bool> {
+        let end = cursor.span().end().index();
+        Ok(char_len(&cursor.span().src()[end..]).is_some())
+    }
+}
+
+impl Parse for LChar {
+    #[inline]
+    fn parse(cursor: &mut Cursor, _: &mut zinq_parse::ZinqParser) -> Result<Self> {
+        let end = cursor.span().end().index();
+        let len = match char_len(&cursor.span().src()[end..]) {
+            None => return Err(cursor.error(NOT_FOUND, "expected a char literal")),
+            Some(v) => v,
+        };
+
+        cursor.next_n(len)?;
+
+        Ok(Self {
+            span: cursor.source_span(),
+        })
+    }
+}
+
+impl Spanned for LChar {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl From<LChar> for Literal {
+    fn from(value: LChar) -> Self {
+        Self::Char(value)
+    }
+}
+
+impl From<LChar> for Token {
+    fn from(value: LChar) -> Self {
+        Self::Literal(Literal::Char(value))
+    }
+}
+
+impl ToTokens for LChar {
+    fn to_tokens(&self) -> zinq_error::Result<TokenStream> {
+        Ok(Token::Literal(Literal::Char(self.clone())).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::{Lexer, Token};
+
+    #[test]
+    fn is_char() -> Result<()> {
+        let span = Span::from_bytes(b"'p'");
+        let mut cursor = span.cursor();
+        let mut parser = zinq_parse::ZinqParser;
+
+        let token = parser.parse::<Token>(&mut cursor)?;
+
+        debug_assert!(token.is_char_literal());
+        debug_assert_eq!(token.to_string(), "'p'");
+        debug_assert_eq!(token.try_to_literal()?.try_to_char()?.to_char(), 'p');
+        debug_assert_eq!(cursor.bytes(), b"");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_lex_chars_apart_from_labels() -> Result<()> {
+        let tokens = Lexer::new(&Span::from_str("'é' 'outer 'a'..='z'")).lex()?;
+        let names = tokens.iter().map(|t| t.name()).collect::<Vec<_>>();
+
+        debug_assert_eq!(
+            names,
+            ["LChar", "Apostrophe", "Ident", "LChar", "DotDotEq", "LChar"]
+        );
+        debug_assert_eq!(tokens[0].try_to_literal()?.try_to_char()?.to_char(), 'é');
+
+        Ok(())
+    }
+}
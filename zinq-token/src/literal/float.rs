@@ -15,6 +15,39 @@ pub struct LFloat {
 }
 
 impl LFloat {
+    ///
+    /// ## SUFFIXES
+    /// the type suffixes the `Lexer` accepts
+    /// after the fraction
+    ///
+    pub(crate) const SUFFIXES: &'static [&'static str] = &["f32", "f64"];
+
+    ///
+    /// ## new
+    /// an unsuffixed float with a generated span,
+    /// panics if `value` is negative or not finite
+    ///
+    pub fn new(value: f64) -> Self {
+        assert!(
+            value.is_finite() && value.is_sign_positive(),
+            "float literals must be finite and positive"
+        );
+
+        let mut text = value.to_string();
+
+        if !text.contains('.') {
+            text.push_str(".0");
+        }
+
+        Self {
+            span: Span::generated(&text),
+        }
+    }
+
+    pub(crate) fn from_span(span: Span) -> Self {
+        Self { span }
+    }
+
     pub fn name(&self) -> &'static str {
         "LFloat"
     }
@@ -115,13 +148,13 @@ impl Parse for LFloat {
             cursor.next_n(3)?;
 
             return Ok(Self {
-                span: cursor.span().clone(),
+                span: cursor.source_span(),
             }
             .into());
         }
 
         Ok(Self {
-            span: cursor.span().clone(),
+            span: cursor.source_span(),
         }
         .into())
     }
@@ -15,6 +15,28 @@ pub struct LInt {
 }
 
 impl LInt {
+    ///
+    /// ## SUFFIXES
+    /// the type suffixes the `Lexer` accepts
+    /// after the digits
+    ///
+    pub(crate) const SUFFIXES: &'static [&'static str] =
+        &["u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64"];
+
+    ///
+    /// ## new
+    /// an unsuffixed int with a generated span
+    ///
+    pub fn new(value: u64) -> Self {
+        Self {
+            span: Span::generated(&value.to_string()),
+        }
+    }
+
+    pub(crate) fn from_span(span: Span) -> Self {
+        Self { span }
+    }
+
     pub fn name(&self) -> &'static str {
         "LInt"
     }
@@ -235,7 +257,7 @@ impl Parse for LInt {
             cursor.next_n(2)?;
 
             return Ok(Self {
-                span: cursor.span().clone(),
+                span: cursor.source_span(),
             }
             .into());
         }
@@ -250,13 +272,13 @@ impl Parse for LInt {
             cursor.next_n(3)?;
 
             return Ok(Self {
-                span: cursor.span().clone(),
+                span: cursor.source_span(),
             }
             .into());
         }
 
         Ok(Self {
-            span: cursor.span().clone(),
+            span: cursor.source_span(),
         }
         .into())
     }
@@ -1,11 +1,13 @@
 mod bool;
 mod byte;
+mod char;
 mod float;
 mod int;
 mod string;
 
 pub use bool::*;
 pub use byte::*;
+pub use char::*;
 pub use float::*;
 pub use int::*;
 pub use string::*;
@@ -24,6 +26,7 @@ pub enum Literal {
     Int(LInt),
     Float(LFloat),
     Byte(LByte),
+    Char(LChar),
     Bool(LBool),
     String(LString),
 }
@@ -34,6 +37,7 @@ impl Literal {
             Self::Int(v) => v.name(),
             Self::Float(v) => v.name(),
             Self::Byte(v) => v.name(),
+            Self::Char(v) => v.name(),
             Self::Bool(v) => v.name(),
             Self::String(v) => v.name(),
         }
@@ -96,6 +100,22 @@ impl Literal {
         }
     }
 
+    pub fn is_char(&self) -> bool {
+        matches!(self, Self::Char(_))
+    }
+
+    pub fn try_to_char(&self) -> Result<&LChar> {
+        match self {
+            Self::Char(v) => Ok(v),
+            other => {
+                Err(
+                    TokenMismatchError::from_types("LChar", other.name(), other.span().clone())
+                        .into(),
+                )
+            }
+        }
+    }
+
     pub fn is_bool(&self) -> bool {
         match self {
             Self::Bool(_) => true,
@@ -160,6 +180,14 @@ impl Token {
         false
     }
 
+    pub fn is_char_literal(&self) -> bool {
+        if let Token::Literal(v) = &self {
+            return v.is_char();
+        }
+
+        false
+    }
+
     pub fn is_bool_literal(&self) -> bool {
         if let Token::Literal(v) = &self {
             return v.is_bool();
@@ -184,6 +212,7 @@ impl std::fmt::Display for Literal {
             Self::Int(v) => write!(f, "{}", v),
             Self::Float(v) => write!(f, "{}", v),
             Self::Byte(v) => write!(f, "{}", v),
+            Self::Char(v) => write!(f, "{}", v),
             Self::Bool(v) => write!(f, "{}", v),
             Self::String(v) => write!(f, "{}", v),
         }
@@ -205,6 +234,10 @@ impl Peek for Literal {
             return Ok(true);
         }
 
+        if parser.peek::<LChar>(cursor)? {
+            return Ok(true);
+        }
+
         if parser.peek::<LBool>(cursor)? {
             return Ok(true);
         }
@@ -228,6 +261,10 @@ impl Parse for Literal {
             return Ok(parser.parse::<LByte>(cursor)?.into());
         }
 
+        if parser.peek::<LChar>(cursor)? {
+            return Ok(parser.parse::<LChar>(cursor)?.into());
+        }
+
         if parser.peek::<LString>(cursor)? {
             return Ok(parser.parse::<LString>(cursor)?.into());
         }
@@ -246,6 +283,7 @@ impl Spanned for Literal {
             Self::Int(v) => v.span(),
             Self::Float(v) => v.span(),
             Self::Byte(v) => v.span(),
+            Self::Char(v) => v.span(),
             Self::Bool(v) => v.span(),
             Self::String(v) => v.span(),
         }
@@ -9,6 +9,23 @@ pub struct LString {
 }
 
 impl LString {
+    ///
+    /// ## new
+    /// a quoted string with a generated span,
+    /// panics if `value` contains a `"`
+    ///
+    pub fn new(value: &str) -> Self {
+        assert!(!value.contains('"'), "string literals cannot contain '\"'");
+
+        Self {
+            span: Span::generated(&format!("\"{}\"", value)),
+        }
+    }
+
+    pub(crate) fn from_span(span: Span) -> Self {
+        Self { span }
+    }
+
     pub fn name(&self) -> &'static str {
         "LString"
     }
@@ -34,7 +51,7 @@ impl Parse for LString {
         cursor.next()?.next_while(|next, _| next != &b'"')?.next()?;
 
         Ok(Self {
-            span: cursor.span().clone(),
+            span: cursor.source_span(),
         })
     }
 }
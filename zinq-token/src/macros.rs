@@ -5,6 +5,132 @@ macro_rules! Stream {
     }};
 }
 
+///
+/// ## quote
+/// quasi-quote Zinq source into a `TokenStream`
+///
+/// - `#var` pushes anything implementing `ToTokens`
+/// - `#(...)*` or `#(...),*` repeats its body for each item of
+///   every variable interpolated in it, joined by an optional separator
+///
+/// ```
+/// let name = Stream!(add)?;
+/// let params = vec![Stream!(a)?, Stream!(b)?];
+/// let stream = quote!(fn #name(#(#params: u32),*) -> u32 { a + b })?;
+/// ```
+///
+/// a variable may appear more than once inside a repetition,
+/// and iterables are borrowed so they can be reused afterwards
+///
+#[macro_export]
+macro_rules! quote {
+    ($($body:tt)*) => {
+        $crate::TokenStream::build(|stream| {
+            $crate::__quote!(@ stream $($body)*);
+            Ok(())
+        })
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quote {
+    // collect the variables interpolated in a repetition body,
+    // flattening nested groups, then expand the loop
+    (@repeat $s:ident [$($sep:tt)?] [$($var:ident)*] [] $body:tt) => {
+        $crate::__quote!(@loop $s [$($sep)?] [$($var)*] $body);
+    };
+    (@repeat $s:ident $sep:tt [$($var:ident)*] [# $next:ident $($rest:tt)*] $body:tt) => {
+        $crate::__quote!(@repeat $s $sep [$($var)* $next] [$($rest)*] $body);
+    };
+    (@repeat $s:ident $sep:tt $vars:tt [( $($group:tt)* ) $($rest:tt)*] $body:tt) => {
+        $crate::__quote!(@repeat $s $sep $vars [$($group)* $($rest)*] $body);
+    };
+    (@repeat $s:ident $sep:tt $vars:tt [[ $($group:tt)* ] $($rest:tt)*] $body:tt) => {
+        $crate::__quote!(@repeat $s $sep $vars [$($group)* $($rest)*] $body);
+    };
+    (@repeat $s:ident $sep:tt $vars:tt [{ $($group:tt)* } $($rest:tt)*] $body:tt) => {
+        $crate::__quote!(@repeat $s $sep $vars [$($group)* $($rest)*] $body);
+    };
+    (@repeat $s:ident $sep:tt $vars:tt [$_:tt $($rest:tt)*] $body:tt) => {
+        $crate::__quote!(@repeat $s $sep $vars [$($rest)*] $body);
+    };
+
+    (@loop $s:ident [$($sep:tt)?] [] $body:tt) => {
+        compile_error!("quote! repetitions must interpolate at least one variable");
+    };
+    (@loop $s:ident [$($sep:tt)?] [$($var:ident)+] [$($body:tt)*]) => {{
+        let mut iters = $crate::__quote!(@iters $($var)+);
+        let mut first = true;
+
+        loop {
+            $crate::__quote!(@next [iters] $($var)+);
+
+            if !first {
+                $($s.push(&$crate::Stream!($sep)?)?;)?
+            }
+
+            first = false;
+            $crate::__quote!(@ $s $($body)*);
+        }
+    }};
+
+    // every occurrence of a variable gets its own iterator, nested
+    // as `(iter, (iter, ()))` so they are reached by position and
+    // a variable used twice is advanced in lockstep
+    (@iters) => { () };
+    (@iters $var:ident $($rest:ident)*) => {
+        ((&$var).into_iter(), $crate::__quote!(@iters $($rest)*))
+    };
+
+    (@next [$($iter:tt)*]) => {};
+    (@next [$($iter:tt)*] $var:ident $($rest:ident)*) => {
+        #[allow(unused_variables)]
+        let $var = match $($iter)*.0.next() {
+            None => break,
+            Some(v) => v,
+        };
+
+        $crate::__quote!(@next [$($iter)*.1] $($rest)*);
+    };
+
+    (@ $s:ident) => {};
+    (@ $s:ident # $var:ident $($rest:tt)*) => {
+        $s.push(&$var)?;
+        $crate::__quote!(@ $s $($rest)*);
+    };
+    (@ $s:ident # ( $($inner:tt)* ) * $($rest:tt)*) => {
+        $crate::__quote!(@repeat $s [] [] [$($inner)*] [$($inner)*]);
+        $crate::__quote!(@ $s $($rest)*);
+    };
+    (@ $s:ident # ( $($inner:tt)* ) $sep:tt * $($rest:tt)*) => {
+        $crate::__quote!(@repeat $s [$sep] [] [$($inner)*] [$($inner)*]);
+        $crate::__quote!(@ $s $($rest)*);
+    };
+    (@ $s:ident ( $($inner:tt)* ) $($rest:tt)*) => {
+        $s.push(&<$crate::TokenStream as std::str::FromStr>::from_str("(")?)?;
+        $crate::__quote!(@ $s $($inner)*);
+        $s.push(&<$crate::TokenStream as std::str::FromStr>::from_str(")")?)?;
+        $crate::__quote!(@ $s $($rest)*);
+    };
+    (@ $s:ident [ $($inner:tt)* ] $($rest:tt)*) => {
+        $s.push(&<$crate::TokenStream as std::str::FromStr>::from_str("[")?)?;
+        $crate::__quote!(@ $s $($inner)*);
+        $s.push(&<$crate::TokenStream as std::str::FromStr>::from_str("]")?)?;
+        $crate::__quote!(@ $s $($rest)*);
+    };
+    (@ $s:ident { $($inner:tt)* } $($rest:tt)*) => {
+        $s.push(&<$crate::TokenStream as std::str::FromStr>::from_str("{")?)?;
+        $crate::__quote!(@ $s $($inner)*);
+        $s.push(&<$crate::TokenStream as std::str::FromStr>::from_str("}")?)?;
+        $crate::__quote!(@ $s $($rest)*);
+    };
+    (@ $s:ident $token:tt $($rest:tt)*) => {
+        $s.push(&$crate::Stream!($token)?)?;
+        $crate::__quote!(@ $s $($rest)*);
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use zinq_error::Result;
@@ -23,4 +149,70 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn should_quote_interpolation() -> Result<()> {
+        let name = Stream!(add)?;
+        let ty = Stream!(u32)?;
+        let stream = quote!(let #name: #ty = 1;)?;
+        let names = stream.iter().map(|t| t.name()).collect::<Vec<_>>();
+
+        debug_assert_eq!(
+            names,
+            vec!["Let", "Ident", "Colon", "Ident", "Eq", "LInt", "SemiColon"]
+        );
+        debug_assert_eq!(stream[1].to_string(), "add");
+        debug_assert_eq!(stream[3].to_string(), "u32");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_quote_repetition() -> Result<()> {
+        let params = vec![Stream!(a)?, Stream!(b)?, Stream!(c)?];
+        let stream = quote!(fn add(#(#params: u32),*) {})?;
+        let text = stream.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+
+        debug_assert_eq!(
+            text,
+            vec![
+                "fn", "add", "(", "a", ":", "u32", ",", "b", ":", "u32", ",", "c", ":", "u32", ")",
+                "{", "}"
+            ]
+        );
+
+        let empty: Vec<crate::TokenStream> = vec![];
+        debug_assert_eq!(quote!(#(#empty)*)?.len(), 0);
+        debug_assert_eq!(params.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_quote_nested_groups() -> Result<()> {
+        let items = vec![Stream!(x)?, Stream!(y)?];
+        let values = vec![Stream!(1)?, Stream!(2)?];
+        let stream = quote!({ [#((#items, #values))*] })?;
+        let text = stream.iter().map(|t| t.to_string()).collect::<String>();
+
+        debug_assert_eq!(text, "{[(x,1)(y,2)]}");
+        debug_assert!(stream.first().unwrap().is_left_brace());
+        debug_assert!(stream.last().unwrap().is_right_brace());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_quote_repeated_variable() -> Result<()> {
+        let names = vec![Stream!(a)?, Stream!(b)?];
+        let stream = quote!(#(let #names = #names;)*)?;
+        let text = stream.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+
+        debug_assert_eq!(
+            text,
+            vec!["let", "a", "=", "a", ";", "let", "b", "=", "b", ";"]
+        );
+
+        Ok(())
+    }
 }
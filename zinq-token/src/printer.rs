@@ -0,0 +1,297 @@
+use zinq_parse::Span;
+
+use crate::{Lexer, Token};
+
+///
+/// ## Printer
+/// prints tokens as canonical Zinq source using
+/// only the tokens themselves, so streams built
+/// from synthesized or unrelated tokens can be
+/// written out and parsed again
+///
+/// ```
+/// let src = Printer::new().print(&stream);
+/// ```
+///
+#[derive(Debug, Default, Clone)]
+pub struct Printer {
+    out: String,
+    indent: usize,
+    groups: Vec<u8>,
+    prev: Option<Token>,
+    prev_unary: bool,
+    prev_generic: bool,
+    newline: bool,
+}
+
+impl Printer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// ## print
+    /// print every token and return the source
+    ///
+    pub fn print(mut self, tokens: &[Token]) -> String {
+        for token in tokens {
+            self.push(token);
+        }
+
+        self.finish()
+    }
+
+    ///
+    /// ## push
+    /// print the next token
+    ///
+    pub fn push(&mut self, token: &Token) {
+        let generic_open = token.is_lt() && self.prev.as_ref().is_some_and(Self::is_generic_base);
+        let generic_close = token.is_gt() && self.groups.last() == Some(&b'<');
+        let empty_block =
+            token.is_right_brace() && self.prev.as_ref().is_some_and(|prev| prev.is_left_brace());
+
+        if token.is_close_delim() || generic_close {
+            self.groups.pop();
+        }
+
+        if token.is_right_brace() {
+            self.indent = self.indent.saturating_sub(1);
+            self.newline = !empty_block;
+        }
+
+        if self.newline {
+            self.break_line(token);
+        } else if let Some(prev) = &self.prev
+            && self.is_spaced(prev, token, generic_open || generic_close)
+        {
+            self.out.push(' ');
+        }
+
+        self.out.push_str(&token.to_string());
+        self.newline = false;
+
+        if token.is_left_brace() {
+            self.indent += 1;
+            self.newline = true;
+        }
+
+        if token.is_open_delim() {
+            self.groups.push(token.to_string().as_bytes()[0]);
+        }
+
+        if generic_open {
+            self.groups.push(b'<');
+        }
+
+        if token.is_right_brace() {
+            self.newline = true;
+        }
+
+        if token.is_semi_colon() || token.is_comma() {
+            self.newline = match self.groups.last() {
+                None => token.is_semi_colon(),
+                Some(group) => group == &b'{',
+            };
+        }
+
+        self.prev_unary = self.is_unary(token);
+        self.prev_generic = generic_open;
+        self.prev = Some(token.clone());
+    }
+
+    pub fn finish(self) -> String {
+        self.out.trim_end().to_string()
+    }
+
+    fn break_line(&mut self, token: &Token) {
+        let after_block = self.prev.as_ref().is_some_and(|prev| prev.is_right_brace());
+
+        if after_block
+            && (token.is_comma()
+                || token.is_semi_colon()
+                || token.is_dot()
+                || token.is_question()
+                || token.is_right_paren()
+                || token.is_right_bracket())
+        {
+            return;
+        }
+
+        if after_block && token.is_else() {
+            self.out.push(' ');
+            return;
+        }
+
+        self.out.push('\n');
+
+        if after_block && self.groups.is_empty() && !token.is_right_brace() {
+            self.out.push('\n');
+        }
+
+        self.out.push_str(&"    ".repeat(self.indent));
+    }
+
+    fn is_spaced(&self, prev: &Token, token: &Token, generic: bool) -> bool {
+        let wanted = !(generic
+            || self.prev_generic
+            || self.prev_unary
+            || token.is_close_delim()
+            || token.is_comma()
+            || token.is_semi_colon()
+            || token.is_colon()
+            || token.is_colon_colon()
+            || token.is_dot()
+            || token.is_dot_dot()
+            || token.is_question()
+            || prev.is_left_paren()
+            || prev.is_left_bracket()
+            || prev.is_dot()
+            || prev.is_dot_dot()
+            || prev.is_colon_colon()
+            || prev.is_pound()
+            || prev.is_apostrophe()
+            || prev.is_dollar()
+            || (token.is_not() && prev.is_ident())
+            || ((token.is_left_paren() || token.is_left_bracket()) && Self::is_callee(prev)));
+
+        // never glue two tokens that would lex as something else
+        wanted || !Self::is_separable(prev, token)
+    }
+
+    fn is_unary(&self, token: &Token) -> bool {
+        let operator = token.is_minus() || token.is_not() || token.is_star() || token.is_and();
+
+        operator && !self.prev.as_ref().is_some_and(Self::is_value)
+    }
+
+    fn is_value(token: &Token) -> bool {
+        match token {
+            Token::Ident(_) | Token::Literal(_) => true,
+            Token::Delim(delim) => delim.is_close(),
+            Token::Punct(punct) => punct.is_question() || punct.is_gt(),
+            Token::Keyword(keyword) => matches!(keyword.as_str(), "self" | "Self" | "super"),
+        }
+    }
+
+    fn is_callee(token: &Token) -> bool {
+        Self::is_value(token) || token.is_fn()
+    }
+
+    fn is_generic_base(token: &Token) -> bool {
+        match token {
+            Token::Ident(ident) => ident.unraw().starts_with(|c: char| c.is_ascii_uppercase()),
+            Token::Punct(punct) => punct.is_colon_colon(),
+            _ => false,
+        }
+    }
+
+    fn is_separable(prev: &Token, token: &Token) -> bool {
+        let left = prev.to_string();
+        let right = token.to_string();
+        let src = format!("{}{}", left, right);
+
+        match Lexer::new(&Span::from_str(&src)).lex() {
+            Err(_) => false,
+            Ok(tokens) => {
+                tokens.len() == 2 && tokens[0].to_string() == left && tokens[1].to_string() == right
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use zinq_error::Result;
+
+    use crate::{Comma, Ident, LInt, LParen, Printer, Punct, RParen, Token, TokenStream};
+
+    fn canonical(src: &str) -> Result<String> {
+        Ok(Printer::new().print(&TokenStream::from_str(src)?))
+    }
+
+    #[test]
+    fn should_print_synthesized_tokens() -> Result<()> {
+        let tokens: Vec<Token> = vec![
+            Ident::new("add").into(),
+            LParen::new().into(),
+            LInt::new(1).into(),
+            Punct::comma().into(),
+            Ident::new("b").into(),
+            Comma::new().into(),
+            RParen::new().into(),
+        ];
+
+        debug_assert!(
+            tokens
+                .iter()
+                .all(|t| zinq_parse::Spanned::span(t).is_generated())
+        );
+        debug_assert_eq!(Printer::new().print(&tokens), "add(1, b,)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_print_canonical_source() -> Result<()> {
+        let src = canonical(
+            "pub   struct User<T:ToString>{name:string,age:u8}
+            impl User{fn birthday(&mut self)->bool{let ok=self.age>=18&&!false;self.age+=1;-ok}}",
+        )?;
+
+        debug_assert_eq!(
+            src,
+            "pub struct User<T: ToString> {
+    name: string,
+    age: u8
+}
+
+impl User {
+    fn birthday(&mut self) -> bool {
+        let ok = self.age >= 18 && !false;
+        self.age += 1;
+        -ok
+    }
+}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reparse_printed_source() -> Result<()> {
+        let src = "#[Print(pretty)] fn main(){let a=items[0..2];if a<b{a}else{b};x=- -1;y=a-1;Vec::<u8>::new()}";
+        let stream = TokenStream::from_str(src)?;
+        let printed = Printer::new().print(&stream);
+        let reparsed = TokenStream::from_str(&printed)?;
+
+        debug_assert_eq!(
+            stream.iter().map(|t| t.to_string()).collect::<Vec<_>>(),
+            reparsed.iter().map(|t| t.to_string()).collect::<Vec<_>>()
+        );
+        debug_assert_eq!(
+            printed,
+            "#[Print(pretty)] fn main() {
+    let a = items[0..2];
+    if a < b {
+        a
+    } else {
+        b
+    };
+    x = --1;
+    y = a - 1;
+    Vec::<u8>::new()
+}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_print_empty_blocks() -> Result<()> {
+        debug_assert_eq!(canonical("fn a(){} fn b(){}")?, "fn a() {}\n\nfn b() {}");
+        Ok(())
+    }
+}
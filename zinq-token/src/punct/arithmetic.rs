@@ -1,6 +1,6 @@
 use zinq_parse::{Parse, Peek, Spanned};
 
-use crate::{Minus, Plus, Slash, Star};
+use crate::{Minus, Percent, Plus, Slash, Star};
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -9,6 +9,7 @@ pub enum Arithmetic {
     Sub(Minus),
     Div(Slash),
     Mult(Star),
+    Rem(Percent),
 }
 
 impl Arithmetic {
@@ -18,6 +19,7 @@ impl Arithmetic {
             Self::Sub(v) => v.name(),
             Self::Div(v) => v.name(),
             Self::Mult(v) => v.name(),
+            Self::Rem(v) => v.name(),
         }
     }
 
@@ -48,6 +50,10 @@ impl Arithmetic {
             _ => false,
         }
     }
+
+    pub fn is_rem(&self) -> bool {
+        matches!(self, Self::Rem(_))
+    }
 }
 
 impl From<Plus> for Arithmetic {
@@ -74,6 +80,12 @@ impl From<Star> for Arithmetic {
     }
 }
 
+impl From<Percent> for Arithmetic {
+    fn from(value: Percent) -> Self {
+        Self::Rem(value)
+    }
+}
+
 impl std::fmt::Display for Arithmetic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -81,6 +93,7 @@ impl std::fmt::Display for Arithmetic {
             Self::Sub(v) => write!(f, "{}", v),
             Self::Div(v) => write!(f, "{}", v),
             Self::Mult(v) => write!(f, "{}", v),
+            Self::Rem(v) => write!(f, "{}", v),
         }
     }
 }
@@ -93,7 +106,8 @@ impl Peek for Arithmetic {
         Ok(parser.peek::<Plus>(cursor).unwrap_or(false)
             || parser.peek::<Minus>(cursor).unwrap_or(false)
             || parser.peek::<Slash>(cursor).unwrap_or(false)
-            || parser.peek::<Star>(cursor).unwrap_or(false))
+            || parser.peek::<Star>(cursor).unwrap_or(false)
+            || parser.peek::<Percent>(cursor).unwrap_or(false))
     }
 }
 
@@ -111,11 +125,15 @@ impl Parse for Arithmetic {
         }
 
         if parser.peek::<Slash>(cursor).unwrap_or(false) {
-            return Ok(parser.parse::<Plus>(cursor)?.into());
+            return Ok(parser.parse::<Slash>(cursor)?.into());
         }
 
         if parser.peek::<Star>(cursor).unwrap_or(false) {
-            return Ok(parser.parse::<Minus>(cursor)?.into());
+            return Ok(parser.parse::<Star>(cursor)?.into());
+        }
+
+        if parser.peek::<Percent>(cursor).unwrap_or(false) {
+            return Ok(parser.parse::<Percent>(cursor)?.into());
         }
 
         Err(cursor.error(
@@ -132,6 +150,7 @@ impl Spanned for Arithmetic {
             Self::Sub(v) => v.span(),
             Self::Div(v) => v.span(),
             Self::Mult(v) => v.span(),
+            Self::Rem(v) => v.span(),
         }
     }
 }
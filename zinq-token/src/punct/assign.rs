@@ -0,0 +1,251 @@
+use zinq_parse::{Parse, Peek, Spanned};
+
+use crate::{
+    AndEq, CaretEq, Eq, GtGtEq, LtLtEq, MinusEq, OrEq, PercentEq, PlusEq, Punct, SlashEq, StarEq,
+};
+
+///
+/// ## Assign
+/// `=` or a compound assignment like `+=` or `<<=`
+///
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Assign {
+    Shl(LtLtEq),
+    Shr(GtGtEq),
+    Add(PlusEq),
+    Sub(MinusEq),
+    Mult(StarEq),
+    Div(SlashEq),
+    Rem(PercentEq),
+    And(AndEq),
+    Or(OrEq),
+    Xor(CaretEq),
+    Eq(Eq),
+}
+
+impl Assign {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Shl(v) => v.name(),
+            Self::Shr(v) => v.name(),
+            Self::Add(v) => v.name(),
+            Self::Sub(v) => v.name(),
+            Self::Mult(v) => v.name(),
+            Self::Div(v) => v.name(),
+            Self::Rem(v) => v.name(),
+            Self::And(v) => v.name(),
+            Self::Or(v) => v.name(),
+            Self::Xor(v) => v.name(),
+            Self::Eq(v) => v.name(),
+        }
+    }
+
+    pub fn is_shl(&self) -> bool {
+        matches!(self, Self::Shl(_))
+    }
+
+    pub fn is_shr(&self) -> bool {
+        matches!(self, Self::Shr(_))
+    }
+
+    pub fn is_add(&self) -> bool {
+        matches!(self, Self::Add(_))
+    }
+
+    pub fn is_sub(&self) -> bool {
+        matches!(self, Self::Sub(_))
+    }
+
+    pub fn is_mult(&self) -> bool {
+        matches!(self, Self::Mult(_))
+    }
+
+    pub fn is_div(&self) -> bool {
+        matches!(self, Self::Div(_))
+    }
+
+    pub fn is_rem(&self) -> bool {
+        matches!(self, Self::Rem(_))
+    }
+
+    pub fn is_and(&self) -> bool {
+        matches!(self, Self::And(_))
+    }
+
+    pub fn is_or(&self) -> bool {
+        matches!(self, Self::Or(_))
+    }
+
+    pub fn is_xor(&self) -> bool {
+        matches!(self, Self::Xor(_))
+    }
+
+    pub fn is_eq(&self) -> bool {
+        matches!(self, Self::Eq(_))
+    }
+
+    ///
+    /// ## is_compound
+    /// every assignment but a plain `=`
+    ///
+    pub fn is_compound(&self) -> bool {
+        !self.is_eq()
+    }
+}
+
+impl From<LtLtEq> for Assign {
+    fn from(value: LtLtEq) -> Self {
+        Self::Shl(value)
+    }
+}
+
+impl From<GtGtEq> for Assign {
+    fn from(value: GtGtEq) -> Self {
+        Self::Shr(value)
+    }
+}
+
+impl From<PlusEq> for Assign {
+    fn from(value: PlusEq) -> Self {
+        Self::Add(value)
+    }
+}
+
+impl From<MinusEq> for Assign {
+    fn from(value: MinusEq) -> Self {
+        Self::Sub(value)
+    }
+}
+
+impl From<StarEq> for Assign {
+    fn from(value: StarEq) -> Self {
+        Self::Mult(value)
+    }
+}
+
+impl From<SlashEq> for Assign {
+    fn from(value: SlashEq) -> Self {
+        Self::Div(value)
+    }
+}
+
+impl From<PercentEq> for Assign {
+    fn from(value: PercentEq) -> Self {
+        Self::Rem(value)
+    }
+}
+
+impl From<AndEq> for Assign {
+    fn from(value: AndEq) -> Self {
+        Self::And(value)
+    }
+}
+
+impl From<OrEq> for Assign {
+    fn from(value: OrEq) -> Self {
+        Self::Or(value)
+    }
+}
+
+impl From<CaretEq> for Assign {
+    fn from(value: CaretEq) -> Self {
+        Self::Xor(value)
+    }
+}
+
+impl From<Eq> for Assign {
+    fn from(value: Eq) -> Self {
+        Self::Eq(value)
+    }
+}
+
+impl std::fmt::Display for Assign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shl(v) => write!(f, "{}", v),
+            Self::Shr(v) => write!(f, "{}", v),
+            Self::Add(v) => write!(f, "{}", v),
+            Self::Sub(v) => write!(f, "{}", v),
+            Self::Mult(v) => write!(f, "{}", v),
+            Self::Div(v) => write!(f, "{}", v),
+            Self::Rem(v) => write!(f, "{}", v),
+            Self::And(v) => write!(f, "{}", v),
+            Self::Or(v) => write!(f, "{}", v),
+            Self::Xor(v) => write!(f, "{}", v),
+            Self::Eq(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Peek for Assign {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        // `Punct` reads the longest operator, so a
+        // prefix of another operator is never matched
+        Ok(matches!(
+            fork_parser.parse::<Punct>(&mut fork),
+            Ok(Punct::LtLtEq(_)
+                | Punct::GtGtEq(_)
+                | Punct::PlusEq(_)
+                | Punct::MinusEq(_)
+                | Punct::StarEq(_)
+                | Punct::SlashEq(_)
+                | Punct::PercentEq(_)
+                | Punct::AndEq(_)
+                | Punct::OrEq(_)
+                | Punct::CaretEq(_)
+                | Punct::Eq(_))
+        ))
+    }
+}
+
+impl Parse for Assign {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let start = cursor.fork();
+
+        match parser.parse::<Punct>(cursor)? {
+            Punct::LtLtEq(v) => Ok(v.into()),
+            Punct::GtGtEq(v) => Ok(v.into()),
+            Punct::PlusEq(v) => Ok(v.into()),
+            Punct::MinusEq(v) => Ok(v.into()),
+            Punct::StarEq(v) => Ok(v.into()),
+            Punct::SlashEq(v) => Ok(v.into()),
+            Punct::PercentEq(v) => Ok(v.into()),
+            Punct::AndEq(v) => Ok(v.into()),
+            Punct::OrEq(v) => Ok(v.into()),
+            Punct::CaretEq(v) => Ok(v.into()),
+            Punct::Eq(v) => Ok(v.into()),
+            _ => Err(start.error(
+                zinq_error::NOT_FOUND,
+                &format!("unexpected token '{}'", *start.peek()? as char),
+            )),
+        }
+    }
+}
+
+impl Spanned for Assign {
+    fn span(&self) -> zinq_parse::Span {
+        match self {
+            Self::Shl(v) => v.span(),
+            Self::Shr(v) => v.span(),
+            Self::Add(v) => v.span(),
+            Self::Sub(v) => v.span(),
+            Self::Mult(v) => v.span(),
+            Self::Div(v) => v.span(),
+            Self::Rem(v) => v.span(),
+            Self::And(v) => v.span(),
+            Self::Or(v) => v.span(),
+            Self::Xor(v) => v.span(),
+            Self::Eq(v) => v.span(),
+        }
+    }
+}
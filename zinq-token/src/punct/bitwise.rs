@@ -0,0 +1,143 @@
+use zinq_parse::{Parse, Peek, Spanned};
+
+use crate::{And, Caret, GtGt, LtLt, Or, Punct};
+
+///
+/// ## Bitwise
+/// `&`, `|`, `^`, `<<` or `>>`, each is only
+/// matched when no longer operator starts with it,
+/// so `&&` and `&=` are never read as `&`
+///
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Bitwise {
+    Shl(LtLt),
+    Shr(GtGt),
+    And(And),
+    Or(Or),
+    Xor(Caret),
+}
+
+impl Bitwise {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Shl(v) => v.name(),
+            Self::Shr(v) => v.name(),
+            Self::And(v) => v.name(),
+            Self::Or(v) => v.name(),
+            Self::Xor(v) => v.name(),
+        }
+    }
+
+    pub fn is_shl(&self) -> bool {
+        matches!(self, Self::Shl(_))
+    }
+
+    pub fn is_shr(&self) -> bool {
+        matches!(self, Self::Shr(_))
+    }
+
+    pub fn is_and(&self) -> bool {
+        matches!(self, Self::And(_))
+    }
+
+    pub fn is_or(&self) -> bool {
+        matches!(self, Self::Or(_))
+    }
+
+    pub fn is_xor(&self) -> bool {
+        matches!(self, Self::Xor(_))
+    }
+}
+
+impl From<LtLt> for Bitwise {
+    fn from(value: LtLt) -> Self {
+        Self::Shl(value)
+    }
+}
+
+impl From<GtGt> for Bitwise {
+    fn from(value: GtGt) -> Self {
+        Self::Shr(value)
+    }
+}
+
+impl From<And> for Bitwise {
+    fn from(value: And) -> Self {
+        Self::And(value)
+    }
+}
+
+impl From<Or> for Bitwise {
+    fn from(value: Or) -> Self {
+        Self::Or(value)
+    }
+}
+
+impl From<Caret> for Bitwise {
+    fn from(value: Caret) -> Self {
+        Self::Xor(value)
+    }
+}
+
+impl std::fmt::Display for Bitwise {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shl(v) => write!(f, "{}", v),
+            Self::Shr(v) => write!(f, "{}", v),
+            Self::And(v) => write!(f, "{}", v),
+            Self::Or(v) => write!(f, "{}", v),
+            Self::Xor(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Peek for Bitwise {
+    fn peek(
+        cursor: &zinq_parse::Cursor,
+        parser: &zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<bool> {
+        let mut fork = cursor.fork();
+        let mut fork_parser = parser.clone();
+
+        // `Punct` reads the longest operator, so a
+        // prefix of another operator is never matched
+        Ok(matches!(
+            fork_parser.parse::<Punct>(&mut fork),
+            Ok(Punct::LtLt(_) | Punct::GtGt(_) | Punct::And(_) | Punct::Or(_) | Punct::Caret(_))
+        ))
+    }
+}
+
+impl Parse for Bitwise {
+    fn parse(
+        cursor: &mut zinq_parse::Cursor,
+        parser: &mut zinq_parse::ZinqParser,
+    ) -> zinq_error::Result<Self> {
+        let start = cursor.fork();
+
+        match parser.parse::<Punct>(cursor)? {
+            Punct::LtLt(v) => Ok(v.into()),
+            Punct::GtGt(v) => Ok(v.into()),
+            Punct::And(v) => Ok(v.into()),
+            Punct::Or(v) => Ok(v.into()),
+            Punct::Caret(v) => Ok(v.into()),
+            _ => Err(start.error(
+                zinq_error::NOT_FOUND,
+                &format!("unexpected token '{}'", *start.peek()? as char),
+            )),
+        }
+    }
+}
+
+impl Spanned for Bitwise {
+    fn span(&self) -> zinq_parse::Span {
+        match self {
+            Self::Shl(v) => v.span(),
+            Self::Shr(v) => v.span(),
+            Self::And(v) => v.span(),
+            Self::Or(v) => v.span(),
+            Self::Xor(v) => v.span(),
+        }
+    }
+}
@@ -1,13 +1,17 @@
 mod arithmetic;
+mod assign;
+mod bitwise;
 mod cmp;
 mod logical;
 
 pub use arithmetic::*;
+pub use assign::*;
+pub use bitwise::*;
 pub use cmp::*;
 pub use logical::*;
 
 macro_rules! define_puncts {
-    ($($token:literal, pub struct $name:ident, $is_method:ident),*) => {
+    ($($token:literal, pub struct $name:ident, $is_method:ident, $new_method:ident),*) => {
         #[derive(Debug, Clone, PartialEq, Eq, Hash)]
         pub enum Punct {
             $($name($name),)*
@@ -32,6 +36,29 @@ macro_rules! define_puncts {
             )*
         }
 
+        impl Punct {
+            $(
+                #[doc = "a `"]
+                #[doc = $token]
+                #[doc = "` with a generated span"]
+                #[inline]
+                pub fn $new_method() -> Self {
+                    Self::$name($name::new())
+                }
+            )*
+        }
+
+        impl Punct {
+            ///
+            /// ## TABLE
+            /// every punct in declaration order, paired
+            /// with a constructor used by the `Lexer`
+            ///
+            pub(crate) const TABLE: &'static [(&'static str, fn(zinq_parse::Span) -> $crate::Token)] = &[
+                $(($token, |span| $crate::Token::from($name { span })),)*
+            ];
+        }
+
         impl zinq_parse::Peek for Punct {
             #[inline]
             fn peek(cursor: &zinq_parse::Cursor, parser: &zinq_parse::ZinqParser) -> zinq_error::Result<bool> {
@@ -98,15 +125,39 @@ macro_rules! define_puncts {
             }
 
             impl $name {
+                #[doc = "a `"]
+                #[doc = $token]
+                #[doc = "` with a generated span"]
+                pub fn new() -> Self {
+                    Self {
+                        span: zinq_parse::Span::generated($token),
+                    }
+                }
+
                 pub fn name(&self) -> &'static str {
                     stringify!($name)
                 }
             }
 
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
             impl zinq_parse::Peek for $name {
                 #[inline]
                 fn peek(cursor: &zinq_parse::Cursor, _: &zinq_parse::ZinqParser) -> zinq_error::Result<bool> {
-                    Ok(cursor.peek_n($token.len())? == $token.as_bytes())
+                    if cursor.peek_n($token.len())? != $token.as_bytes() {
+                        return Ok(false);
+                    }
+
+                    // word like puncts (`_`) must end at an identifier boundary
+                    if $token.bytes().all(|b| $crate::is_ident_byte(&b)) {
+                        return Ok(!cursor.peek_at(cursor.end().index() + $token.len()).is_ok_and($crate::is_ident_byte));
+                    }
+
+                    Ok(true)
                 }
             }
 
@@ -117,8 +168,12 @@ macro_rules! define_puncts {
                         return Err(cursor.error(zinq_error::NOT_FOUND, &format!("expected '{}'", $token)));
                     }
 
+                    if $token.bytes().all(|b| $crate::is_ident_byte(&b)) && cursor.peek().is_ok_and($crate::is_ident_byte) {
+                        return Err(cursor.error(zinq_error::NOT_FOUND, &format!("expected '{}'", $token)));
+                    }
+
                     Ok(Self {
-                        span: cursor.span().clone(),
+                        span: cursor.source_span(),
                     })
                 }
             }
@@ -190,42 +245,51 @@ macro_rules! define_puncts {
 }
 
 define_puncts! {
-    "&&",       pub struct AndAnd,       is_and_and,
-    "&",        pub struct And,          is_and,
-    "@",        pub struct At,           is_at,
-    "^=",       pub struct CaretEq,      is_caret_eq,
-    "^",        pub struct Caret,        is_caret,
-    "::",       pub struct ColonColon,   is_colon_colon,
-    ":",        pub struct Colon,        is_colon,
-    ";",        pub struct SemiColon,    is_semi_colon,
-    ",",        pub struct Comma,        is_comma,
-    "$",        pub struct Dollar,       is_dollar,
-    "..",       pub struct DotDot,       is_dot_dot,
-    ".",        pub struct Dot,          is_dot,
-    "==",       pub struct EqEq,         is_eq_eq,
-    "=>",       pub struct EqArrow,      is_eq_arrow,
-    "=",        pub struct Eq,           is_eq,
-    ">=",       pub struct GtEq,         is_gt_eq,
-    ">",        pub struct Gt,           is_gt,
-    "<=",       pub struct LtEq,         is_lt_eq,
-    "<",        pub struct Lt,           is_lt,
-    "_",        pub struct Underscore,   is_underscore,
-    "->",       pub struct RArrow,       is_right_arrow,
-    "-=",       pub struct MinusEq,      is_minus_eq,
-    "-",        pub struct Minus,        is_minus,
-    "!=",       pub struct NotEq,        is_not_eq,
-    "!",        pub struct Not,          is_not,
-    "||",       pub struct OrOr,         is_or_or,
-    "|",        pub struct Or,           is_or,
-    "%",        pub struct Percent,      is_percent,
-    "+=",       pub struct PlusEq,       is_plus_eq,
-    "+",        pub struct Plus,         is_plus,
-    "#",        pub struct Pound,        is_pound,
-    "?",        pub struct Question,     is_question,
-    "/=",       pub struct SlashEq,      is_slash_eq,
-    "//",       pub struct SlashSlash,   is_slash_slash,
-    "/",        pub struct Slash,        is_slash,
-    "*=",       pub struct StarEq,       is_star_eq,
-    "*",        pub struct Star,         is_star,
-    "~",        pub struct Tilde,        is_tilde
+    "&&",       pub struct AndAnd,       is_and_and,     and_and,
+    "&=",       pub struct AndEq,        is_and_eq,      and_eq,
+    "&",        pub struct And,          is_and,         and,
+    "@",        pub struct At,           is_at,          at,
+    "'",        pub struct Apostrophe,   is_apostrophe,  apostrophe,
+    "^=",       pub struct CaretEq,      is_caret_eq,    caret_eq,
+    "^",        pub struct Caret,        is_caret,       caret,
+    "::",       pub struct ColonColon,   is_colon_colon, colon_colon,
+    ":",        pub struct Colon,        is_colon,       colon,
+    ";",        pub struct SemiColon,    is_semi_colon,  semi_colon,
+    ",",        pub struct Comma,        is_comma,       comma,
+    "$",        pub struct Dollar,       is_dollar,      dollar,
+    "..=",      pub struct DotDotEq,     is_dot_dot_eq,  dot_dot_eq,
+    "..",       pub struct DotDot,       is_dot_dot,     dot_dot,
+    ".",        pub struct Dot,          is_dot,         dot,
+    "==",       pub struct EqEq,         is_eq_eq,       eq_eq,
+    "=>",       pub struct EqArrow,      is_eq_arrow,    eq_arrow,
+    "=",        pub struct Eq,           is_eq,          eq,
+    ">>=",      pub struct GtGtEq,       is_gt_gt_eq,    gt_gt_eq,
+    ">>",       pub struct GtGt,         is_gt_gt,       gt_gt,
+    ">=",       pub struct GtEq,         is_gt_eq,       gt_eq,
+    ">",        pub struct Gt,           is_gt,          gt,
+    "<<=",      pub struct LtLtEq,       is_lt_lt_eq,    lt_lt_eq,
+    "<<",       pub struct LtLt,         is_lt_lt,       lt_lt,
+    "<=",       pub struct LtEq,         is_lt_eq,       lt_eq,
+    "<",        pub struct Lt,           is_lt,          lt,
+    "_",        pub struct Underscore,   is_underscore,  underscore,
+    "->",       pub struct RArrow,       is_right_arrow, right_arrow,
+    "-=",       pub struct MinusEq,      is_minus_eq,    minus_eq,
+    "-",        pub struct Minus,        is_minus,       minus,
+    "!=",       pub struct NotEq,        is_not_eq,      not_eq,
+    "!",        pub struct Not,          is_not,         not,
+    "||",       pub struct OrOr,         is_or_or,       or_or,
+    "|=",       pub struct OrEq,         is_or_eq,       or_eq,
+    "|",        pub struct Or,           is_or,          or,
+    "%=",       pub struct PercentEq,    is_percent_eq,  percent_eq,
+    "%",        pub struct Percent,      is_percent,     percent,
+    "+=",       pub struct PlusEq,       is_plus_eq,     plus_eq,
+    "+",        pub struct Plus,         is_plus,        plus,
+    "#",        pub struct Pound,        is_pound,       pound,
+    "?",        pub struct Question,     is_question,    question,
+    "/=",       pub struct SlashEq,      is_slash_eq,    slash_eq,
+    "//",       pub struct SlashSlash,   is_slash_slash, slash_slash,
+    "/",        pub struct Slash,        is_slash,       slash,
+    "*=",       pub struct StarEq,       is_star_eq,     star_eq,
+    "*",        pub struct Star,         is_star,        star,
+    "~",        pub struct Tilde,        is_tilde,       tilde
 }
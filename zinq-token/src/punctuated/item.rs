@@ -28,6 +28,20 @@ where
             Self::Final(v, _) => v,
         }
     }
+
+    pub fn value_mut(&mut self) -> &mut T {
+        match self {
+            Self::Leading(v, _) => v,
+            Self::Final(v, _) => v,
+        }
+    }
+
+    pub fn map<F: FnOnce(T) -> T>(self, f: F) -> Self {
+        match self {
+            Self::Leading(v, p) => Self::Leading(f(v), p),
+            Self::Final(v, p) => Self::Final(f(v), p),
+        }
+    }
 }
 
 impl<T, P> From<(T, P)> for Item<T, P>
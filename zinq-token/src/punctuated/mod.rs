@@ -25,6 +25,16 @@ where
     }
 }
 
+impl<T, P> std::ops::DerefMut for Punctuated<T, P>
+where
+    T: std::fmt::Display + Parse,
+    P: std::fmt::Display + Parse,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.items
+    }
+}
+
 impl<T, P> Punctuated<T, P>
 where
     T: std::fmt::Display + Parse,
@@ -38,6 +48,40 @@ where
         self.items.push(item);
         self
     }
+
+    ///
+    /// ## remove
+    /// remove the value at `index`, removing the
+    /// final value makes the one before it final
+    ///
+    pub fn remove(&mut self, index: usize) -> T {
+        let value = match self.items.remove(index) {
+            Item::Leading(v, _) => v,
+            Item::Final(v, _) => v,
+        };
+
+        if index == self.items.len()
+            && let Some(Item::Leading(v, p)) = self.items.pop()
+        {
+            self.items.push(Item::Final(v, Some(p)));
+        }
+
+        value
+    }
+
+    ///
+    /// ## map
+    /// rebuild each value, keeping the punctuation
+    ///
+    pub fn map<F: FnMut(T) -> T>(self, mut f: F) -> Self {
+        Self {
+            items: self
+                .items
+                .into_iter()
+                .map(|item| item.map(&mut f))
+                .collect(),
+        }
+    }
 }
 
 impl<T, P> std::fmt::Display for Punctuated<T, P>
@@ -136,14 +180,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn should_remove() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"1, 2, 3").cursor();
+        let mut stream = parser.parse::<Punctuated<LInt, Comma>>(&mut cursor)?;
+
+        debug_assert_eq!(stream.remove(2).to_string(), "3");
+        debug_assert!(stream[1].is_final());
+        debug_assert_eq!(stream.remove(0).to_string(), "1");
+        debug_assert_eq!(stream.len(), 1);
+        debug_assert_eq!(stream[0].value().to_string(), "2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_map() -> Result<()> {
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = Span::from_bytes(b"a, b,").cursor();
+        let mut stream = parser.parse::<Punctuated<Ident, Comma>>(&mut cursor)?;
+
+        let first = stream[0].value().clone();
+        stream = stream.map(|_| first.clone());
+
+        debug_assert_eq!(stream.len(), 2);
+        debug_assert_eq!(stream[1].value().to_string(), "a");
+        debug_assert!(stream[1].is_final());
+
+        *stream[0].value_mut() = parser.parse::<Ident>(&mut Span::from_bytes(b"c").cursor())?;
+        debug_assert_eq!(stream[0].value().to_string(), "c");
+
+        Ok(())
+    }
+
     #[test]
     fn should_parse_with_trailing() -> Result<()> {
         let mut parser = zinq_parse::ZinqParser;
-        let mut cursor = Span::from_bytes(b"hello::world::mod::").cursor();
+        let mut cursor = Span::from_bytes(b"hello::world::r#mod::").cursor();
         let stream = parser.parse::<Punctuated<Ident, ColonColon>>(&mut cursor)?;
 
         debug_assert_eq!(stream.len(), 3);
-        debug_assert_eq!(stream.to_string(), "hello::world::mod::");
+        debug_assert_eq!(stream.to_string(), "hello::world::r#mod::");
 
         Ok(())
     }
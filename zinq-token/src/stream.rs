@@ -3,7 +3,7 @@ use std::{ops::Index, str::FromStr};
 use zinq_error::{Result, ZinqError};
 use zinq_parse::{Parse, Peek, Span, Spanned};
 
-use crate::{ToTokens, Token};
+use crate::{Lexer, Printer, ToTokens, Token, TokenTree};
 
 #[derive(Debug, Default, Clone, Hash)]
 pub struct TokenStream {
@@ -19,14 +19,30 @@ impl TokenStream {
         self.inner.extend(tokens.to_tokens()?);
         Ok(self)
     }
+
+    ///
+    /// ## trees
+    /// nest the stream by its delimiters
+    ///
+    pub fn trees(&self) -> Result<Vec<TokenTree>> {
+        TokenTree::from_tokens(self.inner.iter().cloned())
+    }
+
+    ///
+    /// ## build
+    /// create a stream by pushing into it,
+    /// used by the `quote!` macro
+    ///
+    pub fn build<F: FnOnce(&mut Self) -> Result<()>>(f: F) -> Result<Self> {
+        let mut stream = Self::new();
+        f(&mut stream)?;
+        Ok(stream)
+    }
 }
 
 impl ToTokens for Span {
     fn to_tokens(&self) -> Result<TokenStream> {
-        let mut cursor = self.cursor();
-        let mut parser = zinq_parse::ZinqParser::new();
-
-        parser.parse::<TokenStream>(&mut cursor)
+        Ok(Lexer::new(self).lex()?.into_iter().collect())
     }
 }
 
@@ -38,10 +54,7 @@ impl TryFrom<&[u8]> for TokenStream {
             return Ok(Self::new());
         }
 
-        let mut parser = zinq_parse::ZinqParser;
-        let mut cursor = Span::from_bytes(value).cursor();
-
-        parser.parse::<Self>(&mut cursor)
+        Span::from_bytes(value).to_tokens()
     }
 }
 
@@ -53,10 +66,7 @@ impl<const N: usize> TryFrom<&[u8; N]> for TokenStream {
             return Ok(Self::new());
         }
 
-        let mut parser = zinq_parse::ZinqParser;
-        let mut cursor = Span::from_bytes(value).cursor();
-
-        parser.parse::<Self>(&mut cursor)
+        Span::from_bytes(value).to_tokens()
     }
 }
 
@@ -68,10 +78,7 @@ impl FromStr for TokenStream {
             return Ok(Self::new());
         }
 
-        let mut parser = zinq_parse::ZinqParser;
-        let mut cursor = Span::from_str(s).cursor();
-
-        parser.parse::<Self>(&mut cursor)
+        Span::from_str(s).to_tokens()
     }
 }
 
@@ -122,6 +129,21 @@ impl Extend<Self> for TokenStream {
 
 impl std::fmt::Display for TokenStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let joinable = match self.inner.first() {
+            None => true,
+            Some(first) => {
+                let span = first.span();
+                self.inner
+                    .iter()
+                    .all(|t| !t.span().is_generated() && t.span().is_same_source(&span))
+            }
+        };
+
+        // synthesized or unrelated tokens have no source between them
+        if !joinable {
+            return write!(f, "{}", Printer::new().print(&self.inner));
+        }
+
         write!(f, "{}", self.span())
     }
 }
@@ -199,7 +221,7 @@ mod tests {
 
     use zinq_error::Result;
 
-    use crate::TokenStream;
+    use crate::{Ident, LInt, TokenStream, quote};
 
     #[test]
     fn should_parse_str() -> Result<()> {
@@ -219,4 +241,14 @@ mod tests {
         debug_assert_eq!(a, b);
         Ok(())
     }
+
+    #[test]
+    fn should_print_synthesized() -> Result<()> {
+        let name = Ident::new("total");
+        let value = LInt::new(10);
+        let stream = quote!(let #name: u32 = #value + 1;)?;
+
+        debug_assert_eq!(stream.to_string(), "let total: u32 = 10 + 1;");
+        Ok(())
+    }
 }
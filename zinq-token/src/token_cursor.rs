@@ -0,0 +1,353 @@
+use std::rc::Rc;
+
+use zinq_error::{Error, NOT_FOUND, Result};
+use zinq_parse::{
+    Cursor, Diagnostic, Location, Parse, ParseError, ParseResult, Peek, SourceMap, Span, Spanned,
+    ZinqParser,
+};
+
+use crate::Token;
+
+///
+/// ## TokenCursor
+/// a cursor over already lexed tokens, anything
+/// implementing `Parse` can be parsed from it and
+/// keeps the spans of the original tokens
+///
+/// the tokens are printed once when the cursor is
+/// created, parsing reads that source from the
+/// current token onwards, spans and diagnostics
+/// are mapped back to the tokens they came from
+///
+/// ```
+/// let mut cursor = TokenCursor::new(&stream);
+/// let path = cursor.parse::<Path>(&mut parser)?;
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct TokenCursor {
+    tokens: Rc<[Token]>,
+    source: Rc<Source>,
+    index: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl TokenCursor {
+    pub fn new(tokens: &[Token]) -> Self {
+        Self {
+            tokens: tokens.into(),
+            source: Rc::new(Source::new(tokens)),
+            index: 0,
+            diagnostics: vec![],
+        }
+    }
+
+    ///
+    /// ## fork
+    /// a cursor at the same position that
+    /// can be moved independently
+    ///
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// the index of the next token
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    ///
+    /// ## seek
+    /// move the cursor to the token at `index`
+    ///
+    pub fn seek(&mut self, index: usize) -> &mut Self {
+        self.index = index.min(self.tokens.len());
+        self
+    }
+
+    pub fn eof(&self) -> bool {
+        self.index >= self.tokens.len()
+    }
+
+    ///
+    /// ## rest
+    /// the tokens not yet consumed
+    ///
+    pub fn rest(&self) -> &[Token] {
+        &self.tokens[self.index.min(self.tokens.len())..]
+    }
+
+    pub fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index)
+    }
+
+    pub fn peek_n(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.index + n)
+    }
+
+    ///
+    /// ## peek_syntax
+    /// peek a `Peek` type at the current position
+    ///
+    pub fn peek_syntax<T: Peek>(&self, parser: &ZinqParser) -> Result<bool> {
+        if self.eof() {
+            return Ok(false);
+        }
+
+        parser.peek::<T>(&self.source.cursor(self.index))
+    }
+
+    ///
+    /// ## parse
+    /// parse a type from the remaining tokens,
+    /// moving past the tokens it consumed
+    ///
+    pub fn parse<T: Parse>(&mut self, parser: &mut ZinqParser) -> Result<T> {
+        let mut cursor = self.source.cursor(self.index);
+        let value = parser.parse::<T>(&mut cursor)?;
+
+        self.index = self
+            .index
+            .max(self.source.map.count(cursor.span().end().index()));
+
+        self.diagnostics.extend_from_slice(cursor.diagnostics());
+        Ok(value)
+    }
+
+    ///
+    /// ## build
+    /// build the parse result with the diagnostics
+    /// emitted by everything parsed so far
+    ///
+    pub fn build<T>(&self, value: T) -> ParseResult<T> {
+        if self.diagnostics.is_empty() {
+            return ParseResult::from_value(value);
+        }
+
+        let span = match (self.tokens.first(), self.tokens.last()) {
+            (Some(first), Some(last)) if first.span().is_same_source(&last.span()) => {
+                Span::join(first.span(), last.span())
+            }
+            (Some(first), _) => first.span(),
+            _ => Span::default(),
+        };
+
+        ParseResult {
+            value,
+            diagnostic: Some(Diagnostic::noop(span).children(&self.diagnostics).build()),
+        }
+    }
+
+    ///
+    /// ## error
+    /// create an error at the next token,
+    /// or at the last token once exhausted
+    ///
+    pub fn error(&self, message: &str) -> zinq_error::ZinqError {
+        let span = match self.peek().or(self.tokens.last()) {
+            None => Span::default(),
+            Some(token) => token.span(),
+        };
+
+        ParseError::from_error(
+            span,
+            Error::new().code(NOT_FOUND).message(message).build().into(),
+        )
+        .into()
+    }
+}
+
+impl Iterator for TokenCursor {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.tokens.get(self.index)?.clone();
+        self.index += 1;
+        Some(token)
+    }
+}
+
+impl From<&crate::TokenStream> for TokenCursor {
+    fn from(value: &crate::TokenStream) -> Self {
+        Self::new(value)
+    }
+}
+
+#[derive(Debug)]
+struct Source {
+    span: Span,
+    map: SourceMap,
+    starts: Vec<Location>,
+}
+
+impl Source {
+    // print the tokens one space apart, which never
+    // merges them, remembering where each came from
+    fn new(tokens: &[Token]) -> Self {
+        let mut text = String::new();
+        let mut entries = vec![];
+
+        for token in tokens {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+
+            let start = text.len();
+            text.push_str(&token.to_string());
+            entries.push((start, text.len(), token.span()));
+        }
+
+        let span = Span::from_str(&text);
+        let mut location = *span.start();
+        let mut starts = vec![];
+
+        for (start, _, _) in &entries {
+            while location.index() < *start {
+                location.next(text.as_bytes());
+            }
+
+            starts.push(location);
+        }
+
+        Self {
+            span,
+            map: SourceMap::new(entries),
+            starts,
+        }
+    }
+
+    // a cursor over the source starting at the token `index`
+    fn cursor(&self, index: usize) -> Cursor {
+        let at = match self.starts.get(index) {
+            None => *self.span.end(),
+            Some(v) => *v,
+        };
+
+        Cursor::from(self.span.between(at, at)).with_map(self.map.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use zinq_error::Result;
+    use zinq_parse::{Cursor, Parse, Peek, Span, Spanned, ZinqParser, diagnostic::NOOP};
+
+    use crate::{Comma, Ident, LInt, Punctuated, TokenCursor, TokenStream};
+
+    // an ident that reports a diagnostic where it starts
+    #[derive(Debug, Clone)]
+    struct Flagged(Ident);
+
+    impl Peek for Flagged {
+        fn peek(cursor: &Cursor, parser: &ZinqParser) -> Result<bool> {
+            parser.peek::<Ident>(cursor)
+        }
+    }
+
+    impl Parse for Flagged {
+        fn parse(cursor: &mut Cursor, parser: &mut ZinqParser) -> Result<Self> {
+            cursor.report_as(NOOP, "flagged");
+            Ok(Self(parser.parse::<Ident>(cursor)?))
+        }
+    }
+
+    impl Spanned for Flagged {
+        fn span(&self) -> Span {
+            self.0.span()
+        }
+    }
+
+    #[test]
+    fn should_parse_tokens() -> Result<()> {
+        let stream = TokenStream::from_str("a,  b,\n c rest")?;
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = TokenCursor::new(&stream);
+        let items = cursor.parse::<Punctuated<Ident, Comma>>(&mut parser)?;
+
+        debug_assert_eq!(items.len(), 3);
+        debug_assert_eq!(items.to_string(), "a,  b,\n c");
+        debug_assert_eq!(items.last().unwrap().value().span().start().line(), 1);
+        debug_assert_eq!(cursor.peek().unwrap().to_string(), "rest");
+        debug_assert!(cursor.parse::<Ident>(&mut parser).is_ok());
+        debug_assert!(cursor.eof());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_synthesized_tokens() -> Result<()> {
+        let value = LInt::new(7);
+        let stream = crate::quote!(#value)?;
+        let mut parser = zinq_parse::ZinqParser;
+        let parsed = TokenCursor::new(&stream).parse::<LInt>(&mut parser)?;
+
+        debug_assert!(parsed.span().is_generated());
+        debug_assert_eq!(parsed.to_u64()?, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_keep_original_spans() -> Result<()> {
+        let stream = TokenStream::from_str("x(\n  1 )")?;
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = TokenCursor::new(&stream[2..]);
+        let value = cursor.parse::<LInt>(&mut parser)?;
+
+        debug_assert_eq!(value.span().start().line(), 1);
+        debug_assert_eq!(value.span().start().column(), 2);
+        debug_assert!(cursor.parse::<Ident>(&mut parser).is_err());
+        debug_assert!(cursor.peek().unwrap().is_right_paren());
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_after_moving() -> Result<()> {
+        let stream = TokenStream::from_str("a b c d")?;
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = TokenCursor::new(&stream);
+
+        cursor.next();
+
+        let fork = cursor.fork();
+        let b = cursor.parse::<Ident>(&mut parser)?;
+
+        debug_assert_eq!(b.to_string(), "b");
+        debug_assert_eq!(b.span().start().column(), 2);
+        debug_assert!(cursor.peek_syntax::<Ident>(&parser)?);
+        debug_assert_eq!(cursor.parse::<Ident>(&mut parser)?.to_string(), "c");
+        debug_assert_eq!(fork.peek().unwrap().to_string(), "b");
+        debug_assert_eq!(cursor.rest().len(), 1);
+        debug_assert_eq!(cursor.seek(0).index(), 0);
+        debug_assert_eq!(cursor.parse::<Ident>(&mut parser)?.to_string(), "a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_report_at_original_offsets() -> Result<()> {
+        let stream = TokenStream::from_str("a,\n\n    flag")?;
+        let mut parser = zinq_parse::ZinqParser;
+        let mut cursor = TokenCursor::new(&stream[2..]);
+        let result = cursor
+            .parse::<Flagged>(&mut parser)
+            .map(|v| cursor.build(v))?;
+        let diagnostic = result.diagnostic.expect("expected diagnostic");
+        let mut leaf = &diagnostic;
+
+        while let Some(child) = leaf.children.first() {
+            leaf = child;
+        }
+
+        debug_assert_eq!(leaf.message.as_deref(), Some("flagged"));
+        debug_assert_eq!(leaf.span.start().index(), 8);
+        debug_assert_eq!(leaf.span.start().line(), 2);
+        debug_assert_eq!(leaf.span.start().column(), 4);
+        debug_assert_eq!(diagnostic.span.start().index(), 8);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,324 @@
+use zinq_error::Result;
+use zinq_parse::{Cursor, EOF, Parse, Peek, Span, Spanned};
+
+use crate::{CloseDelim, Delim, OpenDelim, ToTokens, Token, TokenStream, UnbalancedDelimError};
+
+///
+/// ## TokenTree
+/// a token or a delimited group of token trees,
+/// so delimiters are always balanced
+///
+/// ```
+/// let trees = Lexer::new(&Span::from_str("a(b, [c])")).lex_trees()?;
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TokenTree {
+    Group(Group),
+    Leaf(Token),
+}
+
+impl TokenTree {
+    ///
+    /// ## from_tokens
+    /// nest a flat list of tokens by their delimiters
+    ///
+    pub fn from_tokens<I: IntoIterator<Item = Token>>(tokens: I) -> Result<Vec<Self>> {
+        let mut builder = TreeBuilder::default();
+
+        for token in tokens {
+            builder.push(token)?;
+        }
+
+        builder.finish()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Group(_) => "Group",
+            Self::Leaf(v) => v.name(),
+        }
+    }
+
+    pub fn is_group(&self) -> bool {
+        matches!(self, Self::Group(_))
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        matches!(self, Self::Leaf(_))
+    }
+}
+
+impl From<Group> for TokenTree {
+    fn from(value: Group) -> Self {
+        Self::Group(value)
+    }
+}
+
+impl From<Token> for TokenTree {
+    fn from(value: Token) -> Self {
+        Self::Leaf(value)
+    }
+}
+
+impl std::fmt::Display for TokenTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for TokenTree {
+    fn peek(cursor: &Cursor, parser: &zinq_parse::ZinqParser) -> Result<bool> {
+        Ok(!parser.peek::<CloseDelim>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for TokenTree {
+    fn parse(cursor: &mut Cursor, parser: &mut zinq_parse::ZinqParser) -> Result<Self> {
+        let mut builder = TreeBuilder::default();
+
+        loop {
+            while cursor.peek().is_ok_and(|b| b.is_ascii_whitespace()) {
+                cursor.shift_next()?;
+            }
+
+            if cursor.eof() {
+                builder.finish()?;
+                return Err(cursor.error(EOF, "end of input stream"));
+            }
+
+            builder.push(parser.parse::<Token>(cursor)?)?;
+
+            if builder.stack.is_empty() {
+                return Ok(builder.trees.pop().expect("expected a token tree"));
+            }
+        }
+    }
+}
+
+impl Spanned for TokenTree {
+    fn span(&self) -> Span {
+        match self {
+            Self::Group(v) => v.span(),
+            Self::Leaf(v) => v.span(),
+        }
+    }
+}
+
+impl ToTokens for TokenTree {
+    fn to_tokens(&self) -> Result<TokenStream> {
+        match self {
+            Self::Group(v) => v.to_tokens(),
+            Self::Leaf(v) => v.to_tokens(),
+        }
+    }
+}
+
+///
+/// ## Group
+/// token trees enclosed by a matching
+/// pair of delimiters
+///
+/// `(...)`, `[...]` or `{...}`
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Group {
+    pub open: OpenDelim,
+    pub stream: Vec<TokenTree>,
+    pub close: CloseDelim,
+}
+
+impl Group {
+    ///
+    /// ## inner_tokens
+    /// the flattened tokens between the delimiters
+    ///
+    pub fn inner_tokens(&self) -> Result<TokenStream> {
+        let mut stream = TokenStream::new();
+
+        for tree in &self.stream {
+            stream.push(tree)?;
+        }
+
+        Ok(stream)
+    }
+}
+
+impl std::ops::Deref for Group {
+    type Target = [TokenTree];
+
+    fn deref(&self) -> &Self::Target {
+        &self.stream
+    }
+}
+
+impl std::fmt::Display for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.span())
+    }
+}
+
+impl Peek for Group {
+    fn peek(cursor: &Cursor, parser: &zinq_parse::ZinqParser) -> Result<bool> {
+        Ok(parser.peek::<OpenDelim>(cursor).unwrap_or(false))
+    }
+}
+
+impl Parse for Group {
+    fn parse(cursor: &mut Cursor, parser: &mut zinq_parse::ZinqParser) -> Result<Self> {
+        if !parser.peek::<OpenDelim>(cursor).unwrap_or(false) {
+            return Err(cursor.error(zinq_error::NOT_FOUND, "expected '(', '[' or '{'"));
+        }
+
+        match parser.parse::<TokenTree>(cursor)? {
+            TokenTree::Group(v) => Ok(v),
+            TokenTree::Leaf(_) => unreachable!("open delimiters always start a group"),
+        }
+    }
+}
+
+impl Spanned for Group {
+    fn span(&self) -> Span {
+        Span::join(self.open.span(), self.close.span())
+    }
+}
+
+impl ToTokens for Group {
+    fn to_tokens(&self) -> Result<TokenStream> {
+        let mut stream = TokenStream::new();
+        stream.push(&self.open)?;
+        stream.push(&self.inner_tokens()?)?;
+        stream.push(&self.close)?;
+        Ok(stream)
+    }
+}
+
+///
+/// ## TreeBuilder
+/// nests tokens as they are pushed, failing on
+/// the first unbalanced delimiter so it is
+/// only ever reported once
+///
+#[derive(Debug, Default)]
+pub(crate) struct TreeBuilder {
+    trees: Vec<TokenTree>,
+    stack: Vec<(OpenDelim, Vec<TokenTree>)>,
+}
+
+impl TreeBuilder {
+    pub(crate) fn push(&mut self, token: Token) -> Result<()> {
+        let tree = match token {
+            Token::Delim(Delim::Open(open)) => {
+                let parent = std::mem::take(&mut self.trees);
+                self.stack.push((open, parent));
+                return Ok(());
+            }
+            Token::Delim(Delim::Close(close)) => {
+                let (open, parent) = match self.stack.pop() {
+                    None => return Err(UnbalancedDelimError::unexpected(close.span()).into()),
+                    Some(v) => v,
+                };
+
+                if !open.is_closed_by(&close) {
+                    return Err(UnbalancedDelimError::mismatched(open.span(), close.span()).into());
+                }
+
+                let stream = std::mem::replace(&mut self.trees, parent);
+                TokenTree::Group(Group {
+                    open,
+                    stream,
+                    close,
+                })
+            }
+            other => TokenTree::Leaf(other),
+        };
+
+        self.trees.push(tree);
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Result<Vec<TokenTree>> {
+        if let Some((open, _)) = self.stack.last() {
+            return Err(UnbalancedDelimError::unclosed(open.span()).into());
+        }
+
+        Ok(self.trees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinq_error::Result;
+    use zinq_parse::Span;
+
+    use crate::{Lexer, ToTokens, TokenTree};
+
+    #[test]
+    fn should_nest_groups() -> Result<()> {
+        let trees = Lexer::new(&Span::from_str("a(b, [c]) { d }")).lex_trees()?;
+
+        debug_assert_eq!(trees.len(), 3);
+        debug_assert!(trees[0].is_leaf());
+        debug_assert!(trees[1].is_group());
+        debug_assert_eq!(trees[1].to_string(), "(b, [c])");
+        debug_assert_eq!(trees[2].to_string(), "{ d }");
+
+        let TokenTree::Group(group) = &trees[1] else {
+            unreachable!()
+        };
+
+        debug_assert_eq!(group.len(), 3);
+        debug_assert_eq!(group[2].to_string(), "[c]");
+        debug_assert_eq!(group.to_tokens()?.len(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_report_mismatched_delimiters_once() -> Result<()> {
+        let err = Lexer::new(&Span::from_str("a(b, [c)]"))
+            .lex_trees()
+            .unwrap_err();
+
+        debug_assert_eq!(
+            err.to_string(),
+            "[[c)] => [2] => mismatched delimiters, '[' (Ln 1, Col 6) closed by ')' (Ln 1, Col 8)"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_report_unbalanced_delimiters() -> Result<()> {
+        let unclosed = Lexer::new(&Span::from_str("a { (b) ")).lex_trees();
+        let unexpected = Lexer::new(&Span::from_str("a) b")).lex_trees();
+
+        debug_assert!(
+            unclosed
+                .unwrap_err()
+                .to_string()
+                .contains("unclosed delimiter '{'")
+        );
+        debug_assert!(
+            unexpected
+                .unwrap_err()
+                .to_string()
+                .contains("unexpected closing delimiter ')'")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_tree() -> Result<()> {
+        let mut cursor = Span::from_str("(a, (b)) c").cursor();
+        let mut parser = zinq_parse::ZinqParser;
+        let tree = parser.parse::<TokenTree>(&mut cursor)?;
+
+        debug_assert!(tree.is_group());
+        debug_assert_eq!(tree.to_string(), "(a, (b))");
+        debug_assert!(parser.parse::<TokenTree>(&mut cursor)?.is_leaf());
+
+        Ok(())
+    }
+}